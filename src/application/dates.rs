@@ -0,0 +1,170 @@
+use chrono::{Datelike, NaiveDate};
+use crate::domain::models::{Employer, Experience};
+
+// Month arithmetic for the free-form dates entered on education and
+// experience entries; months are counted from year 0 so ranges compare simply
+
+// Dates that can be read, shown when one cannot be read
+pub const DATE_FORMATS: &str = "2020-03, 03/2020, Mar 2020 or 2020";
+
+// First and last month covered by "2020-03", "03/2020", "Mar 2020" or "2020", as months since year 0;
+// full dates such as "2020-03-15" or "Mar 15, 2020" count as their month
+pub fn month_bounds(value: &str) -> Option<(i32, i32)> {
+    let value = value.trim();
+    if let Ok(year) = value.parse::<i32>() {
+        return Some((year * 12, year * 12 + 11));
+    }
+    let date = NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("01/{}", value), "%d/%m/%Y"))
+        .or_else(|_| NaiveDate::parse_from_str(&format!("01 {}", value), "%d %b %Y"))
+        .or_else(|_| NaiveDate::parse_from_str(&format!("01 {}", value), "%d %B %Y"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%b %d, %Y"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%B %d, %Y"))
+        .ok()?;
+    let month = month_of(date);
+    Some((month, month))
+}
+
+// Month containing a date
+pub fn month_of(date: NaiveDate) -> i32 {
+    date.year() * 12 + date.month0() as i32
+}
+
+// "Mar 2023" for a month counted from year 0
+pub fn month_text(month: i32) -> String {
+    NaiveDate::from_ymd_opt(month.div_euclid(12), month.rem_euclid(12) as u32 + 1, 1)
+        .map(|date| date.format("%b %Y").to_string())
+        .unwrap_or_default()
+}
+
+// First and last month of an entry, with ongoing entries running to today
+// and entries without an end date covering their start only; `None` when a
+// date is given that cannot be read
+pub fn entry_months(start: &str, end: &str, is_current: bool, today: NaiveDate) -> Option<(i32, i32)> {
+    let (first, first_end) = month_bounds(start)?;
+    let last = if is_current || end.trim().eq_ignore_ascii_case("present") {
+        month_of(today)
+    } else if end.trim().is_empty() {
+        first_end
+    } else {
+        month_bounds(end)?.1
+    };
+    Some((first, last.max(first)))
+}
+
+// Why a date typed into an entry cannot be read; `None` for readable and empty dates
+pub fn date_error(value: &str, allow_present: bool) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() || month_bounds(value).is_some() || (allow_present && value.eq_ignore_ascii_case("present")) {
+        return None;
+    }
+    Some(format!("\"{}\" is not a date like {}", value, DATE_FORMATS))
+}
+
+// Start of the earliest role at an employer and end of the latest one, as
+// entered, whatever order the roles are listed in; ongoing roles end last
+// and roles without a readable end count as ending when they started
+pub fn employer_dates<'a>(employer: &Employer<'a>) -> (&'a str, &'a str, bool) {
+    let first = employer.roles.iter()
+        .min_by_key(|(_, exp)| month_bounds(&exp.start_date).map_or(i32::MAX, |(first, _)| first));
+    let latest = employer.roles.iter().max_by_key(|(_, exp)| end_month(exp));
+    (
+        first.map_or("", |(_, exp)| exp.start_date.as_str()),
+        latest.map_or("", |(_, exp)| exp.end_date.as_str()),
+        latest.is_some_and(|(_, exp)| exp.is_current),
+    )
+}
+
+// Last month of a role for ordering roles by when they ended
+fn end_month(exp: &Experience) -> i32 {
+    if exp.is_current || exp.end_date.trim().eq_ignore_ascii_case("present") {
+        return i32::MAX;
+    }
+    month_bounds(&exp.end_date)
+        .or_else(|| month_bounds(&exp.start_date))
+        .map_or(i32::MIN, |(_, last)| last)
+}
+
+// Months covered by a set of ranges, counting overlaps once
+pub fn covered_months(mut ranges: Vec<(i32, i32)>) -> u32 {
+    ranges.sort();
+    let mut total = 0;
+    let mut covered_to = i32::MIN;
+    for (first, last) in ranges {
+        let first = first.max(covered_to.saturating_add(1));
+        if last >= first {
+            total += (last - first + 1) as u32;
+        }
+        covered_to = covered_to.max(last);
+    }
+    total
+}
+
+// "2.5 years", "1 year" or "8 months"; empty for no time at all
+pub fn duration_text(months: u32) -> String {
+    match months {
+        0 => String::new(),
+        1 => "1 month".to_string(),
+        months if months < 12 => format!("{} months", months),
+        12 => "1 year".to_string(),
+        months if months % 12 == 0 => format!("{} years", months / 12),
+        months => format!("{:.1} years", months as f64 / 12.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    #[test]
+    fn reads_month_and_full_dates() {
+        let march = 2020 * 12 + 2;
+        for value in ["2020-03", "03/2020", "Mar 2020", "March 2020", "2020-03-15", "Mar 15, 2020"] {
+            assert_eq!(month_bounds(value), Some((march, march)), "{}", value);
+        }
+        assert_eq!(month_bounds("2020"), Some((2020 * 12, 2020 * 12 + 11)));
+        assert_eq!(month_bounds("spring 2020"), None);
+    }
+
+    #[test]
+    fn unreadable_end_date_is_not_guessed() {
+        assert_eq!(entry_months("2019-01", "sometime", false, today()), None);
+        assert_eq!(entry_months("2019-01", "2021-12-31", false, today()), Some((2019 * 12, 2021 * 12 + 11)));
+        assert_eq!(entry_months("2019-01", "", false, today()), Some((2019 * 12, 2019 * 12)));
+        assert_eq!(entry_months("2019-01", "Present", false, today()), Some((2019 * 12, 2024 * 12 + 5)));
+    }
+
+    #[test]
+    fn employer_dates_do_not_depend_on_role_order() {
+        let role = |start: &str, end: &str, is_current: bool| Experience {
+            company: "Acme".to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            is_current,
+            ..Experience::default()
+        };
+        let mut resume = crate::domain::models::Resume {
+            experience: vec![role("2016-01", "2018-06", false), role("2018-07", "2021-03", false), role("Jan 2015", "Dec 2015", false)],
+            ..Default::default()
+        };
+        resume.experience[1].same_employer = true;
+        resume.experience[2].same_employer = true;
+        assert_eq!(employer_dates(&resume.employers()[0]), ("Jan 2015", "2021-03", false));
+
+        resume.experience[0].is_current = true;
+        assert_eq!(employer_dates(&resume.employers()[0]), ("Jan 2015", "2018-06", true));
+    }
+
+    #[test]
+    fn date_errors_name_the_readable_formats() {
+        assert_eq!(date_error("", false), None);
+        assert_eq!(date_error("Present", true), None);
+        assert!(date_error("Present", false).is_some());
+        assert!(date_error("soon", true).unwrap().contains(DATE_FORMATS));
+    }
+}
@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use crate::domain::{EntryOrder, Resume, Section};
+use super::dates::month_bounds;
+
+// Newest-first ordering of education and experience entries
+//
+// Entries are kept in display order in the resume itself, so the editor,
+// preview and every export list them the same way and an item's position
+// means the same thing everywhere. Sections set to manual order are left
+// as the user arranged them.
+
+// Sorts a section if it is ordered chronologically; returns, for each new
+// position, the position the entry had before
+pub fn sort_section(resume: &mut Resume, section: Section) -> Vec<usize> {
+    if resume.entry_order(section) != EntryOrder::Chronological {
+        let len = match section {
+            Section::Education => resume.education.len(),
+            Section::Experience => resume.experience.len(),
+            _ => 0,
+        };
+        return (0..len).collect();
+    }
+    match section {
+        Section::Education => {
+            let keys: Vec<SortKey> = resume.education.iter()
+                .map(|edu| sort_key(&edu.start_date, &edu.end_date, false))
+                .collect();
+            let order = sorted_groups(&keys, (0..keys.len()).map(|index| index..index + 1).collect()).concat();
+            reorder(&mut resume.education, &order);
+            order
+        }
+        Section::Experience => {
+            // Positions at one employer stay together, the employer placed by its latest position
+            let keys: Vec<SortKey> = resume.experience.iter()
+                .map(|exp| sort_key(&exp.start_date, &exp.end_date, exp.is_current))
+                .collect();
+            let groups = sorted_groups(&keys, resume.employer_ranges());
+            let order = groups.concat();
+            reorder(&mut resume.experience, &order);
+            // The latest position now leads each employer
+            let mut position = 0;
+            for group in groups {
+                for (role, exp) in resume.experience[position..position + group.len()].iter_mut().enumerate() {
+                    exp.same_employer = role > 0;
+                }
+                position += group.len();
+            }
+            order
+        }
+        _ => Vec::new(),
+    }
+}
+
+// Sorts every chronologically ordered section; true when anything moved
+pub fn sort_entries(resume: &mut Resume) -> bool {
+    let orders: Vec<Vec<usize>> = [Section::Education, Section::Experience].into_iter()
+        .map(|section| sort_section(resume, section))
+        .collect();
+    orders.iter().any(|order| order.iter().enumerate().any(|(position, previous)| position != *previous))
+}
+
+// Where an entry ended up after sorting, given the position it had before
+pub fn sorted_position(order: &[usize], previous: usize) -> usize {
+    order.iter().position(|index| *index == previous).unwrap_or(previous)
+}
+
+// Current entries are pinned to the top; the rest go by latest end month,
+// then latest start. Entries without readable dates sink to the bottom.
+type SortKey = (Reverse<bool>, Reverse<Option<i32>>, Reverse<Option<i32>>);
+
+fn sort_key(start: &str, end: &str, is_current: bool) -> SortKey {
+    let current = is_current || end.trim().eq_ignore_ascii_case("present");
+    let start = month_bounds(start);
+    let last = if current { None } else { month_bounds(end).or(start).map(|(_, last)| last) };
+    (Reverse(current), Reverse(last), Reverse(start.map(|(first, _)| first)))
+}
+
+// Groups of previous positions in sorted order: entries sorted within each
+// group, then groups by their first entry. Sorts are stable, so entries with
+// equal dates keep their relative order.
+fn sorted_groups(keys: &[SortKey], groups: Vec<Range<usize>>) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = groups.into_iter()
+        .map(|range| {
+            let mut group: Vec<usize> = range.collect();
+            group.sort_by_key(|index| keys[*index]);
+            group
+        })
+        .collect();
+    groups.sort_by_key(|group| keys[group[0]]);
+    groups
+}
+
+fn reorder<T>(entries: &mut Vec<T>, order: &[usize]) {
+    let mut slots: Vec<Option<T>> = std::mem::take(entries).into_iter().map(Some).collect();
+    *entries = order.iter().filter_map(|index| slots[*index].take()).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Education, Experience};
+
+    fn role(company: &str, position: &str, start: &str, end: &str) -> Experience {
+        Experience {
+            company: company.to_string(),
+            position: position.to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            ..Experience::default()
+        }
+    }
+
+    fn positions(resume: &Resume) -> Vec<&str> {
+        resume.experience.iter().map(|exp| exp.position.as_str()).collect()
+    }
+
+    #[test]
+    fn current_entries_come_first_and_undated_entries_last() {
+        let mut current = role("Initech", "Current", "2015-01", "");
+        current.is_current = true;
+        let mut resume = Resume {
+            experience: vec![
+                role("Acme", "Undated", "", ""),
+                role("Globex", "Old", "2010-01", "2012-06"),
+                current,
+                role("Umbrella", "Recent", "2019-01", "2023-12"),
+                role("Hooli", "Present", "2021-01", "Present"),
+            ],
+            ..Resume::default()
+        };
+        let order = sort_section(&mut resume, Section::Experience);
+        // Current entries among themselves go by the latest start
+        assert_eq!(positions(&resume), vec!["Present", "Current", "Recent", "Old", "Undated"]);
+        assert_eq!(order, vec![4, 2, 3, 1, 0]);
+        assert_eq!(sorted_position(&order, 0), 4);
+        assert!(!sort_entries(&mut resume));
+    }
+
+    #[test]
+    fn ties_on_the_end_date_go_by_the_latest_start() {
+        let key = |start, end| sort_key(start, end, false);
+        assert!(key("2020-01", "2022-06") < key("2018-01", "2022-06"));
+        // A start alone counts as the end too
+        assert!(key("2021-03", "") < key("2018-01", "2020-12"));
+        assert!(key("2018-01", "2020-12") < key("spring", "summer"));
+        assert!(sort_key("2010-01", "", true) < key("2023-01", "2024-01"));
+    }
+
+    #[test]
+    fn positions_at_one_employer_stay_together() {
+        let mut promoted = role("Acme", "Engineer", "2016-01", "2018-12");
+        promoted.same_employer = true;
+        let mut resume = Resume {
+            experience: vec![
+                role("Acme", "Lead", "2019-01", "2020-12"),
+                promoted,
+                role("Globex", "Intern", "2015-01", "2015-12"),
+                role("Hooli", "Architect", "2021-01", "2023-12"),
+                role("Initech", "Consultant", "2017-06", "2019-06"),
+            ],
+            ..Resume::default()
+        };
+        // Initech ends within Acme's years, but Acme's roles are not split up
+        sort_section(&mut resume, Section::Experience);
+        assert_eq!(positions(&resume), vec!["Architect", "Lead", "Engineer", "Consultant", "Intern"]);
+        assert_eq!(resume.employer_ranges(), vec![0..1, 1..3, 3..4, 4..5]);
+    }
+
+    #[test]
+    fn the_latest_position_leads_a_reordered_employer() {
+        let mut lead = role("Acme", "Lead", "2019-01", "2020-12");
+        lead.same_employer = true;
+        let mut resume = Resume {
+            experience: vec![role("Acme", "Engineer", "2016-01", "2018-12"), lead, role("Globex", "Intern", "2015-01", "2015-12")],
+            ..Resume::default()
+        };
+        assert!(sort_entries(&mut resume));
+        assert_eq!(positions(&resume), vec!["Lead", "Engineer", "Intern"]);
+        let grouped: Vec<bool> = resume.experience.iter().map(|exp| exp.same_employer).collect();
+        assert_eq!(grouped, vec![false, true, false]);
+    }
+
+    #[test]
+    fn manual_sections_are_left_alone() {
+        let degree = |name: &str, end: &str| Education { degree: name.to_string(), end_date: end.to_string(), ..Education::default() };
+        let mut resume = Resume {
+            education: vec![degree("B.Sc.", "2012"), degree("M.Sc.", "2014")],
+            ..Resume::default()
+        };
+        resume.set_entry_order(Section::Education, EntryOrder::Manual);
+        assert_eq!(sort_section(&mut resume, Section::Education), vec![0, 1]);
+        assert_eq!(resume.education[0].degree, "B.Sc.");
+
+        resume.set_entry_order(Section::Education, EntryOrder::Chronological);
+        assert_eq!(sort_section(&mut resume, Section::Education), vec![1, 0]);
+        assert_eq!(resume.education[0].degree, "M.Sc.");
+    }
+}
@@ -0,0 +1,691 @@
+use chrono::NaiveDate;
+use super::dates::month_bounds;
+use crate::domain::{Education, Experience, LinkKind, PersonalInfo, ProfileLink, Project, Resume, Skill};
+
+// Merging one resume into another
+//
+// Entries are matched across both resumes (experience by company and
+// overlapping dates, education by institution, skills and projects by
+// normalized name, profile links by site or address). Matched entries that are identical are duplicates, empty
+// fields are filled in silently, and fields that hold different values on both
+// sides become conflicts the user resolves one by one.
+
+// How a single conflict is resolved
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MergeChoice {
+    #[default]
+    KeepMine,
+    TakeTheirs,
+    KeepBoth,
+}
+
+impl MergeChoice {
+    // Choices offered for a conflict; a name, email address, phone number or
+    // location holds a single value, so only the summary and list entries can keep both
+    pub fn all(target: MergeTarget) -> Vec<Self> {
+        match target {
+            MergeTarget::Personal(field) if field != PersonalField::Summary => vec![Self::KeepMine, Self::TakeTheirs],
+            _ => vec![Self::KeepMine, Self::TakeTheirs, Self::KeepBoth],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::KeepMine => "Keep mine",
+            Self::TakeTheirs => "Take theirs",
+            Self::KeepBoth => "Keep both",
+        }
+    }
+}
+
+// Personal information fields that can conflict
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PersonalField {
+    Name,
+    Email,
+    Phone,
+    Location,
+    Summary,
+}
+
+impl PersonalField {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Name,
+            Self::Email,
+            Self::Phone,
+            Self::Location,
+            Self::Summary,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Email => "Email",
+            Self::Phone => "Phone",
+            Self::Location => "Location",
+            Self::Summary => "Summary",
+        }
+    }
+
+    fn value_mut<'a>(&self, info: &'a mut PersonalInfo) -> &'a mut String {
+        match self {
+            Self::Name => &mut info.name,
+            Self::Email => &mut info.email,
+            Self::Phone => &mut info.phone,
+            Self::Location => &mut info.location,
+            Self::Summary => &mut info.summary,
+        }
+    }
+
+    fn value(&self, info: &PersonalInfo) -> String {
+        match self {
+            Self::Name => info.name.clone(),
+            Self::Email => info.email.clone(),
+            Self::Phone => info.phone.clone(),
+            Self::Location => info.location.clone(),
+            Self::Summary => info.summary.clone(),
+        }
+    }
+}
+
+// What a conflict or match refers to; entry indices are (mine, theirs)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MergeTarget {
+    Personal(PersonalField),
+    Link(usize, usize),
+    Education(usize, usize),
+    Experience(usize, usize),
+    Skill(usize, usize),
+    Project(usize, usize),
+}
+
+// A field that holds different values in both resumes
+#[derive(Clone, PartialEq, Debug)]
+pub struct FieldDifference {
+    pub field: &'static str,
+    pub mine: String,
+    pub theirs: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MergeConflict {
+    pub target: MergeTarget,
+    pub label: String,
+    pub differences: Vec<FieldDifference>,
+    pub choice: MergeChoice,
+}
+
+// Result of comparing two resumes, applied with `ResumeUseCase::apply_merge`
+#[derive(Clone, PartialEq)]
+pub struct MergePlan {
+    pub mine: Resume,
+    pub theirs: Resume,
+    pub conflicts: Vec<MergeConflict>,
+    // Entries only present in `theirs`, added as they are
+    pub additions: usize,
+    // Matched entries without any conflicting field
+    pub duplicates: usize,
+    matches: Vec<MergeTarget>,
+}
+
+impl MergePlan {
+    // Compares both resumes and collects duplicates, additions and conflicts
+    pub fn new(mine: &Resume, theirs: &Resume) -> Self {
+        let mut plan = MergePlan {
+            mine: mine.clone(),
+            theirs: theirs.clone(),
+            conflicts: Vec::new(),
+            additions: 0,
+            duplicates: 0,
+            matches: Vec::new(),
+        };
+
+        for field in PersonalField::all() {
+            let (a, b) = (field.value(&mine.personal_info), field.value(&theirs.personal_info));
+            if let Some(difference) = difference(field.name(), &a, &b) {
+                plan.conflicts.push(MergeConflict {
+                    target: MergeTarget::Personal(field),
+                    label: format!("Personal info: {}", field.name()),
+                    differences: vec![difference],
+                    choice: MergeChoice::default(),
+                });
+            }
+        }
+
+        let links = match_entries(&mine.personal_info.links, &theirs.personal_info.links, same_link);
+        for (t, m) in links.iter().enumerate() {
+            let Some(m) = *m else { continue };
+            let (a, b) = (&mine.personal_info.links[m], &theirs.personal_info.links[t]);
+            plan.record(MergeTarget::Link(m, t), format!("Link: {}", a.kind.name()), link_differences(a, b));
+        }
+
+        let education = match_entries(&mine.education, &theirs.education, |a, b| {
+            same_name(&a.institution, &b.institution)
+        });
+        for (t, m) in education.iter().enumerate() {
+            let Some(m) = *m else { continue };
+            let (a, b) = (&mine.education[m], &theirs.education[t]);
+            plan.record(MergeTarget::Education(m, t), format!("Education: {}", a.institution), education_differences(a, b));
+        }
+
+        let experience = match_entries(&mine.experience, &theirs.experience, |a, b| {
+            same_name(&a.company, &b.company)
+                && match dates_overlap(a, b) {
+                    Some(overlap) => overlap,
+                    None => same_name(&a.position, &b.position),
+                }
+        });
+        for (t, m) in experience.iter().enumerate() {
+            let Some(m) = *m else { continue };
+            let (a, b) = (&mine.experience[m], &theirs.experience[t]);
+            plan.record(MergeTarget::Experience(m, t), format!("Experience: {} at {}", a.position, a.company), experience_differences(a, b));
+        }
+
+        let skills = match_entries(&mine.skills.skill_list, &theirs.skills.skill_list, |a, b| {
+            normalized_name(&a.name) == normalized_name(&b.name)
+        });
+        for (t, m) in skills.iter().enumerate() {
+            let Some(m) = *m else { continue };
+            let (a, b) = (&mine.skills.skill_list[m], &theirs.skills.skill_list[t]);
+            plan.record(MergeTarget::Skill(m, t), format!("Skill: {}", a.name), skill_differences(a, b));
+        }
+
+        let projects = match_entries(&mine.projects, &theirs.projects, |a, b| same_name(&a.name, &b.name));
+        for (t, m) in projects.iter().enumerate() {
+            let Some(m) = *m else { continue };
+            let (a, b) = (&mine.projects[m], &theirs.projects[t]);
+            plan.record(MergeTarget::Project(m, t), format!("Project: {}", a.name), project_differences(a, b));
+        }
+
+        plan.additions = [&links, &education, &experience, &skills, &projects]
+            .iter()
+            .map(|matches| matches.iter().filter(|m| m.is_none()).count())
+            .sum();
+        plan
+    }
+
+    fn record(&mut self, target: MergeTarget, label: String, differences: Vec<FieldDifference>) {
+        self.matches.push(target);
+        if differences.is_empty() {
+            self.duplicates += 1;
+        } else {
+            self.conflicts.push(MergeConflict { target, label, differences, choice: MergeChoice::default() });
+        }
+    }
+
+    pub fn set_choice(&mut self, index: usize, choice: MergeChoice) {
+        if let Some(conflict) = self.conflicts.get_mut(index).filter(|conflict| MergeChoice::all(conflict.target).contains(&choice)) {
+            conflict.choice = choice;
+        }
+    }
+
+    // Builds the merged resume from the current conflict choices
+    pub(crate) fn apply(&self) -> Resume {
+        let mut result = self.mine.clone();
+        let theirs = &self.theirs;
+
+        // Empty fields are always filled from the other resume
+        for field in PersonalField::all() {
+            let value = field.value_mut(&mut result.personal_info);
+            if value.trim().is_empty() {
+                *value = field.value(&theirs.personal_info);
+            }
+        }
+        // Photos are not compared; a missing one is taken from the other resume
+        if result.personal_info.photo.is_none() {
+            result.personal_info.photo = theirs.personal_info.photo.clone();
+        }
+        for target in &self.matches {
+            match *target {
+                MergeTarget::Education(m, t) => fill_education(&mut result.education[m], &theirs.education[t]),
+                MergeTarget::Experience(m, t) => fill_experience(&mut result.experience[m], &theirs.experience[t]),
+                MergeTarget::Skill(m, t) => {
+                    let (skill, other) = (&mut result.skills.skill_list[m], &theirs.skills.skill_list[t]);
+                    if skill.level == 0 {
+                        skill.level = other.level;
+                    }
+                    if skill.category.is_empty() {
+                        skill.category = other.category.clone();
+                    }
+                    if skill.years.is_none() {
+                        skill.years = other.years;
+                    }
+                }
+                MergeTarget::Project(m, t) => fill_project(&mut result.projects[m], &theirs.projects[t]),
+                MergeTarget::Link(m, t) => {
+                    let link = &mut result.personal_info.links[m];
+                    if link.label.trim().is_empty() {
+                        link.label = theirs.personal_info.links[t].label.clone();
+                    }
+                }
+                MergeTarget::Personal(_) => {}
+            }
+        }
+
+        // Kept copies are appended after all replacements so indices stay valid
+        let mut extra = Resume::default();
+        for conflict in &self.conflicts {
+            match (conflict.target, conflict.choice) {
+                (_, MergeChoice::KeepMine) => {}
+                (MergeTarget::Link(m, t), MergeChoice::TakeTheirs) => {
+                    result.personal_info.links[m] = theirs.personal_info.links[t].clone();
+                }
+                (MergeTarget::Link(_, t), _) => extra.personal_info.links.push(theirs.personal_info.links[t].clone()),
+                (MergeTarget::Personal(field), choice) => {
+                    let theirs_value = field.value(&theirs.personal_info);
+                    let value = field.value_mut(&mut result.personal_info);
+                    *value = match (choice, field) {
+                        (MergeChoice::KeepBoth, PersonalField::Summary) => format!("{}\n\n{}", value, theirs_value),
+                        _ => theirs_value,
+                    };
+                }
+                (MergeTarget::Education(m, t), choice) => {
+                    replace_or_keep(&mut result.education[m], &theirs.education[t], choice, &mut extra.education, |edu| &mut edu.hidden)
+                }
+                (MergeTarget::Experience(m, t), choice) => {
+                    // Grouping under an employer depends on where the entry sits in mine
+                    let same_employer = result.experience[m].same_employer;
+                    replace_or_keep(&mut result.experience[m], &theirs.experience[t], choice, &mut extra.experience, |exp| &mut exp.hidden);
+                    result.experience[m].same_employer = same_employer;
+                }
+                (MergeTarget::Skill(m, t), choice) => {
+                    replace_or_keep(&mut result.skills.skill_list[m], &theirs.skills.skill_list[t], choice, &mut extra.skills.skill_list, |skill| &mut skill.hidden)
+                }
+                (MergeTarget::Project(m, t), choice) => {
+                    replace_or_keep(&mut result.projects[m], &theirs.projects[t], choice, &mut extra.projects, |project| &mut project.hidden)
+                }
+            }
+        }
+        result.personal_info.links.append(&mut extra.personal_info.links);
+        result.education.append(&mut extra.education);
+        let added_experience = result.experience.len();
+        result.experience.append(&mut extra.experience);
+        result.skills.skill_list.append(&mut extra.skills.skill_list);
+        result.projects.append(&mut extra.projects);
+
+        // Entries without a match are added as they are
+        let matched = |pick: fn(&MergeTarget) -> Option<usize>| -> Vec<usize> {
+            self.matches.iter().filter_map(pick).collect()
+        };
+        let links = matched(|target| match target { MergeTarget::Link(_, t) => Some(*t), _ => None });
+        let education = matched(|target| match target { MergeTarget::Education(_, t) => Some(*t), _ => None });
+        let experience = matched(|target| match target { MergeTarget::Experience(_, t) => Some(*t), _ => None });
+        let skills = matched(|target| match target { MergeTarget::Skill(_, t) => Some(*t), _ => None });
+        let projects = matched(|target| match target { MergeTarget::Project(_, t) => Some(*t), _ => None });
+        result.personal_info.links.extend(unmatched(&theirs.personal_info.links, &links));
+        result.education.extend(unmatched(&theirs.education, &education));
+        result.experience.extend(unmatched(&theirs.experience, &experience));
+        // Added positions stay under an employer only when they follow one of its positions
+        for index in added_experience.max(1)..result.experience.len() {
+            let follows = result.experience[index - 1].company.trim().eq_ignore_ascii_case(result.experience[index].company.trim());
+            result.experience[index].same_employer &= follows;
+        }
+        result.skills.skill_list.extend(unmatched(&theirs.skills.skill_list, &skills));
+        result.projects.extend(unmatched(&theirs.projects, &projects));
+        // Categories that only theirs had are added after mine, in their order
+        let mut categories: Vec<String> = result.skills.skill_list.iter()
+            .map(|skill| skill.category.clone())
+            .filter(|category| !category.is_empty())
+            .collect();
+        categories.sort_by_key(|name| theirs.skills.categories.iter().position(|category| category == name).unwrap_or(usize::MAX));
+        for category in categories {
+            result.skills.add_category(&category);
+        }
+        result
+    }
+}
+
+// Replaces an entry with theirs (keeping its visibility) or keeps a copy of theirs as well
+fn replace_or_keep<T: Clone>(mine: &mut T, theirs: &T, choice: MergeChoice, extra: &mut Vec<T>, hidden: fn(&mut T) -> &mut bool) {
+    match choice {
+        MergeChoice::KeepMine => {}
+        MergeChoice::TakeTheirs => {
+            let was_hidden = *hidden(mine);
+            *mine = theirs.clone();
+            *hidden(mine) = was_hidden;
+        }
+        MergeChoice::KeepBoth => extra.push(theirs.clone()),
+    }
+}
+
+fn unmatched<T: Clone>(entries: &[T], matched: &[usize]) -> Vec<T> {
+    entries.iter()
+        .enumerate()
+        .filter(|(index, _)| !matched.contains(index))
+        .map(|(_, entry)| entry.clone())
+        .collect()
+}
+
+// For each entry in `theirs`, the index of its match in `mine`; every entry matches at most once
+fn match_entries<T>(mine: &[T], theirs: &[T], matches: impl Fn(&T, &T) -> bool) -> Vec<Option<usize>> {
+    let mut used = vec![false; mine.len()];
+    theirs.iter()
+        .map(|entry| {
+            let found = mine.iter().enumerate().position(|(index, candidate)| !used[index] && matches(candidate, entry));
+            if let Some(index) = found {
+                used[index] = true;
+            }
+            found
+        })
+        .collect()
+}
+
+// Lowercase name without punctuation or spacing, keeping "+" and "#" for C++ and C#
+fn normalized_name(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '+' || *c == '#')
+        .collect()
+}
+
+// Compares organization names, ignoring legal suffixes such as "Inc." or "GmbH"
+fn same_name(a: &str, b: &str) -> bool {
+    let strip = |name: &str| {
+        let words: Vec<String> = name.split_whitespace()
+            .map(normalized_name)
+            .filter(|word| !word.is_empty())
+            .filter(|word| !["inc", "llc", "ltd", "gmbh", "corp", "corporation", "co", "plc", "ag", "sa"].contains(&word.as_str()))
+            .collect();
+        words.concat()
+    };
+    let (a, b) = (strip(a), strip(b));
+    !a.is_empty() && a == b
+}
+
+// Profiles on the same site are the same link; other links match by address
+fn same_link(a: &ProfileLink, b: &ProfileLink) -> bool {
+    let address = |link: &ProfileLink| link.url().trim_end_matches('/').to_lowercase();
+    match a.kind {
+        LinkKind::Website | LinkKind::Portfolio | LinkKind::Other => address(a) == address(b),
+        kind => kind == b.kind,
+    }
+}
+
+fn link_differences(a: &ProfileLink, b: &ProfileLink) -> Vec<FieldDifference> {
+    let address = |link: &ProfileLink| link.url().trim_end_matches('/').to_string();
+    [
+        difference("Address", &address(a), &address(b)),
+        difference("Label", &a.label, &b.label),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn difference(field: &'static str, mine: &str, theirs: &str) -> Option<FieldDifference> {
+    let (a, b) = (mine.trim(), theirs.trim());
+    (!a.is_empty() && !b.is_empty() && a != b).then(|| FieldDifference {
+        field,
+        mine: a.to_string(),
+        theirs: b.to_string(),
+    })
+}
+
+fn education_differences(a: &Education, b: &Education) -> Vec<FieldDifference> {
+    [
+        difference("Degree", &a.degree, &b.degree),
+        difference("Field of study", &a.field_of_study, &b.field_of_study),
+        difference("Start date", &a.start_date, &b.start_date),
+        difference("End date", &a.end_date, &b.end_date),
+        difference("Location", &a.location, &b.location),
+        difference("GPA", &a.gpa, &b.gpa),
+        difference("Description", &a.description, &b.description),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn experience_differences(a: &Experience, b: &Experience) -> Vec<FieldDifference> {
+    let end = |exp: &Experience| if exp.is_current { "Present".to_string() } else { exp.end_date.clone() };
+    [
+        difference("Position", &a.position, &b.position),
+        difference("Start date", &a.start_date, &b.start_date),
+        difference("End date", &end(a), &end(b)),
+        difference("Location", &a.location, &b.location),
+        difference("Description", &a.description, &b.description),
+        difference("Achievements", &a.achievements.join("\n"), &b.achievements.join("\n")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn skill_differences(a: &Skill, b: &Skill) -> Vec<FieldDifference> {
+    let level = |skill: &Skill| if skill.level == 0 { String::new() } else { skill.level.to_string() };
+    let years = |skill: &Skill| skill.years.map(|years| years.to_string()).unwrap_or_default();
+    [
+        difference("Level", &level(a), &level(b)),
+        difference("Category", &a.category, &b.category),
+        difference("Years", &years(a), &years(b)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn project_differences(a: &Project, b: &Project) -> Vec<FieldDifference> {
+    let date = |date: Option<NaiveDate>| date.map(|date| date.to_string()).unwrap_or_default();
+    [
+        difference("Role", &a.role, &b.role),
+        difference("Start date", &date(a.start_date), &date(b.start_date)),
+        difference("End date", &date(a.end_date), &date(b.end_date)),
+        difference("Description", &a.description, &b.description),
+        difference("Technologies", &a.technologies.join(", "), &b.technologies.join(", ")),
+        difference("URL", &a.url, &b.url),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn fill(mine: &mut String, theirs: &str) {
+    if mine.trim().is_empty() {
+        *mine = theirs.to_string();
+    }
+}
+
+fn fill_education(mine: &mut Education, theirs: &Education) {
+    fill(&mut mine.degree, &theirs.degree);
+    fill(&mut mine.field_of_study, &theirs.field_of_study);
+    fill(&mut mine.start_date, &theirs.start_date);
+    fill(&mut mine.end_date, &theirs.end_date);
+    fill(&mut mine.location, &theirs.location);
+    fill(&mut mine.gpa, &theirs.gpa);
+    fill(&mut mine.description, &theirs.description);
+}
+
+fn fill_experience(mine: &mut Experience, theirs: &Experience) {
+    fill(&mut mine.position, &theirs.position);
+    fill(&mut mine.start_date, &theirs.start_date);
+    if mine.end_date.trim().is_empty() && !mine.is_current {
+        mine.end_date = theirs.end_date.clone();
+        mine.is_current = theirs.is_current;
+    }
+    fill(&mut mine.location, &theirs.location);
+    fill(&mut mine.description, &theirs.description);
+    if mine.achievements.is_empty() {
+        mine.achievements = theirs.achievements.clone();
+    }
+}
+
+fn fill_project(mine: &mut Project, theirs: &Project) {
+    fill(&mut mine.role, &theirs.role);
+    fill(&mut mine.description, &theirs.description);
+    fill(&mut mine.url, &theirs.url);
+    mine.start_date = mine.start_date.or(theirs.start_date);
+    mine.end_date = mine.end_date.or(theirs.end_date);
+    if mine.technologies.is_empty() {
+        mine.technologies = theirs.technologies.clone();
+    }
+}
+
+// Whether two jobs overlap in time, `None` when either has no readable start date
+fn dates_overlap(a: &Experience, b: &Experience) -> Option<bool> {
+    let range = |exp: &Experience| -> Option<(i32, i32)> {
+        let (start, _) = month_bounds(&exp.start_date)?;
+        let end = if exp.is_current || exp.end_date.trim().eq_ignore_ascii_case("present") {
+            i32::MAX
+        } else {
+            month_bounds(&exp.end_date).map_or(start, |(_, end)| end)
+        };
+        Some((start, end))
+    };
+    let ((a_start, a_end), (b_start, b_end)) = (range(a)?, range(b)?);
+    Some(a_start <= b_end && b_start <= a_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Skills;
+
+    fn resume(email: &str, summary: &str) -> Resume {
+        let mut resume = Resume::default();
+        resume.personal_info.email = email.to_string();
+        resume.personal_info.summary = summary.to_string();
+        resume
+    }
+
+    #[test]
+    fn single_value_fields_cannot_keep_both() {
+        let mut plan = MergePlan::new(&resume("a@x.com", "Mine"), &resume("b@y.com", "Theirs"));
+        for (index, conflict) in plan.conflicts.clone().iter().enumerate() {
+            let offered = MergeChoice::all(conflict.target);
+            let single = conflict.target == MergeTarget::Personal(PersonalField::Email);
+            assert_eq!(offered.contains(&MergeChoice::KeepBoth), !single);
+            plan.set_choice(index, MergeChoice::KeepBoth);
+        }
+        let merged = plan.apply();
+        assert_eq!(merged.personal_info.email, "a@x.com");
+        assert_eq!(merged.personal_info.summary, "Mine\n\nTheirs");
+    }
+
+    fn job(company: &str, position: &str, start: &str, end: &str) -> Experience {
+        Experience {
+            company: company.to_string(),
+            position: position.to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            ..Experience::default()
+        }
+    }
+
+    fn skill(name: &str, level: i32) -> Skill {
+        Skill { name: name.to_string(), level, ..Skill::default() }
+    }
+
+    #[test]
+    fn the_same_entry_is_matched_across_spellings() {
+        let mine = Resume {
+            experience: vec![job("Acme Inc.", "Engineer", "2018-01", "2020-12"), job("Acme Inc.", "Intern", "2015-06", "2015-09")],
+            education: vec![Education { institution: "State University".to_string(), degree: "B.Sc.".to_string(), ..Education::default() }],
+            skills: Skills { skill_list: vec![skill("C++", 4), skill("Node.js", 3)], ..Skills::default() },
+            ..Resume::default()
+        };
+        let theirs = Resume {
+            // A later role at the same company does not overlap and is a new entry
+            experience: vec![job("ACME", "Engineer", "2018-01", "2020-12"), job("Acme", "Lead", "2022-01", "")],
+            education: vec![Education { institution: "state university".to_string(), degree: "B.Sc.".to_string(), ..Education::default() }],
+            skills: Skills { skill_list: vec![skill("c++", 4), skill("NodeJS", 0), skill("C", 2)], ..Skills::default() },
+            ..Resume::default()
+        };
+        let plan = MergePlan::new(&mine, &theirs);
+        assert!(plan.conflicts.is_empty());
+        assert_eq!(plan.duplicates, 4);
+        assert_eq!(plan.additions, 2);
+
+        let merged = plan.apply();
+        let positions: Vec<&str> = merged.experience.iter().map(|exp| exp.position.as_str()).collect();
+        assert_eq!(positions, vec!["Engineer", "Intern", "Lead"]);
+        let skills: Vec<&str> = merged.skills.skill_list.iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(skills, vec!["C++", "Node.js", "C"]);
+    }
+
+    #[test]
+    fn differing_fields_become_conflicts_and_empty_fields_are_filled() {
+        let mut engineer = job("Acme", "Engineer", "2018-01", "2020-12");
+        engineer.location = "Berlin".to_string();
+        let mut senior = job("Acme", "Senior Engineer", "2018-01", "2020-12");
+        senior.description = "Built the billing system".to_string();
+        senior.location = "Munich".to_string();
+        let mine = Resume { experience: vec![engineer], ..resume("", "") };
+        let theirs = Resume { experience: vec![senior], ..resume("b@y.com", "") };
+
+        let plan = MergePlan::new(&mine, &theirs);
+        // An empty field on one side is no conflict
+        assert_eq!(plan.conflicts.len(), 1);
+        let conflict = &plan.conflicts[0];
+        assert_eq!(conflict.target, MergeTarget::Experience(0, 0));
+        assert_eq!(conflict.label, "Experience: Engineer at Acme");
+        let fields: Vec<&str> = conflict.differences.iter().map(|difference| difference.field).collect();
+        assert_eq!(fields, vec!["Position", "Location"]);
+        assert_eq!((conflict.differences[1].mine.as_str(), conflict.differences[1].theirs.as_str()), ("Berlin", "Munich"));
+
+        let merged = plan.apply();
+        assert_eq!(merged.personal_info.email, "b@y.com");
+        assert_eq!(merged.experience[0].position, "Engineer");
+        assert_eq!(merged.experience[0].description, "Built the billing system");
+    }
+
+    #[test]
+    fn set_choice_only_accepts_offered_choices() {
+        let mut plan = MergePlan::new(&resume("a@x.com", ""), &resume("b@y.com", ""));
+        plan.set_choice(0, MergeChoice::KeepBoth);
+        assert_eq!(plan.conflicts[0].choice, MergeChoice::KeepMine);
+        plan.set_choice(0, MergeChoice::TakeTheirs);
+        assert_eq!(plan.conflicts[0].choice, MergeChoice::TakeTheirs);
+        // Out of range indices are ignored
+        plan.set_choice(5, MergeChoice::KeepMine);
+        assert_eq!(plan.apply().personal_info.email, "b@y.com");
+    }
+
+    #[test]
+    fn each_choice_is_applied_to_list_entries() {
+        let mut mine_job = job("Acme", "Engineer", "2018-01", "2020-12");
+        mine_job.hidden = true;
+        let mine = Resume { experience: vec![mine_job], ..Resume::default() };
+        let theirs = Resume { experience: vec![job("Acme", "Senior Engineer", "2018-01", "2020-12")], ..Resume::default() };
+        let merged = |choice| {
+            let mut plan = MergePlan::new(&mine, &theirs);
+            plan.set_choice(0, choice);
+            plan.apply().experience
+        };
+
+        let kept = merged(MergeChoice::KeepMine);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].position, "Engineer");
+
+        // Taking theirs keeps the entry's visibility in mine
+        let taken = merged(MergeChoice::TakeTheirs);
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].position, "Senior Engineer");
+        assert!(taken[0].hidden);
+
+        let both = merged(MergeChoice::KeepBoth);
+        let positions: Vec<&str> = both.iter().map(|exp| exp.position.as_str()).collect();
+        assert_eq!(positions, vec!["Engineer", "Senior Engineer"]);
+        assert!(!both[1].hidden);
+    }
+
+    #[test]
+    fn merging_into_an_empty_resume_takes_everything() {
+        let mut theirs = resume("b@y.com", "Theirs");
+        theirs.personal_info.name = "Ada".to_string();
+        theirs.personal_info.links.push(ProfileLink::new(LinkKind::GitHub, "ada"));
+        theirs.experience = vec![job("Acme", "Lead", "2020-01", ""), job("Acme", "Engineer", "2018-01", "2019-12")];
+        theirs.experience[1].same_employer = true;
+        theirs.skills = Skills { categories: vec!["Languages".to_string()], skill_list: vec![Skill { category: "Languages".to_string(), ..skill("Rust", 5) }], ..Skills::default() };
+
+        let plan = MergePlan::new(&Resume::default(), &theirs);
+        assert!(plan.conflicts.is_empty());
+        assert_eq!(plan.duplicates, 0);
+        assert_eq!(plan.additions, 4);
+        let merged = plan.apply();
+        assert!(merged.personal_info == theirs.personal_info);
+        assert!(merged.experience == theirs.experience);
+        assert!(merged.skills == theirs.skills);
+    }
+}
@@ -1,6 +1,14 @@
 // Application layer - contains use cases and business logic
 pub mod repository;
 pub mod use_cases;
+pub mod merge;
+pub mod dates;
+pub mod entry_order;
+pub mod skill_analysis;
+pub mod timeline;
+pub mod validation;
 
 // Re-export use cases for easier access
-pub use use_cases::*; 
\ No newline at end of file
+pub use use_cases::*;
+pub use merge::*;
+pub use skill_analysis::*; 
\ No newline at end of file
@@ -1,5 +1,10 @@
-use crate::domain::Resume;
+use crate::domain::{Resume, JobApplication, LibraryEntry};
 use std::error::Error;
+use std::future::{self, Future};
+use std::pin::Pin;
+
+// Future returned by async repositories; storage in the browser is single-threaded so it need not be Send
+pub type RepositoryFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, Box<dyn Error>>> + 'a>>;
 
 // Repository trait defines operations for resume storage
 pub trait ResumeRepository {
@@ -11,4 +16,78 @@ pub trait ResumeRepository {
     
     // Check if a resume exists in storage
     fn exists(&self) -> bool;
-} 
\ No newline at end of file
+}
+
+// Async variant of `ResumeRepository` for storage backends such as IndexedDB
+pub trait AsyncResumeRepository {
+    // Save a resume to storage
+    fn save<'a>(&'a self, resume: &'a Resume) -> RepositoryFuture<'a, ()>;
+
+    // Load a resume from storage
+    fn load(&self) -> RepositoryFuture<'_, Resume>;
+
+    // Check if a resume exists in storage
+    fn exists(&self) -> RepositoryFuture<'_, bool>;
+}
+
+// Storage for the serialized resume as an opaque document, used by wrappers such as encryption
+pub trait DocumentStore {
+    // Replace the stored document
+    fn save_document<'a>(&'a self, document: &'a str) -> RepositoryFuture<'a, ()>;
+
+    // Load the stored document, None if nothing was saved yet
+    fn load_document(&self) -> RepositoryFuture<'_, Option<String>>;
+}
+
+// Synchronous storage for one opaque document, such as a localStorage item
+pub trait TextStore {
+    // Replace the stored document
+    fn save_text(&self, document: &str) -> Result<(), Box<dyn Error>>;
+
+    // Load the stored document, None if nothing was saved yet
+    fn load_text(&self) -> Result<Option<String>, Box<dyn Error>>;
+}
+
+// Every synchronous document store can be used where an async one is expected
+impl<T: TextStore> DocumentStore for T {
+    fn save_document<'a>(&'a self, document: &'a str) -> RepositoryFuture<'a, ()> {
+        Box::pin(future::ready(self.save_text(document)))
+    }
+
+    fn load_document(&self) -> RepositoryFuture<'_, Option<String>> {
+        Box::pin(future::ready(self.load_text()))
+    }
+}
+
+// Every synchronous repository can be used where an async one is expected
+impl<R: ResumeRepository> AsyncResumeRepository for R {
+    fn save<'a>(&'a self, resume: &'a Resume) -> RepositoryFuture<'a, ()> {
+        Box::pin(future::ready(ResumeRepository::save(self, resume)))
+    }
+
+    fn load(&self) -> RepositoryFuture<'_, Resume> {
+        Box::pin(future::ready(ResumeRepository::load(self)))
+    }
+
+    fn exists(&self) -> RepositoryFuture<'_, bool> {
+        Box::pin(future::ready(Ok(ResumeRepository::exists(self))))
+    }
+}
+
+// Repository trait defines operations for job application storage
+pub trait ApplicationRepository {
+    // Replace all stored applications
+    fn save_all(&self, applications: &[JobApplication]) -> Result<(), Box<dyn Error>>;
+
+    // Load all stored applications, empty if nothing was saved yet
+    fn load_all(&self) -> Result<Vec<JobApplication>, Box<dyn Error>>;
+}
+
+// Repository trait defines operations for the list of resumes in the library
+pub trait LibraryRepository {
+    // Replace the stored library
+    fn save_all(&self, entries: &[LibraryEntry]) -> Result<(), Box<dyn Error>>;
+
+    // Load the stored library, empty if nothing was saved yet
+    fn load_all(&self) -> Result<Vec<LibraryEntry>, Box<dyn Error>>;
+}
@@ -0,0 +1,326 @@
+use chrono::NaiveDate;
+use crate::domain::{find_known_skill, known_skills, skill_key, Resume};
+use super::dates::{covered_months, duration_text, entry_months, month_of, month_text};
+
+// Skill analysis
+//
+// Scans the visible experience, project and education entries for skills:
+// project technologies count by name, descriptions and achievements count
+// when they mention a listed skill or a skill from the bundled dictionary.
+// The dates of the entries that mention a skill give its "last used" month
+// and how long it has been used.
+
+// Ordinary words that are also skill names; in prose they only count when
+// written the way the skill is, e.g. "Rust" or "REST" but not "rust" or "rest"
+const COMMON_WORDS: &[&str] = &[
+    "bash", "babel", "coaching", "dart", "dynamo", "elastic", "excel", "express", "flask", "helm", "jest",
+    "julia", "lambda", "next", "node", "oracle", "phoenix", "rabbit", "rest", "rust", "security", "shell",
+    "sketch", "snowflake", "spark", "spring", "swift", "torch", "unity",
+];
+
+// How a skill shows up in the resume
+#[derive(Clone, PartialEq, Debug)]
+pub struct SkillEvidence {
+    pub name: String,
+    // Category from the dictionary; empty when it is not a known skill
+    pub category: String,
+    // Entries that mention the skill, e.g. "Senior Developer at Acme"
+    pub sources: Vec<String>,
+    // Whether an ongoing entry mentions it
+    pub current: bool,
+    // Latest month an entry mentioning it ran to, counted from year 0
+    last_month: Option<i32>,
+    // Months covered by dated entries mentioning it, overlaps counted once
+    pub months_used: u32,
+}
+
+impl SkillEvidence {
+    pub fn is_evidenced(&self) -> bool {
+        !self.sources.is_empty()
+    }
+
+    // "Present", "Mar 2023", or empty when no mentioning entry is dated
+    pub fn last_used(&self) -> String {
+        match (self.current, self.last_month) {
+            (true, _) => "Present".to_string(),
+            (false, Some(month)) => month_text(month),
+            (false, None) => String::new(),
+        }
+    }
+
+    // Years used, rounded to whole years; `None` below half a year
+    pub fn years_used(&self) -> Option<u32> {
+        let years = (self.months_used + 6) / 12;
+        (years > 0).then_some(years)
+    }
+
+    // "2.5 years" or "8 months"
+    pub fn duration(&self) -> String {
+        duration_text(self.months_used)
+    }
+}
+
+// Result of `analyze_skills`
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SkillAnalysis {
+    // Every listed skill with its evidence, by position in `skill_list`
+    pub listed: Vec<(usize, SkillEvidence)>,
+    // Skills the entries mention that the skill section does not list, most mentioned first
+    pub missing: Vec<SkillEvidence>,
+}
+
+impl SkillAnalysis {
+    // Listed skills that no entry mentions
+    pub fn unevidenced(&self) -> impl Iterator<Item = &(usize, SkillEvidence)> {
+        self.listed.iter().filter(|(_, evidence)| !evidence.is_evidenced())
+    }
+}
+
+// A visible entry as the analyzer sees it
+struct Entry<'a> {
+    source: String,
+    months: Option<(i32, i32)>,
+    current: bool,
+    prose: Vec<&'a str>,
+    tags: &'a [String],
+}
+
+// A skill to look for and the ways it may be written
+struct Candidate {
+    name: String,
+    category: String,
+    terms: Vec<String>,
+}
+
+pub fn analyze_skills(resume: &Resume, today: NaiveDate) -> SkillAnalysis {
+    let entries = entries(resume, today);
+
+    let listed: Vec<(usize, SkillEvidence)> = resume.skills.skill_list.iter().enumerate()
+        .map(|(index, skill)| (index, evidence(&entries, &candidate(&skill.name))))
+        .collect();
+
+    let mut seen: Vec<String> = resume.skills.skill_list.iter().map(|skill| key_of(&skill.name)).collect();
+    let technologies = resume.projects.iter().filter(|project| !project.hidden).flat_map(|project| &project.technologies);
+    let mut missing: Vec<SkillEvidence> = technologies.map(|name| name.as_str())
+        .chain(known_skills().iter().map(|skill| skill.name))
+        .filter_map(|name| {
+            let key = key_of(name);
+            if key.is_empty() || seen.contains(&key) {
+                return None;
+            }
+            seen.push(key);
+            Some(evidence(&entries, &candidate(name))).filter(SkillEvidence::is_evidenced)
+        })
+        .collect();
+    missing.sort_by(|a, b| b.sources.len().cmp(&a.sources.len()).then_with(|| a.name.cmp(&b.name)));
+
+    SkillAnalysis { listed, missing }
+}
+
+// Dictionary key of a skill, so aliases of one skill count as the same
+fn key_of(name: &str) -> String {
+    skill_key(find_known_skill(name).map_or(name, |known| known.name))
+}
+
+fn candidate(name: &str) -> Candidate {
+    match find_known_skill(name) {
+        Some(known) => Candidate {
+            name: known.name.to_string(),
+            category: known.category.to_string(),
+            terms: std::iter::once(name.trim())
+                .chain(std::iter::once(known.name))
+                .chain(known.aliases.iter().copied())
+                .map(str::to_string)
+                .collect(),
+        },
+        None => Candidate { name: name.trim().to_string(), category: String::new(), terms: vec![name.trim().to_string()] },
+    }
+}
+
+fn entries(resume: &Resume, today: NaiveDate) -> Vec<Entry<'_>> {
+    let mut entries = Vec::new();
+    for exp in resume.experience.iter().filter(|exp| !exp.hidden) {
+        let mut prose = vec![exp.position.as_str(), exp.description.as_str()];
+        prose.extend(exp.achievements.iter().map(String::as_str));
+        entries.push(Entry {
+            source: [exp.position.trim(), exp.company.trim()].iter().filter(|part| !part.is_empty()).copied().collect::<Vec<_>>().join(" at "),
+            months: entry_months(&exp.start_date, &exp.end_date, exp.is_current, today),
+            current: exp.is_current,
+            prose,
+            tags: &[],
+        });
+    }
+    for project in resume.projects.iter().filter(|project| !project.hidden) {
+        let start = project.start_date.map(month_of);
+        entries.push(Entry {
+            source: format!("Project: {}", project.name.trim()),
+            months: start.map(|first| (first, project.end_date.map_or(first, month_of).max(first))),
+            current: false,
+            prose: vec![project.description.as_str()],
+            tags: &project.technologies,
+        });
+    }
+    for edu in resume.education.iter().filter(|edu| !edu.hidden) {
+        entries.push(Entry {
+            source: [edu.degree.trim(), edu.institution.trim()].iter().filter(|part| !part.is_empty()).copied().collect::<Vec<_>>().join(", "),
+            months: entry_months(&edu.start_date, &edu.end_date, false, today),
+            current: false,
+            prose: vec![edu.field_of_study.as_str(), edu.description.as_str()],
+            tags: &[],
+        });
+    }
+    entries
+}
+
+fn evidence(entries: &[Entry], candidate: &Candidate) -> SkillEvidence {
+    let key = key_of(&candidate.name);
+    let mut result = SkillEvidence {
+        name: candidate.name.clone(),
+        category: candidate.category.clone(),
+        sources: Vec::new(),
+        current: false,
+        last_month: None,
+        months_used: 0,
+    };
+    let mut ranges = Vec::new();
+    for entry in entries {
+        let tagged = entry.tags.iter().any(|tag| key_of(tag) == key);
+        let mentioned = entry.prose.iter().any(|text| candidate.terms.iter().any(|term| mentions(text, term)));
+        if !tagged && !mentioned {
+            continue;
+        }
+        if !result.sources.contains(&entry.source) {
+            result.sources.push(entry.source.clone());
+        }
+        result.current |= entry.current;
+        if let Some(months) = entry.months {
+            result.last_month = result.last_month.max(Some(months.1));
+            ranges.push(months);
+        }
+    }
+    result.months_used = covered_months(ranges);
+    result
+}
+
+// Whether prose mentions a skill term as a whole word
+fn mentions(text: &str, term: &str) -> bool {
+    let term = term.trim();
+    if term.chars().count() < 2 {
+        // Single letters such as "C" or "R" are too ambiguous in prose
+        return false;
+    }
+    let distinctive = term.chars().any(|c| !c.is_alphabetic());
+    let lower = term.to_lowercase();
+    if !distinctive && (term.chars().count() <= 3 || COMMON_WORDS.contains(&lower.as_str())) {
+        // Short and common words must be written like the skill, or in capitals
+        let as_written = term.chars().any(char::is_uppercase) && contains_word(text, term);
+        return as_written || contains_word(text, &term.to_uppercase());
+    }
+    contains_word(&text.to_lowercase(), &lower)
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+    let continues = |c: char| c.is_alphanumeric() || c == '+' || c == '#';
+    text.match_indices(word).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + word.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(continues)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Experience, Project, Skill};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    fn job(position: &str, start: &str, end: &str, achievements: &[&str]) -> Experience {
+        Experience {
+            company: "Acme".to_string(),
+            position: position.to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            achievements: achievements.iter().map(|text| text.to_string()).collect(),
+            ..Experience::default()
+        }
+    }
+
+    fn resume(skills: &[&str], experience: Vec<Experience>) -> Resume {
+        let mut resume = Resume { experience, ..Resume::default() };
+        resume.skills.skill_list = skills.iter().map(|name| Skill { name: name.to_string(), ..Skill::default() }).collect();
+        resume
+    }
+
+    fn missing(analysis: &SkillAnalysis) -> Vec<&str> {
+        analysis.missing.iter().map(|evidence| evidence.name.as_str()).collect()
+    }
+
+    #[test]
+    fn mentions_respect_case_for_common_words_and_word_boundaries() {
+        assert!(mentions("Rewrote the parser in Rust.", "Rust"));
+        assert!(!mentions("Removed rust from the pipes", "Rust"));
+        assert!(mentions("Designed REST APIs", "rest"));
+        assert!(!mentions("Took a rest", "rest"));
+        assert!(mentions("Built services with node.js, Go and C++", "Node.js"));
+        assert!(mentions("Built services with node.js, Go and C++", "C++"));
+        assert!(!mentions("Shipped C++ code", "C"));
+        assert!(!mentions("Wrote C# tools", "C++"));
+        assert!(!mentions("Pythonic code", "Python"));
+        assert!(mentions("Pipelines in (python)", "Python"));
+    }
+
+    #[test]
+    fn listed_skills_are_matched_through_aliases_and_dated_by_their_entries() {
+        let resume = resume(&["js", "Haskell"], vec![
+            job("Frontend Developer", "2019-01", "2020-12", &["Moved the app to JavaScript modules"]),
+            job("Lead", "2021-01", "2022-06", &["Mentored JS developers", "Reviewed JavaScript daily"]),
+        ]);
+        let analysis = analyze_skills(&resume, today());
+        let (_, js) = &analysis.listed[0];
+        assert_eq!(js.name, "JavaScript");
+        assert_eq!(js.sources, vec!["Frontend Developer at Acme", "Lead at Acme"]);
+        assert_eq!(js.months_used, 42);
+        assert_eq!(js.years_used(), Some(4));
+        assert_eq!(js.last_used(), "Jun 2022");
+
+        let unevidenced: Vec<usize> = analysis.unevidenced().map(|(index, _)| *index).collect();
+        assert_eq!(unevidenced, vec![1]);
+        assert_eq!(analysis.listed[1].1.last_used(), "");
+    }
+
+    #[test]
+    fn missing_skills_are_reported_once_most_mentioned_first() {
+        let mut current = job("Engineer", "2023-01", "", &["Runs Kubernetes clusters", "Wrote Docker images and k8s manifests"]);
+        current.is_current = true;
+        let mut resume = resume(&["Docker"], vec![current, job("Intern", "2020-06", "2020-08", &["Deployed to Kubernetes"])]);
+        resume.projects.push(Project {
+            name: "Side project".to_string(),
+            technologies: vec!["Terraform".to_string(), "docker".to_string(), "Elm-UI".to_string()],
+            ..Project::default()
+        });
+        let analysis = analyze_skills(&resume, today());
+
+        // Docker is listed; aliases of Kubernetes count towards one skill
+        assert_eq!(missing(&analysis), vec!["Kubernetes", "Elm-UI", "Terraform"]);
+        let kubernetes = &analysis.missing[0];
+        assert_eq!(kubernetes.category, "Cloud & DevOps");
+        assert_eq!(kubernetes.sources.len(), 2);
+        assert!(kubernetes.current);
+        assert_eq!(kubernetes.last_used(), "Present");
+        // Technologies outside the dictionary are reported as typed
+        assert_eq!(analysis.missing[1].category, "");
+        assert_eq!(analysis.listed[0].1.sources, vec!["Engineer at Acme", "Project: Side project"]);
+    }
+
+    #[test]
+    fn hidden_entries_are_not_evidence() {
+        let mut hidden = job("Engineer", "2020-01", "2021-01", &["Wrote Python tools"]);
+        hidden.hidden = true;
+        let analysis = analyze_skills(&resume(&["Python"], vec![hidden]), today());
+        assert_eq!(analysis.unevidenced().count(), 1);
+        assert!(analysis.missing.is_empty());
+    }
+}
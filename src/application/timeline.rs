@@ -0,0 +1,277 @@
+use chrono::NaiveDate;
+use crate::domain::{Resume, Section};
+use super::dates::{covered_months, entry_months, month_of};
+
+// Career timeline
+//
+// Reads the free-form start and end dates of experience and education
+// entries as month ranges and derives total experience, tenure per company,
+// gaps between jobs and positions held at the same time. Entries whose dates
+// cannot be read are listed separately so validation can point at them.
+
+// Gaps up to this many months are normal job changes and are not reported
+pub const DEFAULT_GAP_MONTHS: u32 = 3;
+
+// An entry placed on the timeline
+#[derive(Clone, PartialEq, Debug)]
+pub struct TimelineEntry {
+    // Experience or Education, with the entry's position in that list
+    pub section: Section,
+    pub index: usize,
+    pub title: String,
+    pub organization: String,
+    // First and last month, counted from year 0
+    pub first: i32,
+    pub last: i32,
+    pub current: bool,
+}
+
+impl TimelineEntry {
+    pub fn months(&self) -> u32 {
+        (self.last - self.first + 1) as u32
+    }
+}
+
+// Time spent at one employer, overlapping positions counted once
+#[derive(Clone, PartialEq, Debug)]
+pub struct Tenure {
+    pub company: String,
+    pub months: u32,
+    pub first: i32,
+    pub last: i32,
+    pub current: bool,
+}
+
+// Months without work or study between two periods
+#[derive(Clone, PartialEq, Debug)]
+pub struct Gap {
+    pub first: i32,
+    pub last: i32,
+    // True when the gap runs until today
+    pub ongoing: bool,
+}
+
+impl Gap {
+    pub fn months(&self) -> u32 {
+        (self.last - self.first + 1) as u32
+    }
+}
+
+// Two experience entries that ran at the same time
+#[derive(Clone, PartialEq, Debug)]
+pub struct Overlap {
+    // Positions in `Resume::experience`
+    pub first: usize,
+    pub second: usize,
+    pub months: u32,
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CareerTimeline {
+    // Dated entries, earliest start first
+    pub entries: Vec<TimelineEntry>,
+    // Entries whose dates cannot be read
+    pub undated: Vec<(Section, usize)>,
+    // Months worked, overlapping positions counted once
+    pub total_months: u32,
+    // Employers, most recent first
+    pub tenures: Vec<Tenure>,
+    pub gaps: Vec<Gap>,
+    pub overlaps: Vec<Overlap>,
+    // Current month, counted from year 0
+    pub today: i32,
+}
+
+impl CareerTimeline {
+    pub fn work(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter().filter(|entry| entry.section == Section::Experience)
+    }
+}
+
+// Analyzes the visible experience and education entries; gaps longer than
+// `gap_months` are reported, and time in education does not count as a gap
+pub fn analyze_timeline(resume: &Resume, today: NaiveDate, gap_months: u32) -> CareerTimeline {
+    let mut timeline = CareerTimeline { today: month_of(today), ..CareerTimeline::default() };
+
+    for (index, exp) in resume.experience.iter().enumerate().filter(|(_, exp)| !exp.hidden) {
+        match entry_months(&exp.start_date, &exp.end_date, exp.is_current, today) {
+            Some((first, last)) => timeline.entries.push(TimelineEntry {
+                section: Section::Experience,
+                index,
+                title: exp.position.trim().to_string(),
+                organization: exp.company.trim().to_string(),
+                first,
+                last,
+                current: exp.is_current,
+            }),
+            None => timeline.undated.push((Section::Experience, index)),
+        }
+    }
+    for (index, edu) in resume.education.iter().enumerate().filter(|(_, edu)| !edu.hidden) {
+        match entry_months(&edu.start_date, &edu.end_date, false, today) {
+            Some((first, last)) => timeline.entries.push(TimelineEntry {
+                section: Section::Education,
+                index,
+                title: edu.degree.trim().to_string(),
+                organization: edu.institution.trim().to_string(),
+                first,
+                last,
+                current: false,
+            }),
+            None => timeline.undated.push((Section::Education, index)),
+        }
+    }
+    timeline.entries.sort_by_key(|entry| (entry.first, entry.last));
+
+    let work: Vec<&TimelineEntry> = timeline.work().collect();
+    let total_months = covered_months(work.iter().map(|entry| (entry.first, entry.last)).collect());
+    let tenures = tenures(&work);
+    let employers = resume.employer_ranges();
+    let overlaps = overlaps(&work, |a, b| employers.iter().any(|range| range.contains(&a) && range.contains(&b)));
+    let gaps = gaps(&timeline.entries, timeline.today, gap_months);
+    CareerTimeline { total_months, tenures, gaps, overlaps, ..timeline }
+}
+
+fn tenures(work: &[&TimelineEntry]) -> Vec<Tenure> {
+    let mut companies: Vec<(String, Vec<&TimelineEntry>)> = Vec::new();
+    for entry in work {
+        let key = entry.organization.to_lowercase();
+        match companies.iter_mut().find(|(company, _)| *company == key) {
+            Some((_, entries)) => entries.push(entry),
+            None => companies.push((key, vec![entry])),
+        }
+    }
+    let mut tenures: Vec<Tenure> = companies.into_iter()
+        .map(|(_, entries)| Tenure {
+            company: entries[0].organization.clone(),
+            months: covered_months(entries.iter().map(|entry| (entry.first, entry.last)).collect()),
+            first: entries.iter().map(|entry| entry.first).min().unwrap_or_default(),
+            last: entries.iter().map(|entry| entry.last).max().unwrap_or_default(),
+            current: entries.iter().any(|entry| entry.current),
+        })
+        .collect();
+    tenures.sort_by_key(|tenure| std::cmp::Reverse(tenure.last));
+    tenures
+}
+
+// Positions sharing more than a month; a new job starting in the month the
+// previous one ended is an ordinary handover, and positions grouped under
+// one employer may run side by side
+fn overlaps(work: &[&TimelineEntry], same_employer: impl Fn(usize, usize) -> bool) -> Vec<Overlap> {
+    let mut overlaps = Vec::new();
+    for (position, a) in work.iter().enumerate() {
+        for b in work[position + 1..].iter().filter(|b| !same_employer(a.index, b.index)) {
+            let shared = a.last.min(b.last) - a.first.max(b.first) + 1;
+            if shared > 1 {
+                overlaps.push(Overlap { first: a.index, second: b.index, months: shared as u32 });
+            }
+        }
+    }
+    overlaps
+}
+
+// Stretches after the first job that no job or study covers
+fn gaps(entries: &[TimelineEntry], today: i32, gap_months: u32) -> Vec<Gap> {
+    let Some(start) = entries.iter().filter(|entry| entry.section == Section::Experience).map(|entry| entry.first).min() else {
+        return Vec::new();
+    };
+    let mut gaps = Vec::new();
+    let mut covered_to = start - 1;
+    for entry in entries.iter().filter(|entry| entry.last >= start) {
+        if entry.first > covered_to + 1 {
+            gaps.push(Gap { first: covered_to + 1, last: entry.first - 1, ongoing: false });
+        }
+        covered_to = covered_to.max(entry.last);
+    }
+    // Entries end with the month they ran through, so a gap up to today starts the month after
+    if covered_to < today {
+        gaps.push(Gap { first: covered_to + 1, last: today, ongoing: true });
+    }
+    gaps.retain(|gap| gap.months() > gap_months);
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Education, Experience};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    fn job(company: &str, start: &str, end: &str) -> Experience {
+        Experience {
+            company: company.to_string(),
+            position: "Engineer".to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            ..Experience::default()
+        }
+    }
+
+    fn month(year: i32, month: i32) -> i32 {
+        year * 12 + month - 1
+    }
+
+    #[test]
+    fn overlapping_roles_are_reported_unless_they_share_an_employer() {
+        let mut resume = Resume {
+            experience: vec![job("Acme", "2018-01", "2020-06"), job("Beta", "2020-01", "2021-12"), job("Gamma", "2021-12", "2023-01")],
+            ..Resume::default()
+        };
+        let timeline = analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS);
+        // Gamma starting in the month Beta ended is a handover, not an overlap
+        assert_eq!(timeline.overlaps, vec![Overlap { first: 0, second: 1, months: 6 }]);
+        assert_eq!(timeline.total_months, 61);
+
+        resume.experience[1].same_employer = true;
+        assert!(analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS).overlaps.is_empty());
+    }
+
+    #[test]
+    fn gaps_longer_than_the_threshold_are_reported_and_study_fills_them() {
+        let mut resume = Resume {
+            experience: vec![job("Acme", "2015-01", "2016-12"), job("Beta", "2018-01", "2019-12"), job("Gamma", "2020-03", "2022-12")],
+            ..Resume::default()
+        };
+        let timeline = analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS);
+        assert_eq!(timeline.gaps, vec![
+            Gap { first: month(2017, 1), last: month(2017, 12), ongoing: false },
+            Gap { first: month(2023, 1), last: month(2024, 6), ongoing: true },
+        ]);
+
+        resume.education.push(Education {
+            degree: "M.Sc.".to_string(),
+            start_date: "2017-01".to_string(),
+            end_date: "2017-12".to_string(),
+            ..Education::default()
+        });
+        let gaps = analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS).gaps;
+        assert!(gaps.iter().all(|gap| gap.ongoing));
+    }
+
+    #[test]
+    fn current_roles_without_an_end_date_run_until_today() {
+        let mut current = job("Acme", "2023-01", "");
+        current.is_current = true;
+        let resume = Resume { experience: vec![current], ..Resume::default() };
+        let timeline = analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS);
+        assert_eq!(timeline.total_months, 18);
+        assert!(timeline.gaps.is_empty());
+        assert!(timeline.tenures[0].current);
+    }
+
+    #[test]
+    fn unreadable_and_reversed_dates_stay_off_the_totals() {
+        let resume = Resume {
+            experience: vec![job("Acme", "2022-05", "2021-01"), job("Beta", "spring 2020", "2021")],
+            ..Resume::default()
+        };
+        let timeline = analyze_timeline(&resume, today(), DEFAULT_GAP_MONTHS);
+        assert_eq!(timeline.undated, vec![(Section::Experience, 1)]);
+        // An end before the start counts as the start month only
+        assert_eq!((timeline.entries[0].first, timeline.entries[0].last), (month(2022, 5), month(2022, 5)));
+        assert_eq!(timeline.total_months, 1);
+    }
+}
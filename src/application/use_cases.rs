@@ -1,14 +1,16 @@
 use crate::domain::{Resume, PersonalInfo, Education, Experience, Project, Skills, ResumeTheme};
-use crate::application::repository::ResumeRepository;
+use crate::domain::{JobApplication, ApplicationStatus, LibraryEntry, DEFAULT_RESUME_ID};
+use crate::application::repository::{AsyncResumeRepository, ApplicationRepository, LibraryRepository};
+use crate::application::merge::MergePlan;
 use std::error::Error;
 use std::rc::Rc;
 
 pub struct ResumeUseCase {
-    repository: Rc<dyn ResumeRepository>,
+    repository: Rc<dyn AsyncResumeRepository>,
 }
 
 impl ResumeUseCase {
-    pub fn new(repository: Rc<dyn ResumeRepository>) -> Self {
+    pub fn new(repository: Rc<dyn AsyncResumeRepository>) -> Self {
         Self { repository }
     }
     
@@ -16,13 +18,13 @@ impl ResumeUseCase {
         Resume::default()
     }
     
-    pub fn save_resume(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
-        self.repository.save(resume)
+    pub async fn save_resume(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
+        self.repository.save(resume).await
     }
     
-    pub fn load_resume(&self) -> Result<Resume, Box<dyn Error>> {
-        if self.repository.exists() {
-            self.repository.load()
+    pub async fn load_resume(&self) -> Result<Resume, Box<dyn Error>> {
+        if self.repository.exists().await? {
+            self.repository.load().await
         } else {
             Ok(Resume::default())
         }
@@ -60,7 +62,7 @@ impl ResumeUseCase {
     
     pub fn update_experience(&self, resume: &mut Resume, index: usize, experience: Experience) -> Result<(), &'static str> {
         if index < resume.experience.len() {
-            resume.experience[index] = experience;
+            resume.update_experience(index, experience);
             Ok(())
         } else {
             Err("Experience index out of bounds")
@@ -69,7 +71,7 @@ impl ResumeUseCase {
     
     pub fn remove_experience(&self, resume: &mut Resume, index: usize) -> Result<(), &'static str> {
         if index < resume.experience.len() {
-            resume.experience.remove(index);
+            resume.remove_experience(index);
             Ok(())
         } else {
             Err("Experience index out of bounds")
@@ -105,4 +107,236 @@ impl ResumeUseCase {
     pub fn change_theme(&self, resume: &mut Resume, theme: ResumeTheme) {
         resume.theme = theme;
     }
-} 
\ No newline at end of file
+
+    // Compares an imported resume with the current one; conflicts default to keeping the current values
+    pub fn plan_merge(&self, resume: &Resume, imported: &Resume) -> MergePlan {
+        MergePlan::new(resume, imported)
+    }
+
+    // Builds the merged resume using the choices made for each conflict
+    pub fn apply_merge(&self, plan: &MergePlan) -> Resume {
+        plan.apply()
+    }
+}
+
+
+pub struct ResumeLibraryUseCase {
+    repository: Rc<dyn LibraryRepository>,
+}
+
+impl ResumeLibraryUseCase {
+    pub fn new(repository: Rc<dyn LibraryRepository>) -> Self {
+        Self { repository }
+    }
+
+    // Loads the library; a workspace from before the library existed gets an entry for its resume
+    pub fn load_entries(&self) -> Result<Vec<LibraryEntry>, Box<dyn Error>> {
+        let entries = self.repository.load_all()?;
+        if entries.is_empty() {
+            return Ok(vec![LibraryEntry {
+                id: DEFAULT_RESUME_ID,
+                title: "My Resume".to_string(),
+                updated_on: None,
+            }]);
+        }
+        Ok(entries)
+    }
+
+    pub fn save_entries(&self, entries: &[LibraryEntry]) -> Result<(), Box<dyn Error>> {
+        self.repository.save_all(entries)
+    }
+
+    // Adds a resume with a fresh id and returns that id
+    pub fn add_entry(&self, entries: &mut Vec<LibraryEntry>, title: &str) -> u64 {
+        let id = entries.iter().map(|entry| entry.id).max().unwrap_or(0) + 1;
+        entries.push(LibraryEntry {
+            id,
+            title: title.to_string(),
+            updated_on: Some(chrono::Local::now().date_naive()),
+        });
+        id
+    }
+
+    pub fn rename_entry(&self, entries: &mut [LibraryEntry], id: u64, title: &str) -> Result<(), &'static str> {
+        match entries.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => {
+                entry.title = title.to_string();
+                Ok(())
+            }
+            None => Err("Resume not found"),
+        }
+    }
+
+    // Records that a resume was saved today
+    pub fn touch_entry(&self, entries: &mut [LibraryEntry], id: u64) -> Result<(), &'static str> {
+        match entries.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => {
+                entry.updated_on = Some(chrono::Local::now().date_naive());
+                Ok(())
+            }
+            None => Err("Resume not found"),
+        }
+    }
+
+    pub fn remove_entry(&self, entries: &mut Vec<LibraryEntry>, id: u64) -> Result<(), &'static str> {
+        if entries.len() == 1 {
+            return Err("The library must keep at least one resume");
+        }
+        let count = entries.len();
+        entries.retain(|entry| entry.id != id);
+        if entries.len() < count {
+            Ok(())
+        } else {
+            Err("Resume not found")
+        }
+    }
+}
+
+pub struct ApplicationTrackerUseCase {
+    repository: Rc<dyn ApplicationRepository>,
+}
+
+impl ApplicationTrackerUseCase {
+    pub fn new(repository: Rc<dyn ApplicationRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub fn load_applications(&self) -> Result<Vec<JobApplication>, Box<dyn Error>> {
+        self.repository.load_all()
+    }
+
+    pub fn save_applications(&self, applications: &[JobApplication]) -> Result<(), Box<dyn Error>> {
+        self.repository.save_all(applications)
+    }
+
+    // Adds an application with a fresh id and returns that id
+    pub fn add_application(&self, applications: &mut Vec<JobApplication>, mut application: JobApplication) -> u64 {
+        let id = applications.iter().map(|app| app.id).max().unwrap_or(0) + 1;
+        application.id = id;
+        applications.push(application);
+        id
+    }
+
+    pub fn update_application(&self, applications: &mut [JobApplication], application: JobApplication) -> Result<(), &'static str> {
+        match applications.iter_mut().find(|app| app.id == application.id) {
+            Some(existing) => {
+                *existing = application;
+                Ok(())
+            }
+            None => Err("Application not found"),
+        }
+    }
+
+    pub fn change_status(&self, applications: &mut [JobApplication], id: u64, status: ApplicationStatus) -> Result<(), &'static str> {
+        match applications.iter_mut().find(|app| app.id == id) {
+            Some(existing) => {
+                existing.status = status;
+                existing.last_update = Some(chrono::Local::now().date_naive());
+                Ok(())
+            }
+            None => Err("Application not found"),
+        }
+    }
+
+    pub fn remove_application(&self, applications: &mut Vec<JobApplication>, id: u64) -> Result<(), &'static str> {
+        let count = applications.len();
+        applications.retain(|app| app.id != id);
+        if applications.len() < count {
+            Ok(())
+        } else {
+            Err("Application not found")
+        }
+    }
+
+    // Adds restored applications that are not tracked yet, giving each a fresh id; returns how many were added
+    pub fn merge_applications(&self, applications: &mut Vec<JobApplication>, restored: Vec<JobApplication>) -> usize {
+        let mut added = 0;
+        for application in restored {
+            let tracked = applications.iter().any(|app| {
+                app.company == application.company
+                    && app.role == application.role
+                    && app.applied_date == application.applied_date
+            });
+            if !tracked {
+                self.add_application(applications, application);
+                added += 1;
+            }
+        }
+        added
+    }
+
+    // Exports the application pipeline as CSV with a header row
+    pub fn export_csv(&self, applications: &[JobApplication]) -> String {
+        let mut csv = String::from("id,company,role,status,applied_date,last_update,posting_url,resume,notes\n");
+        for app in applications {
+            let fields = [
+                app.id.to_string(),
+                app.company.clone(),
+                app.role.clone(),
+                app.status.name().to_string(),
+                app.applied_date.map(|date| date.to_string()).unwrap_or_default(),
+                app.last_update.map(|date| date.to_string()).unwrap_or_default(),
+                app.posting_url.clone(),
+                app.resume_label.clone(),
+                app.notes.clone(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+// Quotes a CSV field when it contains separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    // The CSV export never touches storage
+    struct NoStorage;
+
+    impl ApplicationRepository for NoStorage {
+        fn save_all(&self, _applications: &[JobApplication]) -> Result<(), Box<dyn Error>> {
+            Err("not stored".into())
+        }
+
+        fn load_all(&self) -> Result<Vec<JobApplication>, Box<dyn Error>> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn csv_quotes_separators_quotes_and_line_breaks() {
+        let tracker = ApplicationTrackerUseCase::new(Rc::new(NoStorage));
+        let applications = vec![
+            JobApplication {
+                id: 1,
+                company: "Acme, Inc.".to_string(),
+                role: "Engineer".to_string(),
+                status: ApplicationStatus::Interview,
+                applied_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+                resume_label: "Backend \"short\"".to_string(),
+                notes: "Call back\non Monday".to_string(),
+                ..JobApplication::default()
+            },
+            JobApplication { id: 2, company: "Globex".to_string(), ..JobApplication::default() },
+        ];
+        assert_eq!(
+            tracker.export_csv(&applications),
+            "id,company,role,status,applied_date,last_update,posting_url,resume,notes\n\
+             1,\"Acme, Inc.\",Engineer,Interview,2024-03-01,,,\"Backend \"\"short\"\"\",\"Call back\non Monday\"\n\
+             2,Globex,,Applied,,,,,\n"
+        );
+        assert_eq!(tracker.export_csv(&[]).lines().count(), 1);
+    }
+}
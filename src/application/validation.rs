@@ -0,0 +1,186 @@
+use chrono::NaiveDate;
+use crate::domain::{Education, Experience, Resume, Section};
+use super::dates::{duration_text, month_bounds, month_of, month_text, DATE_FORMATS};
+use super::timeline::analyze_timeline;
+
+// Something worth fixing before the resume is sent out
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValidationIssue {
+    pub section: Section,
+    // Entry the issue is about; `None` for the section as a whole
+    pub index: Option<usize>,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(section: Section, index: Option<usize>, message: String) -> Self {
+        Self { section, index, message }
+    }
+}
+
+// Checks contact details, entry dates and the career timeline; gaps longer
+// than `gap_months` are reported
+pub fn validate_resume(resume: &Resume, today: NaiveDate, gap_months: u32) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let info = &resume.personal_info;
+    if info.name.trim().is_empty() {
+        issues.push(ValidationIssue::new(Section::Personal, None, "Add your name".to_string()));
+    }
+    if info.email.trim().is_empty() && info.phone.trim().is_empty() {
+        issues.push(ValidationIssue::new(Section::Personal, None, "Add an email address or phone number".to_string()));
+    }
+
+    for (index, exp) in resume.experience.iter().enumerate().filter(|(_, exp)| !exp.hidden) {
+        let entry = experience_label(exp);
+        let end = if exp.is_current { "" } else { exp.end_date.as_str() };
+        check_dates(&mut issues, Section::Experience, index, &entry, &exp.start_date, end, true, today);
+        if !exp.is_current && exp.end_date.trim().is_empty() && !exp.start_date.trim().is_empty() {
+            issues.push(ValidationIssue::new(
+                Section::Experience,
+                Some(index),
+                format!("{} has no end date; mark it as current if you still work there", entry),
+            ));
+        }
+    }
+    for (index, edu) in resume.education.iter().enumerate().filter(|(_, edu)| !edu.hidden) {
+        check_dates(&mut issues, Section::Education, index, &education_label(edu), &edu.start_date, &edu.end_date, false, today);
+    }
+
+    let timeline = analyze_timeline(resume, today, gap_months);
+    for gap in &timeline.gaps {
+        let message = if gap.ongoing {
+            format!("No position since {} ({})", month_text(gap.first), duration_text(gap.months()))
+        } else {
+            format!("Gap of {} between {} and {}", duration_text(gap.months()), month_text(gap.first), month_text(gap.last))
+        };
+        issues.push(ValidationIssue::new(Section::Experience, None, message));
+    }
+    for overlap in &timeline.overlaps {
+        issues.push(ValidationIssue::new(
+            Section::Experience,
+            Some(overlap.second),
+            format!(
+                "{} and {} overlap by {}",
+                experience_label(&resume.experience[overlap.first]),
+                experience_label(&resume.experience[overlap.second]),
+                duration_text(overlap.months),
+            ),
+        ));
+    }
+    issues
+}
+
+// Unreadable dates, an end before the start, and starts in the future
+#[allow(clippy::too_many_arguments)]
+fn check_dates(
+    issues: &mut Vec<ValidationIssue>,
+    section: Section,
+    index: usize,
+    entry: &str,
+    start: &str,
+    end: &str,
+    start_required: bool,
+    today: NaiveDate,
+) {
+    let mut report = |message: String| issues.push(ValidationIssue::new(section, Some(index), message));
+    let start_months = month_bounds(start);
+    if start.trim().is_empty() {
+        if start_required {
+            report(format!("{} has no start date", entry));
+        }
+    } else if start_months.is_none() {
+        report(format!("Start date \"{}\" of {} is not a date like {}", start.trim(), entry, DATE_FORMATS));
+    }
+
+    let end_months = if end.trim().is_empty() || end.trim().eq_ignore_ascii_case("present") {
+        None
+    } else {
+        let months = month_bounds(end);
+        if months.is_none() {
+            report(format!("End date \"{}\" of {} is not a date like {}", end.trim(), entry, DATE_FORMATS));
+        }
+        months
+    };
+
+    if let (Some((first, _)), Some((_, last))) = (start_months, end_months) {
+        if last < first {
+            report(format!("{} ends before it starts", entry));
+        }
+    }
+    if let Some((first, _)) = start_months {
+        if first > month_of(today) {
+            report(format!("{} starts in the future", entry));
+        }
+    }
+}
+
+fn experience_label(exp: &Experience) -> String {
+    let parts: Vec<&str> = [exp.position.trim(), exp.company.trim()].into_iter().filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() { "An experience entry".to_string() } else { parts.join(" at ") }
+}
+
+fn education_label(edu: &Education) -> String {
+    let parts: Vec<&str> = [edu.degree.trim(), edu.institution.trim()].into_iter().filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() { "An education entry".to_string() } else { parts.join(", ") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::PersonalInfo;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    fn resume(experience: Vec<Experience>) -> Resume {
+        Resume {
+            personal_info: PersonalInfo { name: "Ada".to_string(), email: "ada@example.com".to_string(), ..PersonalInfo::default() },
+            experience,
+            ..Resume::default()
+        }
+    }
+
+    fn job(start: &str, end: &str, is_current: bool) -> Experience {
+        Experience {
+            company: "Acme".to_string(),
+            position: "Engineer".to_string(),
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            is_current,
+            ..Experience::default()
+        }
+    }
+
+    fn messages(resume: &Resume) -> Vec<String> {
+        validate_resume(resume, today(), 3).into_iter().map(|issue| issue.message).collect()
+    }
+
+    #[test]
+    fn complete_resumes_have_no_issues() {
+        assert!(messages(&resume(vec![job("2020-01", "", true)])).is_empty());
+    }
+
+    #[test]
+    fn ends_before_starts_and_future_starts_are_reported() {
+        let messages = messages(&resume(vec![job("2022-05", "2021-01", false), job("2030-01", "", true)]));
+        assert!(messages.contains(&"Engineer at Acme ends before it starts".to_string()));
+        assert!(messages.contains(&"Engineer at Acme starts in the future".to_string()));
+    }
+
+    #[test]
+    fn missing_and_unreadable_dates_are_reported() {
+        let messages = messages(&resume(vec![job("2020-01", "", false), job("", "2019-01", false), job("spring 2018", "2018-12", false)]));
+        assert!(messages.contains(&"Engineer at Acme has no end date; mark it as current if you still work there".to_string()));
+        assert!(messages.contains(&"Engineer at Acme has no start date".to_string()));
+        assert!(messages.iter().any(|message| message.starts_with("Start date \"spring 2018\"")));
+    }
+
+    #[test]
+    fn gaps_and_overlaps_become_issues() {
+        let issues = validate_resume(&resume(vec![job("2015-01", "2016-12", false), job("2016-06", "2018-12", false), job("2020-01", "", true)]), today(), 3);
+        assert!(issues.iter().any(|issue| issue.message == "Gap of 1 year between Jan 2019 and Dec 2019"));
+        let overlap = issues.iter().find(|issue| issue.message.contains("overlap")).unwrap();
+        assert_eq!(overlap.index, Some(1));
+    }
+}
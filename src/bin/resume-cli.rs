@@ -0,0 +1,332 @@
+// Command line interface for working with saved resume files
+
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::ExitCode;
+
+use ResumeBuilder::application::entry_order::sort_entries;
+use ResumeBuilder::domain::{LibraryEntry, PaperSize, Resume, Workspace};
+use ResumeBuilder::infrastructure::backup::{decrypt_backup, encrypt_backup, export_backup, import_backup, is_encrypted_backup};
+#[cfg(feature = "sqlite")]
+use ResumeBuilder::application::repository::ResumeRepository;
+#[cfg(feature = "sqlite")]
+use ResumeBuilder::infrastructure::sqlite::SqliteResumeRepository;
+use ResumeBuilder::infrastructure::export::{LatexTemplate, DEFAULT_TEXT_WIDTH};
+use ResumeBuilder::infrastructure::import::{import_linkedin_archive, import_text_resume};
+use ResumeBuilder::presentation::export_format::ExportFormat;
+
+const USAGE: &str = "\
+Usage: resume-cli export <resume.json> --format <format> [--width <columns>] [--template <name>] [--paper <size>] [--output <file>]
+       resume-cli import <archive.zip|resume.md|resume.txt> [--output <resume.json>]
+       resume-cli backup <resume.json>... [--applications <applications.json>] [--passphrase <passphrase>] --output <backup.zip>
+       resume-cli restore <backup.zip> [--output <dir>] [--applications <applications.json>] [--passphrase <passphrase>]
+       resume-cli db save <library.db> <resume.json> --key <name>
+       resume-cli db load <library.db> --key <name> [--snapshot <id>] [--output <resume.json>]
+       resume-cli db list <library.db>
+       resume-cli db search <library.db> <query>
+
+Formats:
+  html        Self-contained HTML page
+  docx        Word document
+  markdown    GitHub-flavored Markdown
+  text        Plain text wrapped at --width columns (default 80)
+  latex       LaTeX source using --template moderncv (default) or article
+  typst       Typst source on --paper a4 or letter (default: the resume's paper size)
+
+`import` reads a LinkedIn data-export archive or a Markdown/plain-text resume
+and writes the resume as JSON. Text imports list the guessed fields that
+should be reviewed on standard error.
+
+`backup` bundles resumes, titled after their file names, and the tracked
+applications into a backup archive. `restore` verifies an archive's checksums
+and writes each resume to <dir>/<id>-<title>.json, or all of them as one JSON
+array of titles and resumes. With --passphrase, or the RESUME_BACKUP_PASSPHRASE
+environment variable, backups are encrypted and encrypted backups are opened;
+without one the archive is plain ZIP that anyone with the file can read.
+
+`db` stores resumes in a SQLite library keyed by name, lists them with their
+saved snapshots, and searches all of them. It needs the `sqlite` feature.
+
+Without --output the result is written to standard output.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args.first().map(String::as_str) {
+        Some("export") => export(&args[1..]),
+        Some("import") => import(&args[1..]),
+        Some("backup") => backup(&args[1..]),
+        Some("restore") => restore(&args[1..]),
+        #[cfg(feature = "sqlite")]
+        Some("db") => database(&args[1..]),
+        #[cfg(not(feature = "sqlite"))]
+        Some("db") => Err("this build has no SQLite support; rebuild with --features sqlite".into()),
+        Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command '{}'", command).into()),
+        None => Err("missing command".into()),
+    }
+}
+
+fn export(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut format_name = None;
+    let mut width = DEFAULT_TEXT_WIDTH;
+    let mut template = LatexTemplate::default();
+    let mut paper = None;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format_name = Some(args.next().ok_or("--format needs a value")?.clone()),
+            "--width" => width = args.next().ok_or("--width needs a value")?.parse()?,
+            "--template" => {
+                let name = args.next().ok_or("--template needs a value")?;
+                template = LatexTemplate::from_name(name)
+                    .ok_or_else(|| format!("unknown template '{}'", name))?;
+            }
+            "--paper" => {
+                let name = args.next().ok_or("--paper needs a value")?;
+                paper = Some(PaperSize::from_name(name)
+                    .ok_or_else(|| format!("unknown paper size '{}'", name))?);
+            }
+            "--output" => output = Some(args.next().ok_or("--output needs a value")?.clone()),
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg).into()),
+        }
+    }
+
+    let input = input.ok_or("missing resume file")?;
+    let mut resume: Resume = serde_json::from_str(&fs::read_to_string(&input)?)?;
+    // Files edited by hand may list dated entries out of order
+    sort_entries(&mut resume);
+    let format = match format_name.as_deref().ok_or("missing --format")? {
+        "html" => ExportFormat::Html,
+        "docx" => ExportFormat::Docx,
+        "markdown" | "md" => ExportFormat::Markdown,
+        "text" | "txt" => ExportFormat::PlainText { width },
+        "latex" | "tex" => ExportFormat::Latex { template },
+        "typst" | "typ" => ExportFormat::Typst { paper: paper.unwrap_or(resume.paper_size) },
+        other => return Err(format!("unsupported format '{}'", other).into()),
+    };
+
+    if let Some(warning) = format.photo_warning(&resume) {
+        eprintln!("warning: {}", warning);
+    }
+    let contents = format.render(&resume)?;
+
+    match output {
+        Some(path) => fs::write(path, contents)?,
+        None => std::io::stdout().write_all(&contents)?,
+    }
+    Ok(())
+}
+
+fn import(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = Some(args.next().ok_or("--output needs a value")?.clone()),
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg).into()),
+        }
+    }
+
+    let input = input.ok_or("missing archive file")?;
+    let mut resume = if input.to_lowercase().ends_with(".zip") {
+        import_linkedin_archive(&fs::read(&input)?)?
+    } else {
+        let draft = import_text_resume(&fs::read_to_string(&input)?);
+        for field in draft.fields_to_review() {
+            eprintln!("[{}] {}: {}", field.confidence.name(), field.field, field.value);
+        }
+        draft.resume
+    };
+    sort_entries(&mut resume);
+    let json = serde_json::to_string_pretty(&resume)?;
+
+    match output {
+        Some(path) => fs::write(path, json)?,
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+fn backup(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut inputs = Vec::new();
+    let mut applications = None;
+    let mut output = None;
+    let mut passphrase = backup_passphrase();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--applications" => applications = Some(args.next().ok_or("--applications needs a value")?.clone()),
+            "--output" => output = Some(args.next().ok_or("--output needs a value")?.clone()),
+            "--passphrase" => passphrase = Some(args.next().ok_or("--passphrase needs a value")?.clone()),
+            _ => inputs.push(arg.clone()),
+        }
+    }
+
+    if inputs.is_empty() {
+        return Err("missing resume file".into());
+    }
+    let output = output.ok_or("missing --output")?;
+    let mut workspace = Workspace {
+        applications: match applications {
+            Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+            None => Vec::new(),
+        },
+        ..Workspace::default()
+    };
+    for (id, input) in (1..).zip(&inputs) {
+        let title = Path::new(input).file_stem().map_or_else(|| input.clone(), |stem| stem.to_string_lossy().into_owned());
+        workspace.library.push(LibraryEntry { id, title, updated_on: None });
+        workspace.resumes.insert(id, serde_json::from_str(&fs::read_to_string(input)?)?);
+    }
+    let archive = export_backup(&workspace)?;
+    match passphrase {
+        Some(passphrase) => fs::write(output, encrypt_backup(&archive, &passphrase)?)?,
+        None => {
+            eprintln!("warning: the backup is not encrypted; use --passphrase to encrypt it");
+            fs::write(output, archive)?;
+        }
+    }
+    Ok(())
+}
+
+fn restore(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut output = None;
+    let mut applications = None;
+    let mut passphrase = backup_passphrase();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = Some(args.next().ok_or("--output needs a value")?.clone()),
+            "--applications" => applications = Some(args.next().ok_or("--applications needs a value")?.clone()),
+            "--passphrase" => passphrase = Some(args.next().ok_or("--passphrase needs a value")?.clone()),
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg).into()),
+        }
+    }
+
+    let input = input.ok_or("missing backup file")?;
+    let mut bytes = fs::read(&input)?;
+    if is_encrypted_backup(&bytes) {
+        let passphrase = passphrase.ok_or("the backup is encrypted; use --passphrase to open it")?;
+        bytes = decrypt_backup(&bytes, &passphrase)?;
+    }
+    let (manifest, workspace) = import_backup(&bytes)?;
+    eprintln!(
+        "Backup from {} (app {}, format {}): {} resumes, {} applications",
+        manifest.created_at, manifest.app_version, manifest.format_version,
+        workspace.library.len(), workspace.applications.len()
+    );
+
+    if let Some(path) = applications {
+        fs::write(path, serde_json::to_string_pretty(&workspace.applications)?)?;
+    }
+    match output {
+        Some(dir) => {
+            fs::create_dir_all(&dir)?;
+            for entry in &workspace.library {
+                let path = Path::new(&dir).join(format!("{}-{}.json", entry.id, file_name(&entry.title)));
+                fs::write(path, serde_json::to_string_pretty(&workspace.resumes[&entry.id])?)?;
+            }
+        }
+        None => {
+            let resumes: Vec<serde_json::Value> = workspace.library.iter()
+                .map(|entry| serde_json::json!({ "title": entry.title, "resume": workspace.resumes[&entry.id] }))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&resumes)?);
+        }
+    }
+    Ok(())
+}
+
+// Passphrase for backups from the environment, so it need not appear in the process list
+fn backup_passphrase() -> Option<String> {
+    std::env::var("RESUME_BACKUP_PASSPHRASE").ok().filter(|passphrase| !passphrase.is_empty())
+}
+
+// Lowercase letters and digits of a title, with dashes for everything else
+fn file_name(title: &str) -> String {
+    let name: String = title.to_lowercase().chars().map(|c| if c.is_alphanumeric() { c } else { '-' }).collect();
+    name.split('-').filter(|part| !part.is_empty()).collect::<Vec<_>>().join("-")
+}
+
+#[cfg(feature = "sqlite")]
+fn database(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let action = args.first().ok_or("missing db action")?;
+    let mut positional = Vec::new();
+    let mut key = None;
+    let mut snapshot = None;
+    let mut output = None;
+
+    let mut args = args[1..].iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--key" => key = Some(args.next().ok_or("--key needs a value")?.clone()),
+            "--snapshot" => snapshot = Some(args.next().ok_or("--snapshot needs a value")?.parse::<i64>()?),
+            "--output" => output = Some(args.next().ok_or("--output needs a value")?.clone()),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    let path = positional.first().ok_or("missing database file")?;
+    let mut repository = SqliteResumeRepository::open(path, key.as_deref().unwrap_or_default())?;
+    match (action.as_str(), &positional[1..]) {
+        ("save", [input]) => {
+            key.ok_or("missing --key")?;
+            let resume: Resume = serde_json::from_str(&fs::read_to_string(input)?)?;
+            repository.save(&resume)?;
+        }
+        ("load", []) => {
+            key.ok_or("missing --key")?;
+            let resume = match snapshot {
+                Some(id) => repository.load_snapshot(id)?,
+                None => repository.load()?,
+            };
+            let json = serde_json::to_string_pretty(&resume)?;
+            match output {
+                Some(path) => fs::write(path, json)?,
+                None => println!("{}", json),
+            }
+        }
+        ("list", []) => {
+            for key in repository.resume_keys()? {
+                println!("{}", key);
+                repository.select(&key);
+                for snapshot in repository.snapshots()? {
+                    println!("  snapshot {} saved {}", snapshot.id, snapshot.created_at);
+                }
+            }
+        }
+        ("search", [query]) => {
+            for hit in repository.search(query)? {
+                println!("{} / {}: {}", hit.resume_key, hit.section, hit.snippet.replace('\n', " "));
+            }
+        }
+        (action, _) => return Err(format!("unknown or incomplete db action '{}'", action).into()),
+    }
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Small HTTP server that stores resumes for sync between devices
+//
+// The API itself lives in `infrastructure::sync_server`; this binary parses
+// the command line and listens. See docs/sync_api.md.
+
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use tiny_http::Server;
+
+use ResumeBuilder::infrastructure::sync_server::{run, ServerConfig};
+
+const USAGE: &str = "\
+Usage: resume-sync-server [--addr <host:port>] [--data-dir <dir>] [--token <token>]
+
+Defaults: --addr 127.0.0.1:8787, --data-dir ./sync-data. Without --token the
+RESUME_SYNC_TOKEN environment variable is used; when neither is set, requests
+are not authenticated.";
+
+struct Config {
+    addr: String,
+    server: ServerConfig,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse_args(&args).and_then(|config| serve(&config)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Config, Box<dyn Error>> {
+    let mut config = Config {
+        addr: "127.0.0.1:8787".to_string(),
+        server: ServerConfig {
+            data_dir: PathBuf::from("sync-data"),
+            token: std::env::var("RESUME_SYNC_TOKEN").ok().filter(|token| !token.is_empty()),
+        },
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => config.addr = args.next().ok_or("--addr needs a value")?.clone(),
+            "--data-dir" => config.server.data_dir = PathBuf::from(args.next().ok_or("--data-dir needs a value")?),
+            "--token" => config.server.token = Some(args.next().ok_or("--token needs a value")?.clone()),
+            _ => return Err(format!("unexpected argument '{}'", arg).into()),
+        }
+    }
+    Ok(config)
+}
+
+fn serve(config: &Config) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(&config.server.data_dir)?;
+    let server = Server::http(&config.addr).map_err(|err| format!("cannot listen on {}: {}", config.addr, err))?;
+    println!("Resume sync server listening on http://{}", config.addr);
+    run(&server, &config.server);
+    Ok(())
+}
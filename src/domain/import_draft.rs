@@ -0,0 +1,59 @@
+use crate::domain::models::Resume;
+
+// Entities describing a resume recovered from an unstructured document
+
+// How sure an importer is that a field was extracted correctly
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl Confidence {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+}
+
+// Confidence marker for one extracted field
+#[derive(Clone, PartialEq, Debug)]
+pub struct FieldConfidence {
+    // Human readable location, e.g. "Experience 2: Company"
+    pub field: String,
+    pub value: String,
+    pub confidence: Confidence,
+}
+
+// A draft resume together with a confidence marker for every extracted field
+#[derive(Default, Clone, PartialEq)]
+pub struct ImportDraft {
+    pub resume: Resume,
+    pub fields: Vec<FieldConfidence>,
+}
+
+impl ImportDraft {
+    pub fn mark(&mut self, field: impl Into<String>, value: &str, confidence: Confidence) {
+        if !value.trim().is_empty() {
+            self.fields.push(FieldConfidence {
+                field: field.into(),
+                value: value.to_string(),
+                confidence,
+            });
+        }
+    }
+
+    // Fields that should be checked before the draft is accepted, least certain first
+    pub fn fields_to_review(&self) -> Vec<FieldConfidence> {
+        let mut fields: Vec<FieldConfidence> = self.fields.iter()
+            .filter(|field| field.confidence < Confidence::High)
+            .cloned()
+            .collect();
+        fields.sort_by_key(|field| field.confidence);
+        fields
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use crate::domain::models::Resume;
+
+// Job application tracking entities
+
+// Pipeline stage of a job application
+#[derive(Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum ApplicationStatus {
+    #[default]
+    Applied,
+    Interview,
+    Offer,
+    Rejected,
+}
+
+impl ApplicationStatus {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Applied,
+            Self::Interview,
+            Self::Offer,
+            Self::Rejected,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Applied => "Applied",
+            Self::Interview => "Interview",
+            Self::Offer => "Offer",
+            Self::Rejected => "Rejected",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|status| status.name() == name)
+    }
+}
+
+// A single job application and the exact documents sent with it
+#[derive(Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobApplication {
+    pub id: u64,
+    pub company: String,
+    pub role: String,
+    pub posting_url: String,
+    pub posting_text: String,
+    pub status: ApplicationStatus,
+    pub applied_date: Option<NaiveDate>,
+    pub last_update: Option<NaiveDate>,
+    pub notes: String,
+    // Label of the resume variant that was sent, e.g. "Backend - short"
+    pub resume_label: String,
+    // Copy of the resume as it was at the time of applying
+    pub resume_snapshot: Option<Resume>,
+    pub cover_letter: String,
+}
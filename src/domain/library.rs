@@ -0,0 +1,15 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+// Resume that was stored before the library existed keeps this id
+pub const DEFAULT_RESUME_ID: u64 = 1;
+
+// One resume in the library; its content is stored separately under its id,
+// so the library can be listed without loading every resume
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct LibraryEntry {
+    pub id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub updated_on: Option<NaiveDate>,
+}
@@ -1,5 +1,17 @@
 // Domain layer - contains core business entities and rules
 pub mod models;
+pub mod profile_link;
+pub mod skill_dictionary;
+pub mod job_application;
+pub mod import_draft;
+pub mod workspace;
+pub mod library;
 pub mod sample_data;
 // Re-export domain models for easier access
-pub use models::*; 
+pub use models::*;
+pub use profile_link::*;
+pub use skill_dictionary::*;
+pub use job_application::*;
+pub use import_draft::*;
+pub use workspace::*;
+pub use library::*;
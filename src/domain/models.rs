@@ -1,6 +1,10 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
+use std::ops::Range;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
 use chrono::NaiveDate;
+use super::profile_link::{LinkKind, ProfileLink};
 
 // Core domain entities for the Resume Builder application
 
@@ -13,19 +17,341 @@ pub struct Resume {
     pub skills: Skills,
     pub projects: Vec<Project>,
     pub theme: ResumeTheme,
+    #[serde(default)]
+    pub section_order: Vec<Section>,
+    #[serde(default)]
+    pub paper_size: PaperSize,
+    #[serde(default)]
+    pub education_order: EntryOrder,
+    #[serde(default)]
+    pub experience_order: EntryOrder,
+}
+
+impl Resume {
+    // Sections in display order; sections missing from the stored order are appended
+    pub fn sections(&self) -> Vec<Section> {
+        let mut sections: Vec<Section> = Vec::new();
+        for section in self.section_order.iter().chain(Section::all().iter()) {
+            if !sections.contains(section) {
+                sections.push(*section);
+            }
+        }
+        sections
+    }
+
+    // How entries of a section are ordered; sections without dated entries are always manual
+    pub fn entry_order(&self, section: Section) -> EntryOrder {
+        match section {
+            Section::Education => self.education_order,
+            Section::Experience => self.experience_order,
+            _ => EntryOrder::Manual,
+        }
+    }
+
+    pub fn set_entry_order(&mut self, section: Section, order: EntryOrder) {
+        match section {
+            Section::Education => self.education_order = order,
+            Section::Experience => self.experience_order = order,
+            _ => {}
+        }
+    }
+
+    // Moves an entry of a manually ordered section up or down by `offset`
+    // places; returns the entry's new index
+    pub fn move_entry(&mut self, section: Section, index: usize, offset: isize) -> usize {
+        match section {
+            Section::Education => shift(&mut self.education, index, offset),
+            Section::Experience => self.move_experience(index, offset),
+            _ => index,
+        }
+    }
+
+    // Positions move within their employer; an employer with one position
+    // moves past the whole employer next to it
+    fn move_experience(&mut self, index: usize, offset: isize) -> usize {
+        let ranges = self.employer_ranges();
+        let Some(position) = ranges.iter().position(|range| range.contains(&index)) else {
+            return index;
+        };
+        let range = ranges[position].clone();
+        if range.len() > 1 {
+            let target = index as isize + offset;
+            if target < range.start as isize || target as usize >= range.end {
+                return index;
+            }
+            let target = shift(&mut self.experience, index, offset);
+            // Flags stay in place, so the employer keeps a first position
+            let leads = self.experience[index].same_employer;
+            self.experience[index].same_employer = self.experience[target].same_employer;
+            self.experience[target].same_employer = leads;
+            return target;
+        }
+        let neighbour = if offset < 0 { position.checked_sub(1) } else { Some(position + 1) };
+        let Some(neighbour) = neighbour.and_then(|neighbour| ranges.get(neighbour)).cloned() else {
+            return index;
+        };
+        let entry = self.experience.remove(index);
+        let target = if offset < 0 { neighbour.start } else { neighbour.end - 1 };
+        self.experience.insert(target, entry);
+        target
+    }
+
+    // Ranges of experience entries held at one employer: each starts with an
+    // entry that is not marked as another position at the employer above it
+    pub fn employer_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for (index, exp) in self.experience.iter().enumerate() {
+            match ranges.last_mut() {
+                Some(range) if exp.same_employer => range.end = index + 1,
+                _ => ranges.push(index..index + 1),
+            }
+        }
+        ranges
+    }
+
+    // Employers with at least one visible position; company and location come from the first of them
+    pub fn employers(&self) -> Vec<Employer<'_>> {
+        self.employer_ranges().into_iter()
+            .filter_map(|range| {
+                let roles: Vec<(usize, &Experience)> = range.map(|index| (index, &self.experience[index]))
+                    .filter(|(_, exp)| !exp.hidden)
+                    .collect();
+                let (_, head) = roles.first()?;
+                Some(Employer { company: &head.company, location: &head.location, roles })
+            })
+            .collect()
+    }
+
+    // Makes an experience entry another position at the employer of entry
+    // `target`, listed after its current positions; returns the entry's new index
+    pub fn merge_experience(&mut self, index: usize, target: usize) -> usize {
+        if index >= self.experience.len() || target >= self.experience.len() {
+            return index;
+        }
+        let Some(range) = self.employer_ranges().into_iter().find(|range| range.contains(&target)) else {
+            return index;
+        };
+        if range.contains(&index) {
+            return index;
+        }
+        let head = &self.experience[range.start];
+        let (company, location) = (head.company.clone(), head.location.clone());
+        let mut entry = self.remove_experience(index);
+        entry.company = company;
+        if entry.location.is_empty() {
+            entry.location = location;
+        }
+        entry.same_employer = true;
+        let end = if index < range.start { range.end - 1 } else { range.end };
+        self.experience.insert(end, entry);
+        end
+    }
+
+    // Turns a position at an employer with several into an employer of its
+    // own, listed right after the rest; returns the entry's new index
+    pub fn split_experience(&mut self, index: usize) -> usize {
+        let Some(range) = self.employer_ranges().into_iter().find(|range| range.contains(&index)) else {
+            return index;
+        };
+        if range.len() < 2 {
+            return index;
+        }
+        let mut entry = self.remove_experience(index);
+        entry.same_employer = false;
+        self.experience.insert(range.end - 1, entry);
+        range.end - 1
+    }
+
+    // Replaces an experience entry, keeping it at its employer; a new company
+    // name renames the employer for all of its positions
+    pub fn update_experience(&mut self, index: usize, mut experience: Experience) {
+        let Some(range) = self.employer_ranges().into_iter().find(|range| range.contains(&index)) else {
+            return;
+        };
+        experience.same_employer = self.experience[index].same_employer;
+        if experience.company != self.experience[index].company {
+            for exp in &mut self.experience[range] {
+                exp.company = experience.company.clone();
+            }
+        }
+        self.experience[index] = experience;
+    }
+
+    // Removes an experience entry; when it led an employer's positions the next one takes over
+    pub fn remove_experience(&mut self, index: usize) -> Experience {
+        let leads = !self.experience[index].same_employer;
+        if let Some(next) = self.experience.get_mut(index + 1) {
+            if leads && next.same_employer {
+                next.same_employer = false;
+            }
+        }
+        self.experience.remove(index)
+    }
+
+    // Photo to show, if the resume has one and its theme shows photos
+    pub fn displayed_photo(&self) -> Option<&ProfilePhoto> {
+        self.personal_info.photo.as_ref().filter(|_| self.theme.shows_photo())
+    }
+}
+
+// Moves an item `offset` places within a list, staying inside it; returns where it ended up
+fn shift<T>(items: &mut [T], index: usize, offset: isize) -> usize {
+    let target = index as isize + offset;
+    if index < items.len() && target >= 0 && (target as usize) < items.len() {
+        items.swap(index, target as usize);
+        return target as usize;
+    }
+    index
+}
+
+// Resume sections that can be reordered by the user
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum Section {
+    Personal,
+    Education,
+    Experience,
+    Skills,
+    Projects,
+}
+
+impl Section {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Personal,
+            Self::Education,
+            Self::Experience,
+            Self::Skills,
+            Self::Projects,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Personal => "Summary",
+            Self::Education => "Education",
+            Self::Experience => "Experience",
+            Self::Skills => "Skills",
+            Self::Projects => "Projects",
+        }
+    }
 }
 
 // Personal information section
 #[derive(Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "StoredPersonalInfo")]
 pub struct PersonalInfo {
     pub name: String,
     pub email: String,
     pub phone: String,
-    pub website: String,
-    pub linkedin: String,
-    pub github: String,
     pub location: String,
     pub summary: String,
+    pub links: Vec<ProfileLink>,
+    pub photo: Option<ProfilePhoto>,
+}
+
+impl PersonalInfo {
+    // Links worth showing, in the order the user arranged them
+    pub fn visible_links(&self) -> impl Iterator<Item = &ProfileLink> {
+        self.links.iter().filter(|link| !link.is_empty())
+    }
+}
+
+// Personal information as stored, including the fixed website, LinkedIn and
+// GitHub fields that resumes saved before profile links still carry
+#[derive(Deserialize)]
+struct StoredPersonalInfo {
+    name: String,
+    email: String,
+    phone: String,
+    location: String,
+    summary: String,
+    #[serde(default)]
+    links: Vec<ProfileLink>,
+    #[serde(default)]
+    photo: Option<ProfilePhoto>,
+    #[serde(default)]
+    website: String,
+    #[serde(default)]
+    linkedin: String,
+    #[serde(default)]
+    github: String,
+}
+
+impl From<StoredPersonalInfo> for PersonalInfo {
+    fn from(stored: StoredPersonalInfo) -> Self {
+        let legacy = [
+            (LinkKind::Website, stored.website),
+            (LinkKind::LinkedIn, stored.linkedin),
+            (LinkKind::GitHub, stored.github),
+        ];
+        let mut links: Vec<ProfileLink> = legacy.iter()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .map(|(kind, value)| ProfileLink::new(*kind, value))
+            .collect();
+        links.extend(stored.links);
+        Self {
+            name: stored.name,
+            email: stored.email,
+            phone: stored.phone,
+            location: stored.location,
+            summary: stored.summary,
+            links,
+            photo: stored.photo,
+        }
+    }
+}
+
+// Headshot cropped and downscaled in the browser, kept as JPEG bytes
+#[derive(Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfilePhoto {
+    #[serde(default)]
+    pub shape: PhotoShape,
+    #[serde(serialize_with = "serialize_base64", deserialize_with = "deserialize_base64")]
+    pub jpeg: Vec<u8>,
+}
+
+impl ProfilePhoto {
+    // Width and height of a stored photo in pixels
+    pub const SIZE_PX: u32 = 320;
+
+    pub fn data_url(&self) -> String {
+        format!("data:image/jpeg;base64,{}", BASE64.encode(&self.jpeg))
+    }
+}
+
+// Outline a profile photo is cropped to
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum PhotoShape {
+    #[default]
+    Square,
+    Circle,
+}
+
+impl PhotoShape {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Square, Self::Circle]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Square => "Square",
+            Self::Circle => "Circle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|shape| shape.name() == name)
+    }
+}
+
+// Photo bytes are kept as base64 text, which is far smaller than a JSON number array
+fn serialize_base64<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    BASE64.decode(text).map_err(serde::de::Error::custom)
 }
 
 // Education section
@@ -39,6 +365,10 @@ pub struct Education {
     pub location: String,
     pub description: String,
     pub gpa: String,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub page_breaks: PageBreaks,
 }
 
 // Work experience section
@@ -52,20 +382,212 @@ pub struct Experience {
     pub description: String,
     pub achievements: Vec<String>,
     pub is_current: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub page_breaks: PageBreaks,
+    // Another position at the employer of the entry above, shown under the same company
+    #[serde(default)]
+    pub same_employer: bool,
+}
+
+// Visible positions held at one employer, in display order
+pub struct Employer<'a> {
+    pub company: &'a str,
+    pub location: &'a str,
+    // Positions in `Resume::experience` with their entries
+    pub roles: Vec<(usize, &'a Experience)>,
+}
+
+impl Employer<'_> {
+    // Whether the employer is shown as a company block with stacked roles
+    pub fn has_several_roles(&self) -> bool {
+        self.roles.len() > 1
+    }
 }
 
 // Skill item
 #[derive(Default, Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Skill {
     pub name: String,
-    pub level: i32,  // 0-5 scale
+    pub level: i32,  // 0-5 scale, 0 meaning not rated
+    // Name of the category in `Skills::categories`; empty when uncategorized
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub years: Option<u32>,
+    // Featured skills are listed first and emphasized
+    #[serde(default)]
+    pub featured: bool,
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 // Skills section
-#[derive(Default, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "StoredSkills")]
 pub struct Skills {
-    pub categories: HashMap<String, Vec<String>>,
+    // Categories in the order they are shown
+    pub categories: Vec<String>,
     pub skill_list: Vec<Skill>,
+    // Names for proficiency levels 1 to 5
+    pub level_labels: Vec<String>,
+}
+
+impl Default for Skills {
+    fn default() -> Self {
+        Self {
+            categories: Vec::new(),
+            skill_list: Vec::new(),
+            level_labels: Self::default_level_labels(),
+        }
+    }
+}
+
+// One category of visible skills, with each skill's position in `skill_list`
+pub struct SkillGroup<'a> {
+    // Empty for skills without a category
+    pub category: &'a str,
+    pub skills: Vec<(usize, &'a Skill)>,
+}
+
+impl Skills {
+    pub const MAX_LEVEL: i32 = 5;
+
+    pub fn default_level_labels() -> Vec<String> {
+        ["Beginner", "Elementary", "Intermediate", "Advanced", "Expert"]
+            .iter()
+            .map(|label| label.to_string())
+            .collect()
+    }
+
+    // Label for a proficiency level; None when the skill is not rated
+    pub fn level_label(&self, level: i32) -> Option<&str> {
+        if level < 1 {
+            return None;
+        }
+        self.level_labels.get(level as usize - 1).map(String::as_str).filter(|label| !label.trim().is_empty())
+    }
+
+    // Short description of a skill's proficiency and experience, e.g. "Advanced, 4 years"
+    pub fn proficiency(&self, skill: &Skill) -> String {
+        let years = skill.years.map(|years| if years == 1 { "1 year".to_string() } else { format!("{} years", years) });
+        self.level_label(skill.level).map(str::to_string).into_iter().chain(years).collect::<Vec<_>>().join(", ")
+    }
+
+    // Visible skills grouped by category: listed categories in order, then
+    // categories that are not listed, then uncategorized skills; featured
+    // skills come first within each group
+    pub fn groups(&self) -> Vec<SkillGroup<'_>> {
+        let mut names: Vec<&str> = self.categories.iter().map(String::as_str).collect();
+        for skill in &self.skill_list {
+            if !skill.category.is_empty() && !names.contains(&skill.category.as_str()) {
+                names.push(&skill.category);
+            }
+        }
+        names.push("");
+        names.into_iter()
+            .map(|category| {
+                let mut skills: Vec<(usize, &Skill)> = self.skill_list.iter().enumerate()
+                    .filter(|(_, skill)| !skill.hidden && skill.category == category)
+                    .collect();
+                skills.sort_by_key(|(_, skill)| !skill.featured);
+                SkillGroup { category, skills }
+            })
+            .filter(|group| !group.skills.is_empty())
+            .collect()
+    }
+
+    pub fn add_category(&mut self, name: &str) {
+        let name = name.trim();
+        if !name.is_empty() && !self.categories.iter().any(|category| category == name) {
+            self.categories.push(name.to_string());
+        }
+    }
+
+    // Renames a category along with every skill in it
+    pub fn rename_category(&mut self, old: &str, new: &str) {
+        let new = new.trim();
+        if new.is_empty() || old == new || self.categories.iter().any(|category| category == new) {
+            return;
+        }
+        for category in self.categories.iter_mut().filter(|category| *category == old) {
+            *category = new.to_string();
+        }
+        for skill in self.skill_list.iter_mut().filter(|skill| skill.category == old) {
+            skill.category = new.to_string();
+        }
+    }
+
+    // Removes a category; its skills become uncategorized
+    pub fn remove_category(&mut self, name: &str) {
+        self.categories.retain(|category| category != name);
+        for skill in self.skill_list.iter_mut().filter(|skill| skill.category == name) {
+            skill.category.clear();
+        }
+    }
+
+    // Moves a category one place up (-1) or down (1)
+    pub fn move_category(&mut self, index: usize, offset: isize) {
+        let target = index as isize + offset;
+        if index < self.categories.len() && target >= 0 && (target as usize) < self.categories.len() {
+            self.categories.swap(index, target as usize);
+        }
+    }
+}
+
+// Categories as stored: resumes saved before ordered categories kept a map
+// from category name to the names of its skills
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredCategories {
+    Ordered(Vec<String>),
+    Legacy(HashMap<String, Vec<String>>),
+}
+
+impl Default for StoredCategories {
+    fn default() -> Self {
+        Self::Ordered(Vec::new())
+    }
+}
+
+#[derive(Deserialize)]
+struct StoredSkills {
+    #[serde(default)]
+    categories: StoredCategories,
+    #[serde(default)]
+    skill_list: Vec<Skill>,
+    #[serde(default = "Skills::default_level_labels")]
+    level_labels: Vec<String>,
+}
+
+impl From<StoredSkills> for Skills {
+    fn from(stored: StoredSkills) -> Self {
+        let mut skills = Self {
+            categories: Vec::new(),
+            skill_list: stored.skill_list,
+            level_labels: stored.level_labels,
+        };
+        match stored.categories {
+            StoredCategories::Ordered(categories) => skills.categories = categories,
+            StoredCategories::Legacy(map) => {
+                // Map order is arbitrary, so legacy categories are sorted by name
+                let mut legacy: Vec<_> = map.into_iter().collect();
+                legacy.sort();
+                for (category, names) in legacy {
+                    skills.add_category(&category);
+                    for name in names {
+                        match skills.skill_list.iter_mut().find(|skill| skill.name.eq_ignore_ascii_case(&name)) {
+                            Some(skill) if skill.category.is_empty() => skill.category = category.clone(),
+                            Some(_) => {}
+                            None => skills.skill_list.push(Skill { name, category: category.clone(), ..Skill::default() }),
+                        }
+                    }
+                }
+            }
+        }
+        skills
+    }
 }
 
 // Project section
@@ -78,6 +600,21 @@ pub struct Project {
     pub description: String,
     pub technologies: Vec<String>,
     pub url: String,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub page_breaks: PageBreaks,
+}
+
+// How an entry may be split across printed pages
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct PageBreaks {
+    // Never split the entry between two pages
+    #[serde(default)]
+    pub keep_together: bool,
+    // Always start the entry at the top of a page
+    #[serde(default)]
+    pub new_page: bool,
 }
 
 // Resume theme
@@ -88,6 +625,8 @@ pub enum ResumeTheme {
     Minimal,
     Creative,
     Modern,
+    Executive,
+    Technical,
 }
 
 impl ResumeTheme {
@@ -97,6 +636,8 @@ impl ResumeTheme {
             Self::Minimal,
             Self::Creative,
             Self::Modern,
+            Self::Executive,
+            Self::Technical,
         ]
     }
     
@@ -106,6 +647,182 @@ impl ResumeTheme {
             Self::Minimal => "Minimal",
             Self::Creative => "Creative",
             Self::Modern => "Modern",
+            Self::Executive => "Executive",
+            Self::Technical => "Technical",
         }
     }
-} 
\ No newline at end of file
+
+    // Themes for markets where a headshot is expected show the profile photo;
+    // the plainest ones leave it out
+    pub fn shows_photo(&self) -> bool {
+        !matches!(self, Self::Minimal | Self::Technical)
+    }
+
+    // Whether skills are listed with their proficiency and years of
+    // experience, or by name only
+    pub fn shows_skill_levels(&self) -> bool {
+        matches!(self, Self::Professional | Self::Modern | Self::Technical)
+    }
+} 
+
+// How the entries of a dated section are ordered
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum EntryOrder {
+    // Current positions first, then the most recent end date
+    #[default]
+    Chronological,
+    // Kept in the order the user arranged them
+    Manual,
+}
+
+impl EntryOrder {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Chronological, Self::Manual]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Chronological => "Newest first",
+            Self::Manual => "Manual",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|order| order.name().eq_ignore_ascii_case(name))
+    }
+}
+
+// Paper size used for the paginated preview, printed and typeset output
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum PaperSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PaperSize {
+    pub fn all() -> Vec<Self> {
+        vec![Self::A4, Self::Letter]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::A4 => "A4",
+            Self::Letter => "Letter",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|size| size.name().eq_ignore_ascii_case(name))
+    }
+
+    // Width and height in millimetres
+    pub fn dimensions_mm(&self) -> (f64, f64) {
+        match self {
+            Self::A4 => (210.0, 297.0),
+            Self::Letter => (215.9, 279.4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles() -> Resume {
+        let role = |company: &str, position: &str, same_employer: bool| Experience {
+            company: company.to_string(),
+            position: position.to_string(),
+            same_employer,
+            ..Experience::default()
+        };
+        Resume {
+            experience: vec![role("Acme", "Lead", false), role("Acme", "Engineer", true), role("Globex", "Intern", false)],
+            ..Resume::default()
+        }
+    }
+
+    #[test]
+    fn renaming_a_position_renames_its_employer() {
+        let mut resume = roles();
+        let head = Experience { company: "Acme Corp".to_string(), ..resume.experience[0].clone() };
+        resume.update_experience(0, head);
+        assert_eq!(resume.experience[1].company, "Acme Corp");
+        assert_eq!(resume.experience[2].company, "Globex");
+
+        resume.remove_experience(0);
+        let employers = resume.employers();
+        assert_eq!(employers.len(), 2);
+        assert_eq!(employers[0].company, "Acme Corp");
+    }
+
+    #[test]
+    fn editing_a_position_keeps_its_employer() {
+        let mut resume = roles();
+        let edited = Experience { position: "Senior Engineer".to_string(), same_employer: false, ..resume.experience[1].clone() };
+        resume.update_experience(1, edited);
+        assert_eq!(resume.employer_ranges(), vec![0..2, 2..3]);
+    }
+
+    #[test]
+    fn legacy_skill_categories_become_ordered_categories() {
+        // Skills as saved before categories were ordered: a map from category to skill names
+        let legacy = r#"{
+            "categories": {
+                "Tools": ["Git", "Docker"],
+                "Languages": ["Rust", "python", "Go"],
+                "Frameworks": []
+            },
+            "skill_list": [
+                {"name": "Rust", "level": 5},
+                {"name": "Python", "level": 3},
+                {"name": "Git", "level": 4, "category": "Tools"},
+                {"name": "Public speaking", "level": 2}
+            ]
+        }"#;
+        let first: Skills = serde_json::from_str(legacy).unwrap();
+        assert_eq!(first.categories, vec!["Frameworks", "Languages", "Tools"]);
+        assert_eq!(first.level_labels, Skills::default_level_labels());
+
+        let category = |name: &str| first.skill_list.iter().find(|skill| skill.name == name).map(|skill| skill.category.as_str());
+        assert_eq!(category("Rust"), Some("Languages"));
+        assert_eq!(category("Python"), Some("Languages"));
+        assert_eq!(category("Git"), Some("Tools"));
+        assert_eq!(category("Docker"), Some("Tools"));
+        assert_eq!(category("Go"), Some("Languages"));
+        assert_eq!(category("Public speaking"), Some(""));
+        // Listed skills keep their levels and no name is duplicated
+        assert_eq!(first.skill_list.len(), 6);
+        assert_eq!(first.skill_list[1].level, 3);
+
+        // The map's order must not leak into the result
+        for _ in 0..8 {
+            assert!(serde_json::from_str::<Skills>(legacy).unwrap() == first);
+        }
+        let saved = serde_json::to_string(&first).unwrap();
+        assert!(serde_json::from_str::<Skills>(&saved).unwrap() == first);
+    }
+
+    #[test]
+    fn legacy_profile_fields_become_links() {
+        let legacy = r#"{
+            "name": "Ada", "email": "", "phone": "", "location": "", "summary": "",
+            "website": " https://ada.dev ",
+            "linkedin": "ada-lovelace",
+            "github": "  ",
+            "links": [{"kind": "Mastodon", "value": "@ada@mastodon.social"}]
+        }"#;
+        let info: PersonalInfo = serde_json::from_str(legacy).unwrap();
+        assert_eq!(info.links, vec![
+            ProfileLink::new(LinkKind::Website, "https://ada.dev"),
+            ProfileLink::new(LinkKind::LinkedIn, "ada-lovelace"),
+            ProfileLink::new(LinkKind::Mastodon, "@ada@mastodon.social"),
+        ]);
+
+        // Saved again, the links no longer come with the legacy fields
+        let saved = serde_json::to_value(&info).unwrap();
+        assert!(saved.get("linkedin").is_none());
+        let reloaded: PersonalInfo = serde_json::from_value(saved).unwrap();
+        assert_eq!(reloaded.links, info.links);
+    }
+}
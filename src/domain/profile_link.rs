@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+
+// Kind of a profile link; it decides how a handle becomes a URL and which icon is shown
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum LinkKind {
+    #[default]
+    Website,
+    Portfolio,
+    LinkedIn,
+    GitHub,
+    GitLab,
+    StackOverflow,
+    Mastodon,
+    Orcid,
+    Other,
+}
+
+impl LinkKind {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Website,
+            Self::Portfolio,
+            Self::LinkedIn,
+            Self::GitHub,
+            Self::GitLab,
+            Self::StackOverflow,
+            Self::Mastodon,
+            Self::Orcid,
+            Self::Other,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Website => "Website",
+            Self::Portfolio => "Portfolio",
+            Self::LinkedIn => "LinkedIn",
+            Self::GitHub => "GitHub",
+            Self::GitLab => "GitLab",
+            Self::StackOverflow => "Stack Overflow",
+            Self::Mastodon => "Mastodon",
+            Self::Orcid => "ORCID",
+            Self::Other => "Other",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|kind| kind.name() == name)
+    }
+
+    // Example of what to enter, shown as a placeholder
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            Self::Website | Self::Other => "example.com",
+            Self::Portfolio => "example.com/work",
+            Self::LinkedIn | Self::GitHub | Self::GitLab => "username",
+            Self::StackOverflow => "user id, e.g. 22656",
+            Self::Mastodon => "@user@mastodon.social",
+            Self::Orcid => "0000-0002-1825-0097",
+        }
+    }
+
+    // Short mark used as the link's icon
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Website => "www",
+            Self::Portfolio => "pf",
+            Self::LinkedIn => "in",
+            Self::GitHub => "gh",
+            Self::GitLab => "gl",
+            Self::StackOverflow => "so",
+            Self::Mastodon => "m",
+            Self::Orcid => "iD",
+            Self::Other => "↗",
+        }
+    }
+
+    // Site whose profiles are addressed by a handle
+    fn profile_host(&self) -> Option<&'static str> {
+        match self {
+            Self::LinkedIn => Some("linkedin.com"),
+            Self::GitHub => Some("github.com"),
+            Self::GitLab => Some("gitlab.com"),
+            Self::StackOverflow => Some("stackoverflow.com"),
+            Self::Orcid => Some("orcid.org"),
+            Self::Website | Self::Portfolio | Self::Mastodon | Self::Other => None,
+        }
+    }
+
+    // Profile URL prefix for a bare handle
+    fn profile_base(&self) -> Option<&'static str> {
+        match self {
+            Self::LinkedIn => Some("https://www.linkedin.com/in/"),
+            Self::GitHub => Some("https://github.com/"),
+            Self::GitLab => Some("https://gitlab.com/"),
+            Self::StackOverflow => Some("https://stackoverflow.com/users/"),
+            Self::Orcid => Some("https://orcid.org/"),
+            Self::Website | Self::Portfolio | Self::Mastodon | Self::Other => None,
+        }
+    }
+
+    // Recognizes links to well-known profile sites
+    pub fn from_url(url: &str) -> Option<Self> {
+        let url = url.to_lowercase();
+        Self::all().into_iter().find(|kind| kind.profile_host().is_some_and(|host| url.contains(host)))
+    }
+}
+
+// One way to find the person online, entered as a URL or a handle
+#[derive(Default, Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct ProfileLink {
+    pub kind: LinkKind,
+    // Full URL or, for profile sites, just the handle
+    pub value: String,
+    // Text to show instead of the default label
+    #[serde(default)]
+    pub label: String,
+}
+
+impl ProfileLink {
+    pub fn new(kind: LinkKind, value: &str) -> Self {
+        Self { kind, value: value.trim().to_string(), label: String::new() }
+    }
+
+    // Full URL, built from the handle where needed
+    pub fn url(&self) -> String {
+        let value = self.value.trim();
+        if value.starts_with("http://") || value.starts_with("https://") {
+            return value.to_string();
+        }
+        if self.kind == LinkKind::Mastodon {
+            // "@user@server" lives at "https://server/@user"
+            if let Some((user, server)) = value.trim_start_matches('@').split_once('@') {
+                return format!("https://{}/@{}", server, user);
+            }
+        }
+        match (self.kind.profile_host(), self.kind.profile_base()) {
+            (Some(host), Some(base)) if !value.to_lowercase().contains(host) => {
+                format!("{}{}", base, value.trim_start_matches('@'))
+            }
+            _ => format!("https://{}", value),
+        }
+    }
+
+    // Label to show: the custom label, the address for plain websites, or the site name
+    pub fn display_label(&self) -> String {
+        if !self.label.trim().is_empty() {
+            return self.label.trim().to_string();
+        }
+        match self.kind {
+            LinkKind::Website | LinkKind::Portfolio | LinkKind::Other => self.url()
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_start_matches("www.")
+                .trim_end_matches('/')
+                .to_string(),
+            kind => kind.name().to_string(),
+        }
+    }
+
+    // Label with the handle or address, for formats without hyperlinks
+    pub fn text(&self) -> String {
+        match self.kind {
+            LinkKind::Website | LinkKind::Portfolio | LinkKind::Other if self.label.trim().is_empty() => self.display_label(),
+            _ => format!("{}: {}", self.display_label(), self.value.trim()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.trim().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(kind: LinkKind, value: &str) -> String {
+        ProfileLink::new(kind, value).url()
+    }
+
+    #[test]
+    fn addresses_without_a_scheme_get_https() {
+        assert_eq!(url(LinkKind::Website, " example.com "), "https://example.com");
+        assert_eq!(url(LinkKind::Website, "http://example.com"), "http://example.com");
+        assert_eq!(url(LinkKind::LinkedIn, "linkedin.com/in/ada"), "https://linkedin.com/in/ada");
+        assert_eq!(url(LinkKind::GitHub, "https://github.com/ada"), "https://github.com/ada");
+    }
+
+    #[test]
+    fn bare_handles_become_profile_urls() {
+        assert_eq!(url(LinkKind::GitHub, "ada"), "https://github.com/ada");
+        assert_eq!(url(LinkKind::GitHub, "@ada"), "https://github.com/ada");
+        assert_eq!(url(LinkKind::LinkedIn, "ada-lovelace"), "https://www.linkedin.com/in/ada-lovelace");
+        assert_eq!(url(LinkKind::StackOverflow, "22656"), "https://stackoverflow.com/users/22656");
+        assert_eq!(url(LinkKind::Orcid, "0000-0002-1825-0097"), "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(url(LinkKind::Mastodon, "@ada@mastodon.social"), "https://mastodon.social/@ada");
+    }
+
+    #[test]
+    fn labels_drop_the_scheme_www_and_trailing_slash() {
+        assert_eq!(ProfileLink::new(LinkKind::Website, "https://www.example.com/").display_label(), "example.com");
+        assert_eq!(ProfileLink::new(LinkKind::Portfolio, "example.com/work/").display_label(), "example.com/work");
+        assert_eq!(ProfileLink::new(LinkKind::GitHub, "ada").text(), "GitHub: ada");
+        let labelled = ProfileLink { label: " Blog ".to_string(), ..ProfileLink::new(LinkKind::Website, "example.com/") };
+        assert_eq!(labelled.text(), "Blog: example.com/");
+    }
+
+    #[test]
+    fn profile_sites_are_recognized_by_url() {
+        assert_eq!(LinkKind::from_url("https://www.LinkedIn.com/in/ada"), Some(LinkKind::LinkedIn));
+        assert_eq!(LinkKind::from_url("gitlab.com/ada"), Some(LinkKind::GitLab));
+        assert_eq!(LinkKind::from_url("https://example.com"), None);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::models::*;
+use crate::domain::profile_link::{LinkKind, ProfileLink};
 
 pub fn sample_resume() -> Resume {
     Resume {
@@ -6,12 +7,15 @@ pub fn sample_resume() -> Resume {
             name: "John Doe".to_string(),
             email: "john.doe@example.com".to_string(),
             phone: "123-456-7890".to_string(),
-            website: "https://johndoe.dev".to_string(),
-            linkedin: "johndoe".to_string(),
-            github: "johndoe".to_string(),
             location: "New York, NY".to_string(),
             summary: "Experienced software engineer with a passion for building impactful products. Skilled in designing, developing, and deploying scalable applications using modern technologies. Proven track record of leading teams, mentoring junior developers, and delivering high-quality software on time. Adept at collaborating with cross-functional teams to solve complex problems and drive innovation. Always eager to learn new tools and contribute to open source projects. Seeking opportunities to make a meaningful impact in a dynamic environment."
                 .to_string(),
+            links: vec![
+                ProfileLink::new(LinkKind::Website, "https://johndoe.dev"),
+                ProfileLink::new(LinkKind::LinkedIn, "johndoe"),
+                ProfileLink::new(LinkKind::GitHub, "johndoe"),
+            ],
+            photo: None,
         },
         education: vec![
             Education {
@@ -23,6 +27,8 @@ pub fn sample_resume() -> Resume {
                 location: "New York, NY".to_string(),
                 description: "Graduated with honors.".to_string(),
                 gpa: "3.8".to_string(),
+                hidden: false,
+                page_breaks: PageBreaks::default(),
             }
         ],
         experience: vec![
@@ -35,14 +41,32 @@ pub fn sample_resume() -> Resume {
                 description: "Worked on backend systems.".to_string(),
                 achievements: vec!["Improved API performance by 30%".to_string()],
                 is_current: false,
+                hidden: false,
+                page_breaks: PageBreaks::default(),
+                same_employer: false,
             }
         ],
         skills: Skills {
-            categories: Default::default(),
+            categories: vec!["Languages".to_string(), "Frameworks".to_string()],
             skill_list: vec![
-                Skill { name: "Rust".to_string(), level: 4 },
-                Skill { name: "React".to_string(), level: 5 },
+                Skill {
+                    name: "Rust".to_string(),
+                    level: 4,
+                    category: "Languages".to_string(),
+                    years: Some(3),
+                    featured: true,
+                    hidden: false,
+                },
+                Skill {
+                    name: "React".to_string(),
+                    level: 5,
+                    category: "Frameworks".to_string(),
+                    years: Some(5),
+                    featured: false,
+                    hidden: false,
+                },
             ],
+            level_labels: Skills::default_level_labels(),
         },
         projects: vec![
             Project {
@@ -53,8 +77,14 @@ pub fn sample_resume() -> Resume {
                 description: "A CLI tool for productivity.".to_string(),
                 technologies: vec!["Rust".to_string(), "CLI".to_string()],
                 url: "https://github.com/johndoe/cli".to_string(),
+                hidden: false,
+                page_breaks: PageBreaks::default(),
             }
         ],
         theme: ResumeTheme::Professional,
+        section_order: Section::all(),
+        paper_size: PaperSize::default(),
+        education_order: EntryOrder::default(),
+        experience_order: EntryOrder::default(),
     }
 } 
\ No newline at end of file
@@ -0,0 +1,235 @@
+use std::sync::OnceLock;
+use super::models::{Resume, Skill};
+
+// Offline dictionary of well-known skills, bundled with the app so
+// autocomplete and normalization work without a network connection
+const DICTIONARY: &str = include_str!("skill_dictionary.txt");
+
+// A skill from the bundled dictionary
+#[derive(Debug, PartialEq)]
+pub struct KnownSkill {
+    // Canonical spelling, e.g. "JavaScript"
+    pub name: &'static str,
+    pub category: &'static str,
+    // Other ways people write it, e.g. "js"
+    pub aliases: Vec<&'static str>,
+}
+
+impl KnownSkill {
+    fn keys(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(self.name).chain(self.aliases.iter().copied()).map(skill_key)
+    }
+}
+
+// All skills in the dictionary, in the order they are listed
+pub fn known_skills() -> &'static [KnownSkill] {
+    static SKILLS: OnceLock<Vec<KnownSkill>> = OnceLock::new();
+    SKILLS.get_or_init(|| {
+        DICTIONARY.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut parts = line.split('|').map(str::trim);
+                let name = parts.next().filter(|name| !name.is_empty())?;
+                let category = parts.next().unwrap_or_default();
+                let aliases = parts.next().unwrap_or_default()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|alias| !alias.is_empty())
+                    .collect();
+                Some(KnownSkill { name, category, aliases })
+            })
+            .collect()
+    })
+}
+
+// Comparison key that ignores case and separators, so "Node.js", "nodejs"
+// and "Node JS" match while "C", "C#" and "C++" stay apart
+pub fn skill_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || *c == '+' || *c == '#')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// Dictionary entry for a name or alias
+pub fn find_known_skill(name: &str) -> Option<&'static KnownSkill> {
+    let key = skill_key(name);
+    if key.is_empty() {
+        return None;
+    }
+    known_skills().iter().find(|skill| skill.keys().any(|known| known == key))
+}
+
+// Canonical spelling of a skill, or the name as typed when it is not in the dictionary
+pub fn canonical_skill_name(name: &str) -> String {
+    find_known_skill(name).map(|skill| skill.name.to_string()).unwrap_or_else(|| name.trim().to_string())
+}
+
+// Dictionary skills matching what has been typed so far: exact matches
+// first, then names and aliases starting with it, then names containing it
+pub fn suggest_skills(typed: &str, limit: usize) -> Vec<&'static KnownSkill> {
+    let key = skill_key(typed);
+    if key.is_empty() {
+        return Vec::new();
+    }
+    let mut ranked: Vec<(usize, &KnownSkill)> = known_skills().iter()
+        .filter_map(|skill| {
+            let name = skill_key(skill.name);
+            let rank = if skill.keys().any(|known| known == key) {
+                0
+            } else if name.starts_with(&key) {
+                1
+            } else if skill.aliases.iter().any(|alias| skill_key(alias).starts_with(&key)) {
+                2
+            } else if name.contains(&key) {
+                3
+            } else {
+                return None;
+            };
+            Some((rank, skill))
+        })
+        .collect();
+    ranked.sort_by_key(|(rank, skill)| (*rank, skill.name.len()));
+    ranked.into_iter().take(limit).map(|(_, skill)| skill).collect()
+}
+
+// What `normalize_skills` changed
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct SkillNormalization {
+    pub renamed: usize,
+    pub merged: usize,
+}
+
+impl SkillNormalization {
+    pub fn is_empty(&self) -> bool {
+        self.renamed == 0 && self.merged == 0
+    }
+
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "Skills are already consistent".to_string();
+        }
+        let plural = |count: usize, word: &str| format!("{} {}{}", count, word, if count == 1 { "" } else { "s" });
+        format!("Renamed {}, merged {}", plural(self.renamed, "skill"), plural(self.merged, "duplicate"))
+    }
+}
+
+// Renames skills and project technologies to their canonical spelling and
+// merges skills that turn out to be the same, keeping the first one's place
+pub fn normalize_skills(resume: &mut Resume) -> SkillNormalization {
+    let mut result = SkillNormalization::default();
+
+    let mut skills: Vec<Skill> = Vec::new();
+    for mut skill in std::mem::take(&mut resume.skills.skill_list) {
+        let canonical = canonical_skill_name(&skill.name);
+        if canonical != skill.name {
+            skill.name = canonical;
+            result.renamed += 1;
+        }
+        match skills.iter_mut().find(|kept| skill_key(&kept.name) == skill_key(&skill.name)) {
+            Some(kept) => {
+                merge_skill(kept, skill);
+                result.merged += 1;
+            }
+            None => skills.push(skill),
+        }
+    }
+    resume.skills.skill_list = skills;
+
+    for project in &mut resume.projects {
+        let mut technologies: Vec<String> = Vec::new();
+        for technology in std::mem::take(&mut project.technologies) {
+            let canonical = canonical_skill_name(&technology);
+            if canonical != technology {
+                result.renamed += 1;
+            }
+            if technologies.iter().any(|kept| skill_key(kept) == skill_key(&canonical)) {
+                result.merged += 1;
+            } else {
+                technologies.push(canonical);
+            }
+        }
+        project.technologies = technologies;
+    }
+
+    result
+}
+
+// Keeps the strongest details of two entries for the same skill
+fn merge_skill(kept: &mut Skill, duplicate: Skill) {
+    kept.level = kept.level.max(duplicate.level);
+    kept.years = kept.years.max(duplicate.years);
+    kept.featured |= duplicate.featured;
+    kept.hidden &= duplicate.hidden;
+    if kept.category.is_empty() {
+        kept.category = duplicate.category;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Project;
+
+    fn skill(name: &str, level: i32) -> Skill {
+        Skill { name: name.to_string(), level, ..Skill::default() }
+    }
+
+    #[test]
+    fn names_match_ignoring_case_and_punctuation() {
+        assert_eq!(canonical_skill_name("javascript"), "JavaScript");
+        assert_eq!(canonical_skill_name(" JS "), "JavaScript");
+        assert_eq!(canonical_skill_name("golang"), "Go");
+        assert_eq!(skill_key("Node.js"), skill_key("node JS"));
+        // Symbols that tell languages apart are kept
+        assert_eq!(canonical_skill_name("c++"), "C++");
+        assert_eq!(canonical_skill_name("c#"), "C#");
+        assert_eq!(canonical_skill_name("C"), "C");
+        // Unknown names are kept as typed
+        assert_eq!(canonical_skill_name(" Juggling "), "Juggling");
+        assert!(find_known_skill("...").is_none());
+    }
+
+    #[test]
+    fn every_dictionary_key_is_unique() {
+        let mut seen = std::collections::HashMap::new();
+        for skill in known_skills() {
+            for key in skill.keys() {
+                assert_eq!(*seen.entry(key.clone()).or_insert(skill.name), skill.name, "{key}");
+            }
+        }
+    }
+
+    #[test]
+    fn suggestions_rank_exact_matches_first() {
+        let names = |typed: &str| suggest_skills(typed, 5).iter().map(|skill| skill.name).collect::<Vec<_>>();
+        assert_eq!(names("ts").first(), Some(&"TypeScript"));
+        assert!(names("java").starts_with(&["Java", "JavaScript"]));
+        assert_eq!(suggest_skills("py", 1)[0].name, "Python");
+        assert!(names("  ").is_empty());
+        assert!(suggest_skills("a", 3).len() <= 3);
+    }
+
+    #[test]
+    fn duplicates_are_merged_into_the_first_entry() {
+        let mut resume = Resume::default();
+        let mut first = skill("js", 2);
+        first.hidden = true;
+        let second = Skill { featured: true, years: Some(4), category: "Web".to_string(), ..skill("JavaScript", 4) };
+        resume.skills.skill_list = vec![first, skill("Juggling", 1), second, skill("java script", 0)];
+        resume.projects.push(Project { technologies: vec!["ts".to_string(), "TypeScript".to_string(), "Rust".to_string()], ..Project::default() });
+
+        let result = normalize_skills(&mut resume);
+        assert_eq!(result, SkillNormalization { renamed: 3, merged: 3 });
+        assert_eq!(result.summary(), "Renamed 3 skills, merged 3 duplicates");
+        let names: Vec<&str> = resume.skills.skill_list.iter().map(|skill| skill.name.as_str()).collect();
+        assert_eq!(names, vec!["JavaScript", "Juggling"]);
+        let merged = &resume.skills.skill_list[0];
+        assert_eq!((merged.level, merged.years, merged.featured, merged.hidden), (4, Some(4), true, false));
+        assert_eq!(merged.category, "Web");
+        assert_eq!(resume.projects[0].technologies, vec!["TypeScript", "Rust"]);
+
+        assert!(normalize_skills(&mut resume).is_empty());
+    }
+}
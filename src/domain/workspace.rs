@@ -0,0 +1,16 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use crate::domain::job_application::JobApplication;
+use crate::domain::library::LibraryEntry;
+use crate::domain::models::Resume;
+
+// Everything the app stores: the library with every resume and its theme,
+// and the tracked applications together with their resume snapshots and cover letters
+#[derive(Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Workspace {
+    pub library: Vec<LibraryEntry>,
+    // Content of each library entry, by the entry's id
+    pub resumes: BTreeMap<u64, Resume>,
+    pub applications: Vec<JobApplication>,
+}
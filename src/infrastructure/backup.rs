@@ -0,0 +1,268 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::io::{Cursor, Read, Write};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::domain::{JobApplication, LibraryEntry, Resume, Workspace, DEFAULT_RESUME_ID};
+use crate::infrastructure::encryption::{is_sealed, open_with_passphrase, seal_with_passphrase};
+
+// Workspace backup archive
+//
+// A backup is a ZIP file with one JSON document per kind of data and a
+// manifest.json listing each document with its schema version, size and
+// SHA-256 checksum. Restoring reads and verifies every document before the
+// workspace is returned, so a damaged archive never reaches the repository.
+//
+// Format version 1 held a single resume; it is read as a library with that
+// resume as its only entry.
+//
+// An archive is plain ZIP unless a passphrase is given: an encrypted backup is
+// the whole archive sealed in the same envelope as encrypted app data, with its
+// own salt, so it does not depend on the passphrase of the device it came from.
+
+pub const BACKUP_FORMAT: &str = "resume-builder-backup";
+pub const BACKUP_FORMAT_VERSION: u32 = 2;
+
+const MANIFEST_PATH: &str = "manifest.json";
+const LIBRARY_PATH: &str = "library.json";
+const RESUMES_PATH: &str = "resumes.json";
+const APPLICATIONS_PATH: &str = "applications.json";
+const LIBRARY_SCHEMA_VERSION: u32 = 1;
+const RESUMES_SCHEMA_VERSION: u32 = 1;
+const RESUME_SCHEMA_VERSION: u32 = 1;
+const APPLICATIONS_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: String,
+    pub format_version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    pub entries: Vec<BackupEntry>,
+}
+
+// One document in the archive
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    pub path: String,
+    pub schema: String,
+    pub schema_version: u32,
+    pub size: u64,
+    pub sha256: String,
+}
+
+// Writes the whole workspace into a backup archive
+pub fn export_backup(workspace: &Workspace) -> Result<Vec<u8>, Box<dyn Error>> {
+    let documents = [
+        (LIBRARY_PATH, "library", LIBRARY_SCHEMA_VERSION, serde_json::to_vec_pretty(&workspace.library)?),
+        (RESUMES_PATH, "resumes", RESUMES_SCHEMA_VERSION, serde_json::to_vec_pretty(&workspace.resumes)?),
+        (APPLICATIONS_PATH, "applications", APPLICATIONS_SCHEMA_VERSION, serde_json::to_vec_pretty(&workspace.applications)?),
+    ];
+
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT.to_string(),
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        entries: documents.iter()
+            .map(|(path, schema, schema_version, contents)| BackupEntry {
+                path: path.to_string(),
+                schema: schema.to_string(),
+                schema_version: *schema_version,
+                size: contents.len() as u64,
+                sha256: sha256_hex(contents),
+            })
+            .collect(),
+    };
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(MANIFEST_PATH, options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+    for (path, _, _, contents) in &documents {
+        zip.start_file(*path, options)?;
+        zip.write_all(contents)?;
+    }
+    Ok(zip.finish()?.into_inner())
+}
+
+// Seals a backup archive with a passphrase
+pub fn encrypt_backup(archive: &[u8], passphrase: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(seal_with_passphrase(archive, passphrase)?.into_bytes())
+}
+
+pub fn is_encrypted_backup(bytes: &[u8]) -> bool {
+    is_sealed(bytes)
+}
+
+// Opens an encrypted backup into the archive it holds
+pub fn decrypt_backup(bytes: &[u8], passphrase: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    open_with_passphrase(std::str::from_utf8(bytes)?, passphrase)
+}
+
+// Reads a backup archive, checking its format, schema versions and checksums
+pub fn import_backup(bytes: &[u8]) -> Result<(BackupManifest, Workspace), Box<dyn Error>> {
+    if is_encrypted_backup(bytes) {
+        return Err("The backup is encrypted. Enter its passphrase to open it.".into());
+    }
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|_| "Not a backup archive")?;
+    let manifest: BackupManifest = serde_json::from_slice(&read_file(&mut archive, MANIFEST_PATH)?)
+        .map_err(|err| format!("Invalid backup manifest: {}", err))?;
+
+    if manifest.format != BACKUP_FORMAT {
+        return Err("Not a Resume Builder backup".into());
+    }
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "This backup uses format version {}, but this version of the app only reads up to {}",
+            manifest.format_version, BACKUP_FORMAT_VERSION
+        ).into());
+    }
+
+    let mut documents = HashMap::new();
+    for entry in &manifest.entries {
+        let contents = read_file(&mut archive, &entry.path)?;
+        if contents.len() as u64 != entry.size || sha256_hex(&contents) != entry.sha256 {
+            return Err(format!("Checksum mismatch for {}; the backup is damaged", entry.path).into());
+        }
+        documents.insert(entry.schema.as_str(), (entry, contents));
+    }
+
+    let applications: Vec<JobApplication> = parse_document(&documents, "applications", APPLICATIONS_SCHEMA_VERSION)?;
+    if manifest.format_version < 2 {
+        let resume: Resume = parse_document(&documents, "resume", RESUME_SCHEMA_VERSION)?;
+        let entry = LibraryEntry { id: DEFAULT_RESUME_ID, title: "My Resume".to_string(), updated_on: None };
+        let resumes = BTreeMap::from([(DEFAULT_RESUME_ID, resume)]);
+        return Ok((manifest, Workspace { library: vec![entry], resumes, applications }));
+    }
+
+    let library: Vec<LibraryEntry> = parse_document(&documents, "library", LIBRARY_SCHEMA_VERSION)?;
+    let resumes: BTreeMap<u64, Resume> = parse_document(&documents, "resumes", RESUMES_SCHEMA_VERSION)?;
+    if library.is_empty() {
+        return Err("The backup has no resumes".into());
+    }
+    if let Some(entry) = library.iter().find(|entry| !resumes.contains_key(&entry.id)) {
+        return Err(format!("The backup has no content for the resume \"{}\"", entry.title).into());
+    }
+    Ok((manifest, Workspace { library, resumes, applications }))
+}
+
+fn read_file(archive: &mut ZipArchive<Cursor<&[u8]>>, path: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut file = archive.by_name(path).map_err(|_| format!("{} is missing from the backup", path))?;
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)?;
+    Ok(contents)
+}
+
+fn parse_document<T: serde::de::DeserializeOwned>(
+    documents: &HashMap<&str, (&BackupEntry, Vec<u8>)>,
+    schema: &str,
+    supported_version: u32,
+) -> Result<T, Box<dyn Error>> {
+    let (entry, contents) = documents.get(schema).ok_or_else(|| format!("The backup has no {} data", schema))?;
+    if entry.schema_version > supported_version {
+        return Err(format!("The {} data in this backup is from a newer version of the app", schema).into());
+    }
+    serde_json::from_slice(contents).map_err(|err| format!("Invalid {} data in backup: {}", schema, err).into())
+}
+
+fn sha256_hex(contents: &[u8]) -> String {
+    Sha256::digest(contents).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, title: &str) -> LibraryEntry {
+        LibraryEntry { id, title: title.to_string(), updated_on: None }
+    }
+
+    fn named(name: &str) -> Resume {
+        let mut resume = Resume::default();
+        resume.personal_info.name = name.to_string();
+        resume
+    }
+
+    // Archive in the given format with the given documents, listed in a valid manifest
+    fn archive(format_version: u32, documents: &[(&str, &str, Vec<u8>)]) -> Vec<u8> {
+        let manifest = BackupManifest {
+            format: BACKUP_FORMAT.to_string(),
+            format_version,
+            app_version: "0.1.0".to_string(),
+            created_at: "2026-01-01T00:00:00+00:00".to_string(),
+            entries: documents.iter()
+                .map(|(path, schema, contents)| BackupEntry {
+                    path: path.to_string(),
+                    schema: schema.to_string(),
+                    schema_version: 1,
+                    size: contents.len() as u64,
+                    sha256: sha256_hex(contents),
+                })
+                .collect(),
+        };
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file(MANIFEST_PATH, SimpleFileOptions::default()).unwrap();
+        zip.write_all(&serde_json::to_vec(&manifest).unwrap()).unwrap();
+        for (path, _, contents) in documents {
+            zip.start_file(*path, SimpleFileOptions::default()).unwrap();
+            zip.write_all(contents).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn every_resume_in_the_library_is_backed_up() {
+        let workspace = Workspace {
+            library: vec![entry(1, "Backend"), entry(4, "Frontend")],
+            resumes: BTreeMap::from([(1, named("Ada")), (4, named("Ada L."))]),
+            applications: vec![JobApplication { company: "Acme".to_string(), ..JobApplication::default() }],
+        };
+        let (manifest, restored) = import_backup(&export_backup(&workspace).unwrap()).unwrap();
+        assert_eq!(manifest.format_version, BACKUP_FORMAT_VERSION);
+        assert!(restored == workspace);
+    }
+
+    #[test]
+    fn single_resume_backups_become_a_library_of_one() {
+        let applications = serde_json::to_vec(&Vec::<JobApplication>::new()).unwrap();
+        let bytes = archive(1, &[
+            ("resume.json", "resume", serde_json::to_vec(&named("Ada")).unwrap()),
+            ("applications.json", "applications", applications),
+        ]);
+        let (_, restored) = import_backup(&bytes).unwrap();
+        assert_eq!(restored.library, vec![entry(DEFAULT_RESUME_ID, "My Resume")]);
+        assert!(restored.resumes[&DEFAULT_RESUME_ID] == named("Ada"));
+    }
+
+    #[test]
+    fn library_entries_without_content_are_refused() {
+        let bytes = archive(2, &[
+            ("library.json", "library", serde_json::to_vec(&vec![entry(1, "Backend"), entry(2, "Frontend")]).unwrap()),
+            ("resumes.json", "resumes", serde_json::to_vec(&BTreeMap::from([(1, named("Ada"))])).unwrap()),
+            ("applications.json", "applications", b"[]".to_vec()),
+        ]);
+        let err = import_backup(&bytes).err().unwrap();
+        assert!(err.to_string().contains("Frontend"));
+    }
+
+    #[test]
+    fn encrypted_backups_open_only_with_their_passphrase() {
+        let workspace = Workspace {
+            library: vec![entry(1, "Backend")],
+            resumes: BTreeMap::from([(1, named("Ada"))]),
+            applications: Vec::new(),
+        };
+        let sealed = encrypt_backup(&export_backup(&workspace).unwrap(), "backup passphrase").unwrap();
+        assert!(is_encrypted_backup(&sealed));
+        assert!(import_backup(&sealed).is_err());
+        assert!(decrypt_backup(&sealed, "wrong passphrase").is_err());
+
+        let (_, restored) = import_backup(&decrypt_backup(&sealed, "backup passphrase").unwrap()).unwrap();
+        assert!(restored == workspace);
+    }
+}
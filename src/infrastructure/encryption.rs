@@ -0,0 +1,602 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::application::repository::{
+    ApplicationRepository, AsyncResumeRepository, DocumentStore, LibraryRepository, RepositoryFuture, TextStore,
+};
+use crate::domain::{JobApplication, LibraryEntry, Resume};
+
+// Encryption at rest
+//
+// Every stored document (the library, the tracked applications with their
+// resume snapshots, and each resume) is sealed with AES-256-GCM under a key
+// derived from the user's passphrase with Argon2id. The stored document is an
+// envelope holding the KDF parameters, salt, nonce and ciphertext, so it can be
+// opened again after the defaults change. Without a passphrase the wrappers
+// store plain JSON, which keeps encryption optional for the same backends.
+
+const ENVELOPE_FORMAT: &str = "resume-builder-encrypted";
+const ENVELOPE_VERSION: u32 = 1;
+const SALT_LENGTH: usize = 16;
+const KEY_LENGTH: usize = 32;
+const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+// Reasons encrypted data cannot be read or written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionError {
+    Locked,
+    WrongPassphrase,
+    NotEncrypted,
+    WeakPassphrase,
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Locked => write!(f, "Your data is encrypted. Unlock it with your passphrase first."),
+            Self::WrongPassphrase => write!(f, "Wrong passphrase. Check it and try again."),
+            Self::NotEncrypted => write!(f, "The data is not encrypted."),
+            Self::WeakPassphrase => write!(f, "The passphrase must be at least {} characters long.", MIN_PASSPHRASE_LENGTH),
+        }
+    }
+}
+
+impl Error for EncryptionError {}
+
+// Whether a stored document is encrypted and, if so, whether it is unlocked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncryptionState {
+    Unencrypted,
+    Locked,
+    Unlocked,
+}
+
+// Argon2id cost parameters, stored with each envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Default for KdfParams {
+    // OWASP's recommended minimum for Argon2id
+    fn default() -> Self {
+        Self { memory_kib: 19 * 1024, iterations: 2, parallelism: 1 }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    format: String,
+    version: u32,
+    kdf: KdfParams,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl Envelope {
+    // Recognizes an encrypted document; plain JSON yields None
+    fn parse(document: &str) -> Option<Self> {
+        serde_json::from_str::<Self>(document)
+            .ok()
+            .filter(|envelope| envelope.format == ENVELOPE_FORMAT)
+    }
+}
+
+// Key derived from the passphrase together with the salt and parameters it came from
+#[derive(Clone)]
+struct SealingKey {
+    key: [u8; KEY_LENGTH],
+    salt: Vec<u8>,
+    kdf: KdfParams,
+}
+
+impl SealingKey {
+    fn derive(passphrase: &str, salt: &[u8], kdf: KdfParams) -> Result<Self, Box<dyn Error>> {
+        let params = Params::new(kdf.memory_kib, kdf.iterations, kdf.parallelism, Some(KEY_LENGTH))
+            .map_err(|err| format!("Invalid key derivation parameters: {}", err))?;
+        let mut key = [0u8; KEY_LENGTH];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|err| format!("Key derivation failed: {}", err))?;
+        Ok(Self { key, salt: salt.to_vec(), kdf })
+    }
+
+    // Derives a key from a fresh random salt, used whenever a passphrase is set
+    fn generate(passphrase: &str) -> Result<Self, Box<dyn Error>> {
+        if passphrase.chars().count() < MIN_PASSPHRASE_LENGTH {
+            return Err(EncryptionError::WeakPassphrase.into());
+        }
+        let mut salt = [0u8; SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        Self::derive(passphrase, &salt, KdfParams::default())
+    }
+
+    // Derives the key that matches an existing envelope
+    fn for_envelope(passphrase: &str, envelope: &Envelope) -> Result<Self, Box<dyn Error>> {
+        let salt = BASE64.decode(&envelope.salt)?;
+        Self::derive(passphrase, &salt, envelope.kdf)
+    }
+
+    // Whether this key opens documents sealed with the given salt and parameters
+    fn matches(&self, envelope: &Envelope) -> bool {
+        self.kdf == envelope.kdf && BASE64.encode(&self.salt) == envelope.salt
+    }
+
+    fn seal(&self, plaintext: &[u8]) -> Result<String, Box<dyn Error>> {
+        let cipher = Aes256Gcm::new_from_slice(&self.key).map_err(|_| "Invalid encryption key")?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| "Failed to encrypt data")?;
+
+        let envelope = Envelope {
+            format: ENVELOPE_FORMAT.to_string(),
+            version: ENVELOPE_VERSION,
+            kdf: self.kdf,
+            salt: BASE64.encode(&self.salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        };
+        Ok(serde_json::to_string(&envelope)?)
+    }
+
+    // Authentication failure means the key is wrong or the data was tampered with
+    fn open(&self, envelope: &Envelope) -> Result<Vec<u8>, Box<dyn Error>> {
+        let cipher = Aes256Gcm::new_from_slice(&self.key).map_err(|_| "Invalid encryption key")?;
+        let nonce = BASE64.decode(&envelope.nonce)?;
+        if nonce.len() != 12 {
+            return Err("Encrypted data has an invalid nonce".into());
+        }
+        let ciphertext = BASE64.decode(&envelope.ciphertext)?;
+        Ok(cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| EncryptionError::WrongPassphrase)?)
+    }
+
+    fn open_text(&self, envelope: &Envelope) -> Result<String, Box<dyn Error>> {
+        Ok(String::from_utf8(self.open(envelope)?)?)
+    }
+}
+
+// Seals data such as a backup archive under its own passphrase, independent of the app's keyring
+pub fn seal_with_passphrase(data: &[u8], passphrase: &str) -> Result<String, Box<dyn Error>> {
+    SealingKey::generate(passphrase)?.seal(data)
+}
+
+// Opens data sealed by `seal_with_passphrase`
+pub fn open_with_passphrase(document: &str, passphrase: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let envelope = Envelope::parse(document).ok_or(EncryptionError::NotEncrypted)?;
+    SealingKey::for_envelope(passphrase, &envelope)?.open(&envelope)
+}
+
+// Whether the data is an encryption envelope rather than plain content
+pub fn is_sealed(data: &[u8]) -> bool {
+    std::str::from_utf8(data).ok().and_then(Envelope::parse).is_some()
+}
+
+// Keys of the app's encryption, shared by every encrypted store
+//
+// One passphrase protects the library, the applications and every resume.
+// Only keys derived from it are kept, never the passphrase itself. Documents
+// sealed under another salt, such as resumes encrypted on their own before the
+// whole app could be, are unlocked once with their passphrase and sealed under
+// the current key when they are next saved.
+#[derive(Default)]
+pub struct Keyring {
+    // Key new documents are sealed with; None while encryption is off or locked
+    key: RefCell<Option<SealingKey>>,
+    // Keys that opened documents sealed under other salts
+    derived: RefCell<Vec<SealingKey>>,
+}
+
+// A stored document as it was read, with its plain JSON
+struct ReadDocument {
+    stored: String,
+    json: String,
+}
+
+impl Keyring {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Whether encryption is on and unlocked, so new documents are sealed
+    pub fn is_unlocked(&self) -> bool {
+        self.key.borrow().is_some()
+    }
+
+    // Plain JSON of a stored document, opening it when it is encrypted
+    pub fn open(&self, document: &str) -> Result<String, Box<dyn Error>> {
+        match Envelope::parse(document) {
+            Some(envelope) => self.open_envelope(&envelope),
+            None => Ok(document.to_string()),
+        }
+    }
+
+    // Document to store for the given JSON: sealed while encryption is on, and
+    // never plain over a stored document that is encrypted but still locked
+    pub fn seal(&self, json: &str, stored: Option<&str>) -> Result<String, Box<dyn Error>> {
+        if let Some(key) = self.key.borrow().as_ref() {
+            return key.seal(json.as_bytes());
+        }
+        if stored.and_then(Envelope::parse).is_some() {
+            return Err(EncryptionError::Locked.into());
+        }
+        Ok(json.to_string())
+    }
+
+    // Whether a stored document is encrypted and, if so, whether this keyring opens it
+    pub fn state(&self, document: Option<&str>) -> EncryptionState {
+        match document.and_then(Envelope::parse) {
+            None => EncryptionState::Unencrypted,
+            Some(envelope) if self.open_envelope(&envelope).is_ok() => EncryptionState::Unlocked,
+            Some(_) => EncryptionState::Locked,
+        }
+    }
+
+    // Checks the passphrase against an encrypted document and keeps its key for
+    // this session; the first key also seals every document saved later
+    pub fn unlock(&self, document: &str, passphrase: &str) -> Result<(), Box<dyn Error>> {
+        let envelope = Envelope::parse(document).ok_or(EncryptionError::NotEncrypted)?;
+        let key = SealingKey::for_envelope(passphrase, &envelope)?;
+        key.open(&envelope)?;
+        if self.is_unlocked() {
+            self.derived.borrow_mut().push(key);
+        } else {
+            self.key.replace(Some(key));
+        }
+        Ok(())
+    }
+
+    // Turns encryption on, sealing every stored document under the new passphrase
+    pub async fn encrypt(&self, stores: &[Rc<dyn DocumentStore>], passphrase: &str) -> Result<(), Box<dyn Error>> {
+        if self.is_unlocked() {
+            return Err("Your data is already encrypted. Change the passphrase instead.".into());
+        }
+        let key = SealingKey::generate(passphrase)?;
+        self.reseal(stores, Some(key)).await
+    }
+
+    // Re-encrypts every stored document under a new passphrase and salt
+    pub async fn change_passphrase(&self, stores: &[Rc<dyn DocumentStore>], current: &str, new: &str) -> Result<(), Box<dyn Error>> {
+        self.check(current)?;
+        let key = SealingKey::generate(new)?;
+        self.reseal(stores, Some(key)).await
+    }
+
+    // Turns encryption off, storing every document as plain JSON again
+    pub async fn decrypt(&self, stores: &[Rc<dyn DocumentStore>], current: &str) -> Result<(), Box<dyn Error>> {
+        self.check(current)?;
+        self.reseal(stores, None).await
+    }
+
+    // The passphrase is checked by deriving the current key from it again
+    fn check(&self, passphrase: &str) -> Result<(), Box<dyn Error>> {
+        let key = self.key.borrow().clone().ok_or(EncryptionError::NotEncrypted)?;
+        let derived = SealingKey::derive(passphrase, &key.salt, key.kdf)?;
+        // Compared without stopping at the first difference
+        let difference = derived.key.iter().zip(key.key.iter()).fold(0, |acc, (a, b)| acc | (a ^ b));
+        match difference {
+            0 => Ok(()),
+            _ => Err(EncryptionError::WrongPassphrase.into()),
+        }
+    }
+
+    fn open_envelope(&self, envelope: &Envelope) -> Result<String, Box<dyn Error>> {
+        let key = self.key.borrow().iter()
+            .chain(self.derived.borrow().iter())
+            .find(|key| key.matches(envelope))
+            .cloned()
+            .ok_or(EncryptionError::Locked)?;
+        key.open_text(envelope)
+    }
+
+    // Stores every document again under the given key, or as plain JSON
+    //
+    // Everything is read and sealed before the first write, so a locked
+    // document stops the change before anything was written. A write that
+    // fails puts back the documents written before it, so every document
+    // still opens with the old passphrase; the keyring only switches keys
+    // once all writes succeeded.
+    async fn reseal(&self, stores: &[Rc<dyn DocumentStore>], key: Option<SealingKey>) -> Result<(), Box<dyn Error>> {
+        let mut documents = Vec::new();
+        for store in stores {
+            documents.push(match store.load_document().await? {
+                Some(stored) => Some(ReadDocument { json: self.open(&stored)?, stored }),
+                None => None,
+            });
+        }
+        let mut sealed = Vec::new();
+        for document in &documents {
+            sealed.push(match (document, &key) {
+                (Some(document), Some(key)) => Some(key.seal(document.json.as_bytes())?),
+                (Some(document), None) => Some(document.json.clone()),
+                (None, _) => None,
+            });
+        }
+
+        for (written, (store, document)) in stores.iter().zip(&sealed).enumerate() {
+            let Some(document) = document else {
+                continue;
+            };
+            if let Err(err) = store.save_document(document).await {
+                for (store, original) in stores.iter().zip(&documents).take(written) {
+                    if let Some(original) = original {
+                        if let Err(err) = store.save_document(&original.stored).await {
+                            tracing::error!("Error restoring a document after a failed re-encryption: {}", err);
+                        }
+                    }
+                }
+                return Err(err);
+            }
+        }
+
+        self.key.replace(key);
+        self.derived.borrow_mut().clear();
+        Ok(())
+    }
+}
+
+// Repository wrapper that encrypts the resume before it reaches the underlying store
+pub struct EncryptedResumeRepository {
+    store: Rc<dyn DocumentStore>,
+    keyring: Rc<Keyring>,
+}
+
+impl EncryptedResumeRepository {
+    pub fn new(store: Rc<dyn DocumentStore>, keyring: Rc<Keyring>) -> Self {
+        Self { store, keyring }
+    }
+
+    pub async fn state(&self) -> Result<EncryptionState, Box<dyn Error>> {
+        Ok(self.keyring.state(self.store.load_document().await?.as_deref()))
+    }
+
+    // Checks the passphrase against the stored resume and keeps it for this session
+    pub async fn unlock(&self, passphrase: &str) -> Result<(), Box<dyn Error>> {
+        let document = self.store.load_document().await?.ok_or(EncryptionError::NotEncrypted)?;
+        self.keyring.unlock(&document, passphrase)
+    }
+}
+
+impl AsyncResumeRepository for EncryptedResumeRepository {
+    fn save<'a>(&'a self, resume: &'a Resume) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move {
+            let json = serde_json::to_string(resume)?;
+            let stored = match self.keyring.is_unlocked() {
+                true => None,
+                false => self.store.load_document().await?,
+            };
+            let document = self.keyring.seal(&json, stored.as_deref())?;
+            self.store.save_document(&document).await
+        })
+    }
+
+    fn load(&self) -> RepositoryFuture<'_, Resume> {
+        Box::pin(async move {
+            let document = self.store.load_document().await?.ok_or("Resume not found in storage")?;
+            Ok(serde_json::from_str(&self.keyring.open(&document)?)?)
+        })
+    }
+
+    fn exists(&self) -> RepositoryFuture<'_, bool> {
+        Box::pin(async move { Ok(self.store.load_document().await?.is_some()) })
+    }
+}
+
+// Library or application list kept as one document, sealed with the app's keyring
+pub struct EncryptedListRepository {
+    store: Rc<dyn TextStore>,
+    keyring: Rc<Keyring>,
+}
+
+impl EncryptedListRepository {
+    pub fn new(store: Rc<dyn TextStore>, keyring: Rc<Keyring>) -> Self {
+        Self { store, keyring }
+    }
+
+    pub fn state(&self) -> Result<EncryptionState, Box<dyn Error>> {
+        Ok(self.keyring.state(self.store.load_text()?.as_deref()))
+    }
+
+    // Checks the passphrase against the stored list and keeps it for this session
+    pub fn unlock(&self, passphrase: &str) -> Result<(), Box<dyn Error>> {
+        let document = self.store.load_text()?.ok_or(EncryptionError::NotEncrypted)?;
+        self.keyring.unlock(&document, passphrase)
+    }
+
+    fn save_list<T: Serialize>(&self, items: &[T]) -> Result<(), Box<dyn Error>> {
+        let stored = match self.keyring.is_unlocked() {
+            true => None,
+            false => self.store.load_text()?,
+        };
+        self.store.save_text(&self.keyring.seal(&serde_json::to_string(items)?, stored.as_deref())?)
+    }
+
+    fn load_list<T: DeserializeOwned>(&self) -> Result<Vec<T>, Box<dyn Error>> {
+        match self.store.load_text()? {
+            Some(document) => Ok(serde_json::from_str(&self.keyring.open(&document)?)?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl ApplicationRepository for EncryptedListRepository {
+    fn save_all(&self, applications: &[JobApplication]) -> Result<(), Box<dyn Error>> {
+        self.save_list(applications)
+    }
+
+    fn load_all(&self) -> Result<Vec<JobApplication>, Box<dyn Error>> {
+        self.load_list()
+    }
+}
+
+impl LibraryRepository for EncryptedListRepository {
+    fn save_all(&self, entries: &[LibraryEntry]) -> Result<(), Box<dyn Error>> {
+        self.save_list(entries)
+    }
+
+    fn load_all(&self) -> Result<Vec<LibraryEntry>, Box<dyn Error>> {
+        self.load_list()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    const PASSPHRASE: &str = "correct horse battery";
+
+    // Memory stores never wait, so every future is ready when first polled
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match std::pin::pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future was not ready"),
+        }
+    }
+
+    #[derive(Default)]
+    struct Memory(RefCell<Option<String>>);
+
+    impl TextStore for Memory {
+        fn save_text(&self, document: &str) -> Result<(), Box<dyn Error>> {
+            self.0.replace(Some(document.to_string()));
+            Ok(())
+        }
+
+        fn load_text(&self) -> Result<Option<String>, Box<dyn Error>> {
+            Ok(self.0.borrow().clone())
+        }
+    }
+
+    // Store whose writes fail while its flag is set
+    #[derive(Default)]
+    struct Failing(Memory, std::cell::Cell<bool>);
+
+    impl TextStore for Failing {
+        fn save_text(&self, document: &str) -> Result<(), Box<dyn Error>> {
+            match self.1.get() {
+                true => Err("Storage is full".into()),
+                false => self.0.save_text(document),
+            }
+        }
+
+        fn load_text(&self) -> Result<Option<String>, Box<dyn Error>> {
+            self.0.load_text()
+        }
+    }
+
+    fn entries() -> Vec<LibraryEntry> {
+        vec![LibraryEntry { id: 1, title: "Backend".to_string(), updated_on: None }]
+    }
+
+    #[test]
+    fn encrypting_seals_every_store_until_the_passphrase_is_entered_again() {
+        let library = Rc::new(Memory::default());
+        let resume = Rc::new(Memory::default());
+        let keyring = Rc::new(Keyring::new());
+        LibraryRepository::save_all(&EncryptedListRepository::new(library.clone(), keyring.clone()), &entries()).unwrap();
+        ready(EncryptedResumeRepository::new(resume.clone(), keyring.clone()).save(&Resume::default())).unwrap();
+
+        let stores: Vec<Rc<dyn DocumentStore>> = vec![library.clone(), resume.clone()];
+        ready(keyring.encrypt(&stores, PASSPHRASE)).unwrap();
+        assert!(is_sealed(library.0.borrow().as_deref().unwrap().as_bytes()));
+        assert!(is_sealed(resume.0.borrow().as_deref().unwrap().as_bytes()));
+
+        // A new session starts locked and refuses to overwrite the sealed list
+        let repository = EncryptedListRepository::new(library.clone(), Rc::new(Keyring::new()));
+        assert_eq!(repository.state().unwrap(), EncryptionState::Locked);
+        assert!(LibraryRepository::load_all(&repository).is_err());
+        assert!(LibraryRepository::save_all(&repository, &[]).is_err());
+        assert!(repository.unlock("wrong passphrase").is_err());
+
+        repository.unlock(PASSPHRASE).unwrap();
+        assert_eq!(LibraryRepository::load_all(&repository).unwrap(), entries());
+    }
+
+    #[test]
+    fn decrypting_needs_the_current_passphrase() {
+        let library = Rc::new(Memory::default());
+        let keyring = Rc::new(Keyring::new());
+        let repository = EncryptedListRepository::new(library.clone(), keyring.clone());
+        LibraryRepository::save_all(&repository, &entries()).unwrap();
+
+        let stores: Vec<Rc<dyn DocumentStore>> = vec![library.clone()];
+        assert!(ready(keyring.encrypt(&stores, "short")).is_err());
+        ready(keyring.encrypt(&stores, PASSPHRASE)).unwrap();
+        assert!(ready(keyring.decrypt(&stores, "wrong passphrase")).is_err());
+
+        ready(keyring.decrypt(&stores, PASSPHRASE)).unwrap();
+        assert!(!keyring.is_unlocked());
+        let stored: Vec<LibraryEntry> = serde_json::from_str(library.0.borrow().as_deref().unwrap()).unwrap();
+        assert_eq!(stored, entries());
+    }
+
+    #[test]
+    fn documents_sealed_under_another_salt_are_resealed_with_the_current_key() {
+        let resume = Rc::new(Memory::default());
+        resume.save_text(&seal_with_passphrase(b"{}", "older passphrase").unwrap()).unwrap();
+        let library = Rc::new(Memory::default());
+        library.save_text(&seal_with_passphrase(b"[]", PASSPHRASE).unwrap()).unwrap();
+
+        let keyring = Rc::new(Keyring::new());
+        EncryptedListRepository::new(library, keyring.clone()).unlock(PASSPHRASE).unwrap();
+        let repository = EncryptedResumeRepository::new(resume.clone(), keyring.clone());
+        assert_eq!(ready(repository.state()).unwrap(), EncryptionState::Locked);
+        ready(repository.unlock("older passphrase")).unwrap();
+        assert_eq!(ready(repository.state()).unwrap(), EncryptionState::Unlocked);
+
+        ready(repository.save(&Resume::default())).unwrap();
+        let resealed = Envelope::parse(resume.0.borrow().as_deref().unwrap()).unwrap();
+        assert!(keyring.key.borrow().as_ref().unwrap().matches(&resealed));
+    }
+
+    #[test]
+    fn failed_writes_leave_every_document_under_the_old_passphrase() {
+        let library = Rc::new(Memory::default());
+        let applications = Rc::new(Failing::default());
+        let keyring = Rc::new(Keyring::new());
+        LibraryRepository::save_all(&EncryptedListRepository::new(library.clone(), keyring.clone()), &entries()).unwrap();
+        applications.0.save_text("[]").unwrap();
+        let stores: Vec<Rc<dyn DocumentStore>> = vec![library.clone(), applications.clone()];
+
+        applications.1.set(true);
+        assert!(ready(keyring.encrypt(&stores, PASSPHRASE)).is_err());
+        assert!(!keyring.is_unlocked());
+        assert!(!is_sealed(library.0.borrow().as_deref().unwrap().as_bytes()));
+
+        applications.1.set(false);
+        ready(keyring.encrypt(&stores, PASSPHRASE)).unwrap();
+        applications.1.set(true);
+        assert!(ready(keyring.change_passphrase(&stores, PASSPHRASE, "new passphrase")).is_err());
+
+        let reopened = EncryptedListRepository::new(library.clone(), Rc::new(Keyring::new()));
+        reopened.unlock(PASSPHRASE).unwrap();
+        assert_eq!(LibraryRepository::load_all(&reopened).unwrap(), entries());
+        assert!(ready(keyring.decrypt(&stores, "new passphrase")).is_err());
+    }
+
+    #[test]
+    fn data_sealed_with_a_passphrase_opens_only_with_it() {
+        let sealed = seal_with_passphrase(b"archive", PASSPHRASE).unwrap();
+        assert_eq!(open_with_passphrase(&sealed, PASSPHRASE).unwrap(), b"archive");
+        assert!(open_with_passphrase(&sealed, "wrong passphrase").is_err());
+        assert!(!is_sealed(b"PK\x03\x04"));
+    }
+}
@@ -0,0 +1,507 @@
+use std::error::Error;
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+use crate::application::dates::employer_dates;
+use crate::domain::{PhotoShape, ProfilePhoto, Resume, Section};
+use super::{date_range, profile_url, project_dates, skill_groups, theme_style, ThemeStyle};
+
+// DOCX export
+//
+// Writes a minimal but valid Office Open XML package: the document body,
+// styles derived from the resume theme, a bullet numbering definition,
+// relationships for external hyperlinks and, when the theme shows it, the
+// profile photo floating at the top right.
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Default Extension="jpeg" ContentType="image/jpeg"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/>
+<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+</Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>
+</Relationships>"#;
+
+const NUMBERING: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:abstractNum w:abstractNumId="0">
+<w:multiLevelType w:val="singleLevel"/>
+<w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="bullet"/><w:lvlText w:val="•"/><w:lvlJc w:val="left"/><w:pPr><w:ind w:left="360" w:hanging="360"/></w:pPr></w:lvl>
+</w:abstractNum>
+<w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+</w:numbering>"#;
+
+// Numbering instance used for bullet lists
+const BULLET_NUM_ID: u32 = 1;
+
+// Side of the profile photo: one inch, in EMU
+const PHOTO_EMU: u32 = 914_400;
+
+// Renders a resume as a DOCX package
+pub fn export_docx(resume: &Resume) -> Result<Vec<u8>, Box<dyn Error>> {
+    let style = theme_style(resume.theme);
+    let mut document = DocumentBuilder::default();
+    write_body(resume, &mut document);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let parts = [
+        ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+        ("_rels/.rels", PACKAGE_RELS.to_string()),
+        ("docProps/core.xml", core_properties(resume)),
+        ("word/document.xml", document.document_xml()),
+        ("word/styles.xml", styles_xml(&style)),
+        ("word/numbering.xml", NUMBERING.to_string()),
+        ("word/_rels/document.xml.rels", document.relationships_xml()),
+    ];
+    for (name, contents) in parts {
+        zip.start_file(name, options)?;
+        zip.write_all(contents.as_bytes())?;
+    }
+    if let Some(photo) = resume.displayed_photo() {
+        // JPEG data is already compressed
+        zip.start_file("word/media/photo.jpeg", options.compression_method(CompressionMethod::Stored))?;
+        zip.write_all(&photo.jpeg)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+// Writes all visible sections of the resume in display order
+fn write_body(resume: &Resume, doc: &mut DocumentBuilder) {
+    let info = &resume.personal_info;
+    doc.title(&info.name, resume.displayed_photo());
+
+    let mut contacts: Vec<Run> = Vec::new();
+    if !info.email.is_empty() {
+        contacts.push(Run::link(&info.email, &format!("mailto:{}", info.email)));
+    }
+    for value in [&info.phone, &info.location] {
+        if !value.is_empty() {
+            contacts.push(Run::text(value));
+        }
+    }
+    for link in info.visible_links() {
+        contacts.push(Run::link(&link.display_label(), &link.url()));
+    }
+    if !contacts.is_empty() {
+        let mut runs = Vec::new();
+        for (index, contact) in contacts.into_iter().enumerate() {
+            if index > 0 {
+                runs.push(Run::text(" | "));
+            }
+            runs.push(contact);
+        }
+        doc.paragraph("Contact", &runs);
+    }
+
+    for section in resume.sections() {
+        match section {
+            Section::Personal => {
+                if !info.summary.is_empty() {
+                    doc.paragraph("Heading1", &[Run::text("Summary")]);
+                    doc.text_block(&info.summary);
+                }
+            }
+            Section::Education => {
+                let entries: Vec<_> = resume.education.iter().filter(|edu| !edu.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                doc.paragraph("Heading1", &[Run::text("Education")]);
+                for edu in entries {
+                    doc.paragraph("Heading2", &[Run::text(&edu.institution)]);
+                    doc.paragraph("Normal", &[Run::text(&format!("{} in {}", edu.degree, edu.field_of_study))]);
+                    doc.details(&[date_range(&edu.start_date, &edu.end_date, false), edu.location.clone()]);
+                    if !edu.gpa.is_empty() {
+                        doc.paragraph("Normal", &[Run::text(&format!("GPA: {}", edu.gpa))]);
+                    }
+                    doc.text_block(&edu.description);
+                }
+            }
+            Section::Experience => {
+                let employers = resume.employers();
+                if employers.is_empty() {
+                    continue;
+                }
+                doc.paragraph("Heading1", &[Run::text("Experience")]);
+                for employer in employers {
+                    // Several positions at one employer are listed under one company heading
+                    if employer.has_several_roles() {
+                        let (start, end, is_current) = employer_dates(&employer);
+                        doc.paragraph("Heading2", &[Run::text(employer.company)]);
+                        doc.details(&[date_range(start, end, is_current), employer.location.to_string()]);
+                    }
+                    for (_, exp) in employer.roles.iter() {
+                        if employer.has_several_roles() {
+                            doc.paragraph("Normal", &[Run::bold(&exp.position)]);
+                            doc.details(&[date_range(&exp.start_date, &exp.end_date, exp.is_current)]);
+                        } else {
+                            doc.paragraph("Heading2", &[Run::text(&format!("{}, {}", exp.position, exp.company))]);
+                            doc.details(&[date_range(&exp.start_date, &exp.end_date, exp.is_current), exp.location.clone()]);
+                        }
+                        doc.text_block(&exp.description);
+                        for achievement in exp.achievements.iter() {
+                            doc.bullet(&[Run::text(achievement)]);
+                        }
+                    }
+                }
+            }
+            Section::Skills => {
+                let groups = skill_groups(resume);
+                if groups.is_empty() {
+                    continue;
+                }
+                doc.paragraph("Heading1", &[Run::text("Skills")]);
+                for (category, skills) in groups {
+                    if category.is_empty() {
+                        doc.paragraph("Normal", &[Run::text(&skills.join(", "))]);
+                    } else {
+                        doc.paragraph("Normal", &[Run::bold(&format!("{}: ", category)), Run::text(&skills.join(", "))]);
+                    }
+                }
+            }
+            Section::Projects => {
+                let entries: Vec<_> = resume.projects.iter().filter(|project| !project.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                doc.paragraph("Heading1", &[Run::text("Projects")]);
+                for project in entries {
+                    let title = if project.role.is_empty() {
+                        project.name.clone()
+                    } else {
+                        format!("{}, {}", project.name, project.role)
+                    };
+                    doc.paragraph("Heading2", &[Run::text(&title)]);
+                    doc.details(&[project_dates(project)]);
+                    if !project.url.is_empty() {
+                        doc.paragraph("Normal", &[Run::link(&project.url, &profile_url(&project.url, "https://"))]);
+                    }
+                    doc.text_block(&project.description);
+                    if !project.technologies.is_empty() {
+                        doc.paragraph("Normal", &[
+                            Run::bold("Technologies: "),
+                            Run::text(&project.technologies.join(", ")),
+                        ]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// A run of text inside a paragraph, optionally bold or linked
+struct Run {
+    text: String,
+    bold: bool,
+    link: Option<String>,
+}
+
+impl Run {
+    fn text(text: &str) -> Self {
+        Self { text: text.to_string(), bold: false, link: None }
+    }
+
+    fn bold(text: &str) -> Self {
+        Self { text: text.to_string(), bold: true, link: None }
+    }
+
+    fn link(text: &str, url: &str) -> Self {
+        Self { text: text.to_string(), bold: false, link: Some(url.to_string()) }
+    }
+}
+
+// Accumulates the document body and the hyperlink relationships it needs
+#[derive(Default)]
+struct DocumentBuilder {
+    body: String,
+    links: Vec<String>,
+    has_photo: bool,
+}
+
+impl DocumentBuilder {
+    // The name, with the photo anchored to its paragraph so the text flows around it
+    fn title(&mut self, name: &str, photo: Option<&ProfilePhoto>) {
+        self.body.push_str("<w:p><w:pPr><w:pStyle w:val=\"Title\"/></w:pPr>");
+        if let Some(photo) = photo {
+            self.has_photo = true;
+            self.body.push_str(&photo_drawing(photo.shape));
+        }
+        self.runs(&[Run::text(name)]);
+        self.body.push_str("</w:p>");
+    }
+
+    fn paragraph(&mut self, style: &str, runs: &[Run]) {
+        self.body.push_str(&format!("<w:p><w:pPr><w:pStyle w:val=\"{}\"/></w:pPr>", style));
+        self.runs(runs);
+        self.body.push_str("</w:p>");
+    }
+
+    fn bullet(&mut self, runs: &[Run]) {
+        self.body.push_str(&format!(
+            "<w:p><w:pPr><w:pStyle w:val=\"ListParagraph\"/><w:numPr><w:ilvl w:val=\"0\"/><w:numId w:val=\"{}\"/></w:numPr></w:pPr>",
+            BULLET_NUM_ID
+        ));
+        self.runs(runs);
+        self.body.push_str("</w:p>");
+    }
+
+    // One paragraph per line of a multi-line text
+    fn text_block(&mut self, text: &str) {
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            self.paragraph("Normal", &[Run::text(line)]);
+        }
+    }
+
+    // Dates and locations joined on a single muted line
+    fn details(&mut self, details: &[String]) {
+        let details: Vec<&str> = details.iter()
+            .map(String::as_str)
+            .filter(|detail| !detail.is_empty())
+            .collect();
+        if !details.is_empty() {
+            self.paragraph("Details", &[Run::text(&details.join(" | "))]);
+        }
+    }
+
+    fn runs(&mut self, runs: &[Run]) {
+        for run in runs {
+            let properties = if run.bold { "<w:rPr><w:b/></w:rPr>" } else { "" };
+            let text = format!(
+                "<w:r>{}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+                properties,
+                escape_xml(&run.text)
+            );
+            match &run.link {
+                Some(url) => {
+                    self.links.push(url.clone());
+                    let id = format!("rIdLink{}", self.links.len());
+                    self.body.push_str(&format!(
+                        "<w:hyperlink r:id=\"{}\"><w:r><w:rPr><w:rStyle w:val=\"Hyperlink\"/></w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r></w:hyperlink>",
+                        id,
+                        escape_xml(&run.text)
+                    ));
+                }
+                None => self.body.push_str(&text),
+            }
+        }
+    }
+
+    fn document_xml(&self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <w:document xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\" \
+             xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\" \
+             xmlns:wp=\"http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing\" \
+             xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" \
+             xmlns:pic=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">\
+             <w:body>{}<w:sectPr><w:pgMar w:top=\"1080\" w:right=\"1080\" w:bottom=\"1080\" w:left=\"1080\" \
+             w:header=\"708\" w:footer=\"708\" w:gutter=\"0\"/></w:sectPr></w:body></w:document>",
+            self.body
+        )
+    }
+
+    fn relationships_xml(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+             <Relationship Id=\"rIdStyles\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\
+             <Relationship Id=\"rIdNumbering\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering\" Target=\"numbering.xml\"/>",
+        );
+        if self.has_photo {
+            xml.push_str(
+                "<Relationship Id=\"rIdPhoto\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/image\" Target=\"media/photo.jpeg\"/>",
+            );
+        }
+        for (index, url) in self.links.iter().enumerate() {
+            xml.push_str(&format!(
+                "<Relationship Id=\"rIdLink{}\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink\" Target=\"{}\" TargetMode=\"External\"/>",
+                index + 1,
+                escape_xml(url)
+            ));
+        }
+        xml.push_str("</Relationships>");
+        xml
+    }
+}
+
+// Run holding the photo, floating at the right margin; circles are clipped by an ellipse
+fn photo_drawing(shape: PhotoShape) -> String {
+    let geometry = match shape {
+        PhotoShape::Square => "rect",
+        PhotoShape::Circle => "ellipse",
+    };
+    format!(
+        "<w:r><w:drawing><wp:anchor distT=\"0\" distB=\"0\" distL=\"114300\" distR=\"0\" simplePos=\"0\" \
+         relativeHeight=\"1\" behindDoc=\"0\" locked=\"0\" layoutInCell=\"1\" allowOverlap=\"0\">\
+         <wp:simplePos x=\"0\" y=\"0\"/>\
+         <wp:positionH relativeFrom=\"margin\"><wp:align>right</wp:align></wp:positionH>\
+         <wp:positionV relativeFrom=\"paragraph\"><wp:posOffset>0</wp:posOffset></wp:positionV>\
+         <wp:extent cx=\"{size}\" cy=\"{size}\"/><wp:effectExtent l=\"0\" t=\"0\" r=\"0\" b=\"0\"/>\
+         <wp:wrapSquare wrapText=\"left\"/><wp:docPr id=\"1\" name=\"Photo\"/><wp:cNvGraphicFramePr/>\
+         <a:graphic><a:graphicData uri=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">\
+         <pic:pic><pic:nvPicPr><pic:cNvPr id=\"1\" name=\"photo.jpeg\"/><pic:cNvPicPr/></pic:nvPicPr>\
+         <pic:blipFill><a:blip r:embed=\"rIdPhoto\"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>\
+         <pic:spPr><a:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"{size}\" cy=\"{size}\"/></a:xfrm>\
+         <a:prstGeom prst=\"{geometry}\"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>\
+         </a:graphicData></a:graphic></wp:anchor></w:drawing></w:r>",
+        size = PHOTO_EMU,
+    )
+}
+
+// Paragraph and character styles derived from the theme
+fn styles_xml(style: &ThemeStyle) -> String {
+    let heading = escape_xml(style.heading_font);
+    let body = escape_xml(style.body_font);
+    let accent = style.accent_color;
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <w:styles xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\">\
+         <w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii=\"{body}\" w:hAnsi=\"{body}\" w:cs=\"{body}\"/>\
+         <w:sz w:val=\"21\"/><w:szCs w:val=\"21\"/></w:rPr></w:rPrDefault>\
+         <w:pPrDefault><w:pPr><w:spacing w:after=\"60\"/></w:pPr></w:pPrDefault></w:docDefaults>\
+         <w:style w:type=\"paragraph\" w:default=\"1\" w:styleId=\"Normal\"><w:name w:val=\"Normal\"/><w:qFormat/></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"Title\"><w:name w:val=\"Title\"/><w:basedOn w:val=\"Normal\"/><w:next w:val=\"Normal\"/><w:qFormat/>\
+         <w:pPr><w:spacing w:after=\"40\"/></w:pPr>\
+         <w:rPr><w:rFonts w:ascii=\"{heading}\" w:hAnsi=\"{heading}\" w:cs=\"{heading}\"/><w:b/><w:color w:val=\"{accent}\"/><w:sz w:val=\"44\"/><w:szCs w:val=\"44\"/></w:rPr></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"Contact\"><w:name w:val=\"Contact\"/><w:basedOn w:val=\"Normal\"/>\
+         <w:pPr><w:spacing w:after=\"200\"/></w:pPr><w:rPr><w:color w:val=\"4B5563\"/><w:sz w:val=\"19\"/><w:szCs w:val=\"19\"/></w:rPr></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"Heading1\"><w:name w:val=\"heading 1\"/><w:basedOn w:val=\"Normal\"/><w:next w:val=\"Normal\"/><w:qFormat/>\
+         <w:pPr><w:keepNext/><w:spacing w:before=\"240\" w:after=\"80\"/><w:pBdr><w:bottom w:val=\"single\" w:sz=\"6\" w:space=\"1\" w:color=\"{accent}\"/></w:pBdr><w:outlineLvl w:val=\"0\"/></w:pPr>\
+         <w:rPr><w:rFonts w:ascii=\"{heading}\" w:hAnsi=\"{heading}\" w:cs=\"{heading}\"/><w:b/><w:caps/><w:color w:val=\"{accent}\"/><w:sz w:val=\"26\"/><w:szCs w:val=\"26\"/></w:rPr></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"Heading2\"><w:name w:val=\"heading 2\"/><w:basedOn w:val=\"Normal\"/><w:next w:val=\"Normal\"/><w:qFormat/>\
+         <w:pPr><w:keepNext/><w:spacing w:before=\"160\" w:after=\"20\"/><w:outlineLvl w:val=\"1\"/></w:pPr>\
+         <w:rPr><w:rFonts w:ascii=\"{heading}\" w:hAnsi=\"{heading}\" w:cs=\"{heading}\"/><w:b/><w:sz w:val=\"22\"/><w:szCs w:val=\"22\"/></w:rPr></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"Details\"><w:name w:val=\"Details\"/><w:basedOn w:val=\"Normal\"/>\
+         <w:rPr><w:i/><w:color w:val=\"4B5563\"/><w:sz w:val=\"19\"/><w:szCs w:val=\"19\"/></w:rPr></w:style>\
+         <w:style w:type=\"paragraph\" w:styleId=\"ListParagraph\"><w:name w:val=\"List Paragraph\"/><w:basedOn w:val=\"Normal\"/>\
+         <w:pPr><w:spacing w:after=\"20\"/><w:ind w:left=\"720\"/></w:pPr></w:style>\
+         <w:style w:type=\"character\" w:styleId=\"Hyperlink\"><w:name w:val=\"Hyperlink\"/>\
+         <w:rPr><w:color w:val=\"{accent}\"/><w:u w:val=\"single\"/></w:rPr></w:style>\
+         </w:styles>"
+    )
+}
+
+fn core_properties(resume: &Resume) -> String {
+    let name = escape_xml(&resume.personal_info.name);
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <cp:coreProperties xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\
+         <dc:title>{name} - Resume</dc:title><dc:creator>{name}</dc:creator></cp:coreProperties>"
+    )
+}
+
+// Escapes markup characters and drops control characters that XML 1.0 forbids
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use zip::ZipArchive;
+    use crate::domain::sample_data::sample_resume;
+    use crate::domain::ResumeTheme;
+
+    // Reads one part of a generated package as text
+    fn part(package: &[u8], name: &str) -> String {
+        let mut archive = ZipArchive::new(Cursor::new(package)).expect("valid zip");
+        let mut contents = String::new();
+        archive.by_name(name).expect(name).read_to_string(&mut contents).unwrap();
+        contents
+    }
+
+    #[test]
+    fn package_contains_every_required_part() {
+        let package = export_docx(&sample_resume()).unwrap();
+        let archive = ZipArchive::new(Cursor::new(&package[..])).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+        for name in [
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "docProps/core.xml",
+            "word/document.xml",
+            "word/styles.xml",
+            "word/numbering.xml",
+            "word/_rels/document.xml.rels",
+        ] {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+        assert!(!names.contains(&"word/media/photo.jpeg"));
+    }
+
+    #[test]
+    fn document_has_headings_bullets_and_hyperlinks() {
+        let package = export_docx(&sample_resume()).unwrap();
+        let document = part(&package, "word/document.xml");
+        assert!(document.starts_with("<?xml"));
+        assert!(document.contains("<w:pStyle w:val=\"Title\"/></w:pPr><w:r><w:t xml:space=\"preserve\">John Doe</w:t>"));
+        for heading in ["Summary", "Education", "Experience", "Skills"] {
+            assert!(
+                document.contains(&format!("<w:pStyle w:val=\"Heading1\"/></w:pPr><w:r><w:t xml:space=\"preserve\">{}</w:t>", heading)),
+                "missing heading {}",
+                heading
+            );
+        }
+        assert!(document.contains("Software Engineer, Tech Corp"));
+        assert!(document.contains(&format!(
+            "<w:numPr><w:ilvl w:val=\"0\"/><w:numId w:val=\"{}\"/></w:numPr></w:pPr><w:r><w:t xml:space=\"preserve\">Improved API performance by 30%</w:t>",
+            BULLET_NUM_ID
+        )));
+        assert!(document.contains("<w:hyperlink r:id=\"rIdLink1\">"));
+        assert!(document.ends_with("</w:body></w:document>"));
+
+        let relationships = part(&package, "word/_rels/document.xml.rels");
+        assert!(relationships.contains("Id=\"rIdLink1\""));
+        assert!(relationships.contains("Target=\"mailto:john.doe@example.com\" TargetMode=\"External\""));
+        assert!(relationships.contains("Target=\"https://johndoe.dev\""));
+    }
+
+    #[test]
+    fn styles_follow_the_theme() {
+        let mut resume = sample_resume();
+        resume.theme = ResumeTheme::Creative;
+        let styles = part(&export_docx(&resume).unwrap(), "word/styles.xml");
+        assert!(styles.contains("w:ascii=\"Georgia\""));
+        assert!(styles.contains("<w:color w:val=\"7E22CE\"/>"));
+        for style in ["Normal", "Title", "Contact", "Heading1", "Heading2", "Details", "ListParagraph", "Hyperlink"] {
+            assert!(styles.contains(&format!("w:styleId=\"{}\"", style)), "missing style {}", style);
+        }
+    }
+
+    #[test]
+    fn numbering_defines_the_bullet_list() {
+        let numbering = part(&export_docx(&sample_resume()).unwrap(), "word/numbering.xml");
+        assert!(numbering.contains("<w:numFmt w:val=\"bullet\"/>"));
+        assert!(numbering.contains(&format!("<w:num w:numId=\"{}\"><w:abstractNumId w:val=\"0\"/></w:num>", BULLET_NUM_ID)));
+    }
+
+    #[test]
+    fn user_text_is_escaped() {
+        let mut resume = sample_resume();
+        resume.personal_info.name = "Ada <\"Lovelace\"> & Co\u{1}".to_string();
+        let package = export_docx(&resume).unwrap();
+        let document = part(&package, "word/document.xml");
+        assert!(document.contains("Ada &lt;&quot;Lovelace&quot;&gt; &amp; Co</w:t>"));
+        assert!(part(&package, "docProps/core.xml").contains("<dc:creator>Ada &lt;&quot;Lovelace&quot;&gt; &amp; Co</dc:creator>"));
+    }
+}
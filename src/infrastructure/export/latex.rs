@@ -0,0 +1,493 @@
+use crate::application::dates::employer_dates;
+use crate::domain::{LinkKind, PaperSize, Resume, ResumeTheme, Section};
+use super::{date_range, profile_url, project_dates, skill_groups, theme_style};
+
+// LaTeX export
+//
+// Produces editable `.tex` sources. All user text goes through `escape_latex`
+// so names like "R&D" or "C#" compile without manual fixes.
+
+// Built-in document templates
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LatexTemplate {
+    #[default]
+    ModernCv,
+    Article,
+}
+
+impl LatexTemplate {
+    pub fn all() -> Vec<Self> {
+        vec![Self::ModernCv, Self::Article]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ModernCv => "moderncv",
+            Self::Article => "article",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().into_iter().find(|template| template.name() == name)
+    }
+}
+
+// Renders a resume as a LaTeX document using the given template
+pub fn export_latex(resume: &Resume, template: LatexTemplate) -> String {
+    match template {
+        LatexTemplate::ModernCv => moderncv(resume),
+        LatexTemplate::Article => article(resume),
+    }
+}
+
+// Escapes characters with a special meaning in LaTeX text mode
+pub fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' => escaped.push_str("\\{"),
+            '}' => escaped.push_str("\\}"),
+            '$' => escaped.push_str("\\$"),
+            '&' => escaped.push_str("\\&"),
+            '#' => escaped.push_str("\\#"),
+            '%' => escaped.push_str("\\%"),
+            '_' => escaped.push_str("\\_"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '<' => escaped.push_str("\\textless{}"),
+            '>' => escaped.push_str("\\textgreater{}"),
+            '|' => escaped.push_str("\\textbar{}"),
+            c if c.is_control() && c != '\n' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Escapes text and keeps its line breaks
+fn escape_lines(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(escape_latex)
+        .collect::<Vec<_>>()
+        .join("\\newline{}")
+}
+
+// Escapes a URL for use as the target of \href or \url
+fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            '\\' | '{' | '}' | '#' | '%' | '~' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_whitespace() || c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Document class option for the resume's paper size
+fn paper_option(paper: PaperSize) -> &'static str {
+    match paper {
+        PaperSize::A4 => "a4paper",
+        PaperSize::Letter => "letterpaper",
+    }
+}
+
+// moderncv `\social` type for profiles whose URL ends in the handle
+fn moderncv_social(kind: LinkKind) -> Option<&'static str> {
+    match kind {
+        LinkKind::LinkedIn => Some("linkedin"),
+        LinkKind::GitHub => Some("github"),
+        LinkKind::GitLab => Some("gitlab"),
+        LinkKind::Orcid => Some("orcid"),
+        LinkKind::Website | LinkKind::Portfolio | LinkKind::StackOverflow | LinkKind::Mastodon | LinkKind::Other => None,
+    }
+}
+
+// Last path segment of a profile URL, or the value itself for plain handles
+fn profile_handle(value: &str) -> &str {
+    value.trim_end_matches('/').rsplit('/').next().unwrap_or(value).trim_start_matches('@')
+}
+
+fn itemize(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\\begin{itemize}\n");
+    for item in items {
+        out.push_str(&format!("  \\item {}\n", escape_latex(item)));
+    }
+    out.push_str("\\end{itemize}\n");
+    out
+}
+
+// Closest color scheme offered by moderncv for a theme
+fn moderncv_color(theme: ResumeTheme) -> &'static str {
+    match theme {
+        ResumeTheme::Professional => "blue",
+        ResumeTheme::Minimal => "grey",
+        ResumeTheme::Creative => "purple",
+        ResumeTheme::Modern => "green",
+        ResumeTheme::Executive => "orange",
+        ResumeTheme::Technical => "blue",
+    }
+}
+
+fn moderncv(resume: &Resume) -> String {
+    let info = &resume.personal_info;
+    let (first_name, last_name) = match info.name.trim().rsplit_once(' ') {
+        Some((first, last)) => (first, last),
+        None => (info.name.trim(), ""),
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!("\\documentclass[11pt,{},sans]{{moderncv}}\n", paper_option(resume.paper_size)));
+    out.push_str("\\moderncvstyle{classic}\n");
+    out.push_str(&format!("\\moderncvcolor{{{}}}\n", moderncv_color(resume.theme)));
+    out.push_str("\\usepackage[utf8]{inputenc}\n");
+    out.push_str("\\usepackage[scale=0.8]{geometry}\n\n");
+
+    out.push_str(&format!("\\name{{{}}}{{{}}}\n", escape_latex(first_name), escape_latex(last_name)));
+    if !info.location.is_empty() {
+        out.push_str(&format!("\\address{{{}}}{{}}{{}}\n", escape_latex(&info.location)));
+    }
+    if !info.phone.is_empty() {
+        out.push_str(&format!("\\phone[mobile]{{{}}}\n", escape_latex(&info.phone)));
+    }
+    if !info.email.is_empty() {
+        out.push_str(&format!("\\email{{{}}}\n", escape_latex(&info.email)));
+    }
+    // moderncv has one homepage and icons for well-known profiles; other links go into the extra info line.
+    // It prints the email and handles as text, so they are escaped like any other text
+    let mut has_homepage = false;
+    let mut extra_links: Vec<String> = Vec::new();
+    for link in info.visible_links() {
+        let url = link.url();
+        match moderncv_social(link.kind) {
+            Some(social) => out.push_str(&format!("\\social[{}]{{{}}}\n", social, escape_latex(profile_handle(&url)))),
+            None if link.kind == LinkKind::Website && !has_homepage => {
+                has_homepage = true;
+                let website = url.trim_start_matches("https://").trim_start_matches("http://");
+                out.push_str(&format!("\\homepage{{{}}}\n", escape_latex(website)));
+            }
+            None => extra_links.push(format!("\\href{{{}}}{{{}}}", escape_url(&url), escape_latex(&link.display_label()))),
+        }
+    }
+    if !extra_links.is_empty() {
+        out.push_str(&format!("\\extrainfo{{{}}}\n", extra_links.join(" \\textbullet{} ")));
+    }
+
+    out.push_str("\n\\begin{document}\n\\makecvtitle\n");
+
+    for section in resume.sections() {
+        match section {
+            Section::Personal => {
+                if !info.summary.is_empty() {
+                    out.push_str("\n\\section{Summary}\n");
+                    out.push_str(&format!("\\cvitem{{}}{{{}}}\n", escape_lines(&info.summary)));
+                }
+            }
+            Section::Education => {
+                let entries: Vec<_> = resume.education.iter().filter(|edu| !edu.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section{Education}\n");
+                for edu in entries {
+                    let gpa = if edu.gpa.is_empty() { String::new() } else { format!("GPA: {}", edu.gpa) };
+                    out.push_str(&format!(
+                        "\\cventry{{{}}}{{{}}}{{{}}}{{{}}}{{{}}}{{{}}}\n",
+                        escape_latex(&date_range(&edu.start_date, &edu.end_date, false)),
+                        escape_latex(&format!("{} in {}", edu.degree, edu.field_of_study)),
+                        escape_latex(&edu.institution),
+                        escape_latex(&edu.location),
+                        escape_latex(&gpa),
+                        escape_lines(&edu.description),
+                    ));
+                }
+            }
+            Section::Experience => {
+                let employers = resume.employers();
+                if employers.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section{Experience}\n");
+                for employer in employers {
+                    // Further positions at the same employer leave the company and location blank
+                    for (role, (_, exp)) in employer.roles.iter().enumerate() {
+                        let (company, location) = if role == 0 { (employer.company, employer.location) } else { ("", "") };
+                        out.push_str(&format!(
+                            "\\cventry{{{}}}{{{}}}{{{}}}{{{}}}{{}}{{{}\n{}}}\n",
+                            escape_latex(&date_range(&exp.start_date, &exp.end_date, exp.is_current)),
+                            escape_latex(&exp.position),
+                            escape_latex(company),
+                            escape_latex(location),
+                            escape_lines(&exp.description),
+                            itemize(&exp.achievements),
+                        ));
+                    }
+                }
+            }
+            Section::Skills => {
+                let groups = skill_groups(resume);
+                if groups.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section{Skills}\n");
+                for (category, skills) in groups {
+                    let skills: Vec<String> = skills.iter().map(|skill| escape_latex(skill)).collect();
+                    out.push_str(&format!("\\cvitem{{{}}}{{{}}}\n", escape_latex(&category), skills.join(", ")));
+                }
+            }
+            Section::Projects => {
+                let entries: Vec<_> = resume.projects.iter().filter(|project| !project.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section{Projects}\n");
+                for project in entries {
+                    let link = if project.url.is_empty() {
+                        String::new()
+                    } else {
+                        format!("\\url{{{}}}", escape_url(&profile_url(&project.url, "https://")))
+                    };
+                    let mut description = escape_lines(&project.description);
+                    if !project.technologies.is_empty() {
+                        if !description.is_empty() {
+                            description.push_str("\\newline{}");
+                        }
+                        description.push_str(&format!(
+                            "\\textit{{Technologies:}} {}",
+                            escape_latex(&project.technologies.join(", "))
+                        ));
+                    }
+                    out.push_str(&format!(
+                        "\\cventry{{{}}}{{{}}}{{{}}}{{{}}}{{}}{{{}}}\n",
+                        escape_latex(&project_dates(project)),
+                        escape_latex(&project.name),
+                        escape_latex(&project.role),
+                        link,
+                        description,
+                    ));
+                }
+            }
+        }
+    }
+
+    out.push_str("\n\\end{document}\n");
+    out
+}
+
+fn article(resume: &Resume) -> String {
+    let info = &resume.personal_info;
+    let style = theme_style(resume.theme);
+
+    let mut out = String::new();
+    out.push_str(&format!("\\documentclass[11pt,{}]{{article}}\n", paper_option(resume.paper_size)));
+    out.push_str("\\usepackage[utf8]{inputenc}\n");
+    out.push_str("\\usepackage[T1]{fontenc}\n");
+    out.push_str("\\usepackage[margin=2cm]{geometry}\n");
+    out.push_str("\\usepackage[noitemsep,topsep=2pt]{enumitem}\n");
+    out.push_str("\\usepackage{xcolor}\n");
+    out.push_str("\\usepackage[hidelinks]{hyperref}\n");
+    out.push_str("\\usepackage{titlesec}\n");
+    out.push_str(&format!("\\definecolor{{accent}}{{HTML}}{{{}}}\n", style.accent_color));
+    out.push_str("\\titleformat{\\section}{\\large\\bfseries\\color{accent}}{}{0em}{}[\\titlerule]\n");
+    out.push_str("\\titlespacing*{\\section}{0pt}{12pt}{6pt}\n");
+    out.push_str("\\setlength{\\parindent}{0pt}\n");
+    out.push_str("\\pagestyle{empty}\n\n");
+    out.push_str("\\begin{document}\n\n");
+
+    out.push_str(&format!("{{\\LARGE\\bfseries\\color{{accent}} {}}}\\\\[4pt]\n", escape_latex(&info.name)));
+    let mut contacts: Vec<String> = Vec::new();
+    if !info.email.is_empty() {
+        contacts.push(format!("\\href{{mailto:{}}}{{{}}}", escape_url(&info.email), escape_latex(&info.email)));
+    }
+    for value in [&info.phone, &info.location] {
+        if !value.is_empty() {
+            contacts.push(escape_latex(value));
+        }
+    }
+    for link in info.visible_links() {
+        contacts.push(format!("\\href{{{}}}{{{}}}", escape_url(&link.url()), escape_latex(&link.display_label())));
+    }
+    if !contacts.is_empty() {
+        out.push_str(&format!("{}\n", contacts.join(" \\textbar{} ")));
+    }
+
+    for section in resume.sections() {
+        match section {
+            Section::Personal => {
+                if !info.summary.is_empty() {
+                    out.push_str("\n\\section*{Summary}\n");
+                    out.push_str(&format!("{}\n", escape_lines(&info.summary)));
+                }
+            }
+            Section::Education => {
+                let entries: Vec<_> = resume.education.iter().filter(|edu| !edu.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section*{Education}\n");
+                for edu in entries {
+                    out.push_str(&format!(
+                        "\\textbf{{{}}} \\hfill {}\\\\\n\\textit{{{}}} \\hfill {}\\\\\n",
+                        escape_latex(&edu.institution),
+                        escape_latex(&date_range(&edu.start_date, &edu.end_date, false)),
+                        escape_latex(&format!("{} in {}", edu.degree, edu.field_of_study)),
+                        escape_latex(&edu.location),
+                    ));
+                    if !edu.gpa.is_empty() {
+                        out.push_str(&format!("GPA: {}\\\\\n", escape_latex(&edu.gpa)));
+                    }
+                    if !edu.description.is_empty() {
+                        out.push_str(&format!("{}\\\\\n", escape_lines(&edu.description)));
+                    }
+                    out.push_str("\\medskip\n");
+                }
+            }
+            Section::Experience => {
+                let employers = resume.employers();
+                if employers.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section*{Experience}\n");
+                for employer in employers {
+                    // Several positions at one employer are stacked under the company
+                    if employer.has_several_roles() {
+                        let (start, end, is_current) = employer_dates(&employer);
+                        out.push_str(&format!(
+                            "\\textbf{{{}}} \\hfill {}\\\\\n",
+                            escape_latex(employer.company),
+                            escape_latex(&date_range(start, end, is_current)),
+                        ));
+                        if !employer.location.is_empty() {
+                            out.push_str(&format!("{}\\\\\n", escape_latex(employer.location)));
+                        }
+                        for (_, exp) in employer.roles.iter() {
+                            out.push_str(&format!(
+                                "\\textit{{{}}} \\hfill {}\\\\\n",
+                                escape_latex(&exp.position),
+                                escape_latex(&date_range(&exp.start_date, &exp.end_date, exp.is_current)),
+                            ));
+                            if !exp.description.is_empty() {
+                                out.push_str(&format!("{}\n", escape_lines(&exp.description)));
+                            }
+                            out.push_str(&itemize(&exp.achievements));
+                            out.push_str("\\smallskip\n");
+                        }
+                        out.push_str("\\medskip\n");
+                        continue;
+                    }
+
+                    let exp = employer.roles[0].1;
+                    out.push_str(&format!(
+                        "\\textbf{{{}}} \\hfill {}\\\\\n\\textit{{{}}} \\hfill {}\\\\\n",
+                        escape_latex(&exp.position),
+                        escape_latex(&date_range(&exp.start_date, &exp.end_date, exp.is_current)),
+                        escape_latex(&exp.company),
+                        escape_latex(&exp.location),
+                    ));
+                    if !exp.description.is_empty() {
+                        out.push_str(&format!("{}\n", escape_lines(&exp.description)));
+                    }
+                    out.push_str(&itemize(&exp.achievements));
+                    out.push_str("\\medskip\n");
+                }
+            }
+            Section::Skills => {
+                let groups = skill_groups(resume);
+                if groups.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section*{Skills}\n");
+                let lines: Vec<String> = groups.iter()
+                    .map(|(category, skills)| {
+                        let skills: Vec<String> = skills.iter().map(|skill| escape_latex(skill)).collect();
+                        if category.is_empty() {
+                            skills.join(", ")
+                        } else {
+                            format!("\\textbf{{{}:}} {}", escape_latex(category), skills.join(", "))
+                        }
+                    })
+                    .collect();
+                out.push_str(&format!("{}\n", lines.join(" \\\\\n")));
+            }
+            Section::Projects => {
+                let entries: Vec<_> = resume.projects.iter().filter(|project| !project.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("\n\\section*{Projects}\n");
+                for project in entries {
+                    let title = if project.url.is_empty() {
+                        escape_latex(&project.name)
+                    } else {
+                        format!("\\href{{{}}}{{{}}}", escape_url(&profile_url(&project.url, "https://")), escape_latex(&project.name))
+                    };
+                    out.push_str(&format!(
+                        "\\textbf{{{}}} \\hfill {}\\\\\n",
+                        title,
+                        escape_latex(&project_dates(project)),
+                    ));
+                    if !project.role.is_empty() {
+                        out.push_str(&format!("\\textit{{{}}}\\\\\n", escape_latex(&project.role)));
+                    }
+                    if !project.description.is_empty() {
+                        out.push_str(&format!("{}\\\\\n", escape_lines(&project.description)));
+                    }
+                    if !project.technologies.is_empty() {
+                        out.push_str(&format!(
+                            "\\textit{{Technologies:}} {}\\\\\n",
+                            escape_latex(&project.technologies.join(", "))
+                        ));
+                    }
+                    out.push_str("\\medskip\n");
+                }
+            }
+        }
+    }
+
+    out.push_str("\n\\end{document}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ProfileLink;
+
+    // Resume whose email and profile handle contain underscores
+    fn underscored_resume() -> Resume {
+        let mut resume = Resume::default();
+        resume.personal_info.name = "Jane Doe".to_string();
+        resume.personal_info.email = "jane_doe@example.com".to_string();
+        resume.personal_info.links = vec![ProfileLink::new(LinkKind::GitLab, "jane_doe")];
+        resume
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        assert_eq!(escape_latex("R&D 100% $5 #1 snake_case {x}"), "R\\&D 100\\% \\$5 \\#1 snake\\_case \\{x\\}");
+        assert_eq!(escape_latex("a~b^c\\d"), "a\\textasciitilde{}b\\textasciicircum{}c\\textbackslash{}d");
+    }
+
+    #[test]
+    fn moderncv_header_escapes_email_and_handles() {
+        let latex = export_latex(&underscored_resume(), LatexTemplate::ModernCv);
+        assert!(latex.contains("\\email{jane\\_doe@example.com}"));
+        assert!(latex.contains("\\social[gitlab]{jane\\_doe}"));
+    }
+
+    #[test]
+    fn article_header_keeps_urls_and_escapes_their_text() {
+        let latex = export_latex(&underscored_resume(), LatexTemplate::Article);
+        assert!(latex.contains("\\href{mailto:jane_doe@example.com}{jane\\_doe@example.com}"));
+        assert!(latex.contains("\\href{https://gitlab.com/jane_doe}{GitLab}"));
+    }
+}
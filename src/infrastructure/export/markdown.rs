@@ -0,0 +1,220 @@
+use crate::application::dates::employer_dates;
+use crate::domain::{Experience, Resume, Section};
+use super::{contact_details, date_range, degree_text, project_dates, skill_groups};
+
+// Renders a resume as GitHub-flavored Markdown, following the section order
+// and leaving out hidden entries
+pub fn export_markdown(resume: &Resume) -> String {
+    let info = &resume.personal_info;
+    let mut out = String::new();
+
+    out.push_str(&format!("# {}\n\n", escape(&info.name)));
+    let contacts = contact_details(info);
+    if !contacts.is_empty() {
+        let contacts: Vec<String> = contacts.iter().map(|detail| escape(detail)).collect();
+        out.push_str(&format!("{}\n\n", contacts.join(" | ")));
+    }
+
+    for section in resume.sections() {
+        match section {
+            Section::Personal => {
+                if !info.summary.is_empty() {
+                    out.push_str("## Summary\n\n");
+                    out.push_str(&format!("{}\n\n", escape(&info.summary)));
+                }
+            }
+            Section::Education => {
+                let entries: Vec<_> = resume.education.iter().filter(|edu| !edu.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("## Education\n\n");
+                for edu in entries {
+                    out.push_str(&format!("### {}\n\n", escape(&edu.institution)));
+                    let mut details = Vec::new();
+                    let degree = degree_text(edu);
+                    if !degree.is_empty() {
+                        details.push(format!("**{}**", escape(&degree)));
+                    }
+                    let dates = date_range(&edu.start_date, &edu.end_date, false);
+                    if !dates.is_empty() {
+                        details.push(format!("*{}*", escape(&dates)));
+                    }
+                    if !edu.location.is_empty() {
+                        details.push(escape(&edu.location));
+                    }
+                    if !details.is_empty() {
+                        out.push_str(&format!("{}\n\n", details.join(" | ")));
+                    }
+                    if !edu.gpa.is_empty() {
+                        out.push_str(&format!("GPA: {}\n\n", escape(&edu.gpa)));
+                    }
+                    if !edu.description.is_empty() {
+                        out.push_str(&format!("{}\n\n", escape(&edu.description)));
+                    }
+                }
+            }
+            Section::Experience => {
+                let employers = resume.employers();
+                if employers.is_empty() {
+                    continue;
+                }
+                out.push_str("## Experience\n\n");
+                for employer in employers {
+                    // Several positions at one employer are stacked under the company
+                    if employer.has_several_roles() {
+                        out.push_str(&format!("### {}\n\n", escape(employer.company)));
+                        let (start, end, is_current) = employer_dates(&employer);
+                        let details: Vec<String> = [date_range(start, end, is_current), employer.location.to_string()]
+                            .into_iter()
+                            .filter(|detail| !detail.is_empty())
+                            .map(|detail| escape(&detail))
+                            .collect();
+                        if !details.is_empty() {
+                            out.push_str(&format!("{}\n\n", details.join(" | ")));
+                        }
+                        for (_, exp) in employer.roles {
+                            out.push_str(&format!("#### {}\n\n", escape(&exp.position)));
+                            let dates = date_range(&exp.start_date, &exp.end_date, exp.is_current);
+                            if !dates.is_empty() {
+                                out.push_str(&format!("*{}*\n\n", escape(&dates)));
+                            }
+                            push_experience_body(&mut out, exp);
+                        }
+                        continue;
+                    }
+
+                    let exp = employer.roles[0].1;
+                    out.push_str(&format!("### {} - {}\n\n", escape(&exp.position), escape(&exp.company)));
+                    let mut details = Vec::new();
+                    let dates = date_range(&exp.start_date, &exp.end_date, exp.is_current);
+                    if !dates.is_empty() {
+                        details.push(format!("*{}*", escape(&dates)));
+                    }
+                    if !exp.location.is_empty() {
+                        details.push(escape(&exp.location));
+                    }
+                    if !details.is_empty() {
+                        out.push_str(&format!("{}\n\n", details.join(" | ")));
+                    }
+                    push_experience_body(&mut out, exp);
+                }
+            }
+            Section::Skills => {
+                let groups = skill_groups(resume);
+                if groups.is_empty() {
+                    continue;
+                }
+                out.push_str("## Skills\n\n");
+                for (category, skills) in groups {
+                    let skills: Vec<String> = skills.iter().map(|skill| escape(skill)).collect();
+                    if category.is_empty() {
+                        out.push_str(&format!("{}\n", skills.join(", ")));
+                    } else {
+                        out.push_str(&format!("- **{}:** {}\n", escape(&category), skills.join(", ")));
+                    }
+                }
+                out.push('\n');
+            }
+            Section::Projects => {
+                let entries: Vec<_> = resume.projects.iter().filter(|project| !project.hidden).collect();
+                if entries.is_empty() {
+                    continue;
+                }
+                out.push_str("## Projects\n\n");
+                for project in entries {
+                    if project.url.is_empty() {
+                        out.push_str(&format!("### {}\n\n", escape(&project.name)));
+                    } else {
+                        out.push_str(&format!("### [{}](<{}>)\n\n", escape(&project.name), project.url));
+                    }
+                    let mut details = Vec::new();
+                    if !project.role.is_empty() {
+                        details.push(format!("**{}**", escape(&project.role)));
+                    }
+                    let dates = project_dates(project);
+                    if !dates.is_empty() {
+                        details.push(format!("*{}*", escape(&dates)));
+                    }
+                    if !details.is_empty() {
+                        out.push_str(&format!("{}\n\n", details.join(" | ")));
+                    }
+                    if !project.description.is_empty() {
+                        out.push_str(&format!("{}\n\n", escape(&project.description)));
+                    }
+                    if !project.technologies.is_empty() {
+                        let technologies: Vec<String> = project.technologies.iter().map(|tech| escape(tech)).collect();
+                        out.push_str(&format!("Technologies: {}\n\n", technologies.join(", ")));
+                    }
+                }
+            }
+        }
+    }
+
+    format!("{}\n", out.trim_end())
+}
+
+// Description and achievement bullets of a position
+fn push_experience_body(out: &mut String, exp: &Experience) {
+    if !exp.description.is_empty() {
+        out.push_str(&format!("{}\n\n", escape(&exp.description)));
+    }
+    if !exp.achievements.is_empty() {
+        for achievement in exp.achievements.iter() {
+            out.push_str(&format!("- {}\n", escape(achievement)));
+        }
+        out.push('\n');
+    }
+}
+
+// Escapes characters that Markdown would otherwise interpret as formatting
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sample_data::sample_resume;
+
+    #[test]
+    fn formatting_characters_in_user_text_are_escaped() {
+        let mut resume = sample_resume();
+        resume.personal_info.summary = "Loves *bold* ideas, snake_case and <tags> | #1".to_string();
+        let markdown = export_markdown(&resume);
+        assert!(markdown.contains("Loves \\*bold\\* ideas, snake\\_case and \\<tags\\> \\| \\#1"));
+    }
+
+    #[test]
+    fn hidden_entries_are_left_out_and_sections_follow_the_resume_order() {
+        let mut resume = sample_resume();
+        resume.projects[0].hidden = true;
+        resume.section_order = vec![Section::Skills, Section::Experience, Section::Education];
+        let markdown = export_markdown(&resume);
+        assert!(!markdown.contains("## Projects"));
+        assert!(!markdown.contains("Open Source CLI"));
+        let position = |heading: &str| markdown.find(heading).unwrap();
+        assert!(position("## Skills") < position("## Experience"));
+        assert!(position("## Experience") < position("## Education"));
+        // Sections missing from the order still follow at the end
+        assert!(position("## Education") < position("## Summary"));
+    }
+
+    #[test]
+    fn degrees_without_a_field_of_study_stand_alone() {
+        let mut resume = sample_resume();
+        assert!(export_markdown(&resume).contains("**B.Sc. in Computer Science**"));
+        resume.education[0].field_of_study.clear();
+        let markdown = export_markdown(&resume);
+        assert!(markdown.contains("**B.Sc.**"));
+        assert!(!markdown.contains(" in **"));
+        assert!(!markdown.contains("B.Sc. in"));
+    }
+}
@@ -0,0 +1,108 @@
+// Export module - text based document formats generated from a resume
+pub mod markdown;
+pub mod plain_text;
+pub mod docx;
+pub mod latex;
+pub mod typst;
+
+// Re-export exporters for easier access
+pub use markdown::export_markdown;
+pub use plain_text::{export_plain_text, DEFAULT_TEXT_WIDTH};
+pub use docx::export_docx;
+pub use latex::{export_latex, LatexTemplate};
+pub use typst::export_typst;
+
+use crate::domain::{Education, PersonalInfo, ProfileLink, Project, Resume, ResumeTheme, Skill};
+
+// Fonts and accent color used by document formats for a theme
+pub(crate) struct ThemeStyle {
+    pub heading_font: &'static str,
+    pub body_font: &'static str,
+    // Hex RGB without the leading '#'
+    pub accent_color: &'static str,
+}
+
+pub(crate) fn theme_style(theme: ResumeTheme) -> ThemeStyle {
+    match theme {
+        ResumeTheme::Professional => ThemeStyle { heading_font: "Calibri", body_font: "Calibri", accent_color: "1E40AF" },
+        ResumeTheme::Minimal => ThemeStyle { heading_font: "Arial", body_font: "Arial", accent_color: "374151" },
+        ResumeTheme::Creative => ThemeStyle { heading_font: "Georgia", body_font: "Georgia", accent_color: "7E22CE" },
+        ResumeTheme::Modern => ThemeStyle { heading_font: "Segoe UI", body_font: "Segoe UI", accent_color: "0F766E" },
+        ResumeTheme::Executive => ThemeStyle { heading_font: "Garamond", body_font: "Garamond", accent_color: "B45309" },
+        ResumeTheme::Technical => ThemeStyle { heading_font: "Consolas", body_font: "Calibri", accent_color: "0E7490" },
+    }
+}
+
+// Turns a project URL entered without its scheme into a full URL
+pub(crate) fn profile_url(value: &str, base: &str) -> String {
+    if value.starts_with("http://") || value.starts_with("https://") {
+        value.to_string()
+    } else if value.contains('.') {
+        format!("https://{}", value)
+    } else {
+        format!("{}{}", base, value.trim_start_matches('@'))
+    }
+}
+
+// Visible skills grouped by category, each with its proficiency where the
+// theme shows it; the category is empty when the resume uses none
+pub(crate) fn skill_groups(resume: &Resume) -> Vec<(String, Vec<String>)> {
+    let groups = resume.skills.groups();
+    let categorized = groups.iter().any(|group| !group.category.is_empty());
+    groups.iter()
+        .map(|group| {
+            let category = if group.category.is_empty() && categorized { "Other" } else { group.category };
+            (category.to_string(), group.skills.iter().map(|(_, skill)| skill_text(resume, skill)).collect())
+        })
+        .collect()
+}
+
+fn skill_text(resume: &Resume, skill: &Skill) -> String {
+    let proficiency = resume.skills.proficiency(skill);
+    if resume.theme.shows_skill_levels() && !proficiency.is_empty() {
+        format!("{} ({})", skill.name, proficiency)
+    } else {
+        skill.name.clone()
+    }
+}
+
+// Degree and field of study, leaving out whichever is empty
+pub(crate) fn degree_text(edu: &Education) -> String {
+    let degree = edu.degree.trim();
+    let field = edu.field_of_study.trim();
+    match (degree.is_empty(), field.is_empty()) {
+        (false, false) => format!("{} in {}", degree, field),
+        (false, true) => degree.to_string(),
+        _ => field.to_string(),
+    }
+}
+
+// Formats a date range, using "Present" for ongoing entries
+pub(crate) fn date_range(start: &str, end: &str, is_current: bool) -> String {
+    let end = if is_current { "Present" } else { end };
+    match (start.is_empty(), end.is_empty()) {
+        (false, false) => format!("{} - {}", start, end),
+        (false, true) => start.to_string(),
+        (true, false) => end.to_string(),
+        (true, true) => String::new(),
+    }
+}
+
+// Formats the optional dates of a project as a range
+pub(crate) fn project_dates(project: &Project) -> String {
+    let start = project.start_date.map(|date| date.format("%b %Y").to_string()).unwrap_or_default();
+    let end = project.end_date.map(|date| date.format("%b %Y").to_string()).unwrap_or_default();
+    let is_current = project.start_date.is_some() && project.end_date.is_none();
+    date_range(&start, &end, is_current)
+}
+
+// Non-empty contact details in display order
+pub(crate) fn contact_details(info: &PersonalInfo) -> Vec<String> {
+    let mut details: Vec<String> = [&info.email, &info.phone, &info.location]
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .cloned()
+        .collect();
+    details.extend(info.visible_links().map(ProfileLink::text));
+    details
+}
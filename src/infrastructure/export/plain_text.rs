@@ -0,0 +1,242 @@
+use crate::application::dates::employer_dates;
+use crate::domain::{Resume, Section};
+use super::{contact_details, date_range, degree_text, project_dates, skill_groups};
+
+// Line width used when the caller has no preference
+pub const DEFAULT_TEXT_WIDTH: usize = 80;
+
+// Narrowest width that still leaves room for bullet indentation
+const MIN_TEXT_WIDTH: usize = 20;
+
+// Renders a resume as plain text wrapped at `width` columns, following the
+// section order and leaving out hidden entries
+pub fn export_plain_text(resume: &Resume, width: usize) -> String {
+    let width = width.max(MIN_TEXT_WIDTH);
+    let info = &resume.personal_info;
+    let mut lines: Vec<String> = Vec::new();
+
+    lines.extend(wrap(&info.name.to_uppercase(), width, "", ""));
+    let contacts = contact_details(info);
+    if !contacts.is_empty() {
+        lines.extend(join_wrapped(&contacts, " | ", width));
+    }
+    lines.push("=".repeat(width));
+
+    for section in resume.sections() {
+        let mut body: Vec<String> = Vec::new();
+        match section {
+            Section::Personal => {
+                if !info.summary.is_empty() {
+                    body.extend(paragraphs(&info.summary, width));
+                }
+            }
+            Section::Education => {
+                for edu in resume.education.iter().filter(|edu| !edu.hidden) {
+                    if !body.is_empty() {
+                        body.push(String::new());
+                    }
+                    body.extend(wrap(&edu.institution, width, "", ""));
+                    body.extend(wrap(&degree_text(edu), width, "", ""));
+                    let details: Vec<String> = [date_range(&edu.start_date, &edu.end_date, false), edu.location.clone()]
+                        .into_iter()
+                        .filter(|detail| !detail.is_empty())
+                        .collect();
+                    if !details.is_empty() {
+                        body.extend(wrap(&details.join(" | "), width, "", ""));
+                    }
+                    if !edu.gpa.is_empty() {
+                        body.push(format!("GPA: {}", edu.gpa));
+                    }
+                    if !edu.description.is_empty() {
+                        body.extend(paragraphs(&edu.description, width));
+                    }
+                }
+            }
+            Section::Experience => {
+                for employer in resume.employers() {
+                    if !body.is_empty() {
+                        body.push(String::new());
+                    }
+                    // Several positions at one employer are indented under the company
+                    if employer.has_several_roles() {
+                        let (start, end, is_current) = employer_dates(&employer);
+                        let header: Vec<String> = [employer.company.to_string(), date_range(start, end, is_current), employer.location.to_string()]
+                            .into_iter()
+                            .filter(|detail| !detail.is_empty())
+                            .collect();
+                        body.extend(wrap(&header.join(" | "), width, "", ""));
+                        for (_, exp) in employer.roles {
+                            body.extend(wrap(&exp.position, width, "  ", "  "));
+                            let dates = date_range(&exp.start_date, &exp.end_date, exp.is_current);
+                            if !dates.is_empty() {
+                                body.extend(wrap(&dates, width, "  ", "  "));
+                            }
+                            if !exp.description.is_empty() {
+                                body.extend(exp.description.lines().flat_map(|line| wrap(line, width, "  ", "  ")));
+                            }
+                            for achievement in exp.achievements.iter() {
+                                body.extend(wrap(achievement, width, "    * ", "      "));
+                            }
+                        }
+                        continue;
+                    }
+
+                    let exp = employer.roles[0].1;
+                    body.extend(wrap(&format!("{}, {}", exp.position, exp.company), width, "", ""));
+                    let details: Vec<String> = [date_range(&exp.start_date, &exp.end_date, exp.is_current), exp.location.clone()]
+                        .into_iter()
+                        .filter(|detail| !detail.is_empty())
+                        .collect();
+                    if !details.is_empty() {
+                        body.extend(wrap(&details.join(" | "), width, "", ""));
+                    }
+                    if !exp.description.is_empty() {
+                        body.extend(paragraphs(&exp.description, width));
+                    }
+                    for achievement in exp.achievements.iter() {
+                        body.extend(wrap(achievement, width, "  * ", "    "));
+                    }
+                }
+            }
+            Section::Skills => {
+                for (category, skills) in skill_groups(resume) {
+                    if category.is_empty() {
+                        body.extend(wrap(&skills.join(", "), width, "", ""));
+                    } else {
+                        body.extend(wrap(&format!("{}: {}", category, skills.join(", ")), width, "", "  "));
+                    }
+                }
+            }
+            Section::Projects => {
+                for project in resume.projects.iter().filter(|project| !project.hidden) {
+                    if !body.is_empty() {
+                        body.push(String::new());
+                    }
+                    let title = if project.role.is_empty() {
+                        project.name.clone()
+                    } else {
+                        format!("{}, {}", project.name, project.role)
+                    };
+                    body.extend(wrap(&title, width, "", ""));
+                    let details: Vec<String> = [project_dates(project), project.url.clone()]
+                        .into_iter()
+                        .filter(|detail| !detail.is_empty())
+                        .collect();
+                    if !details.is_empty() {
+                        body.extend(wrap(&details.join(" | "), width, "", ""));
+                    }
+                    if !project.description.is_empty() {
+                        body.extend(paragraphs(&project.description, width));
+                    }
+                    if !project.technologies.is_empty() {
+                        body.extend(wrap(&format!("Technologies: {}", project.technologies.join(", ")), width, "", ""));
+                    }
+                }
+            }
+        }
+
+        if !body.is_empty() {
+            lines.push(String::new());
+            lines.push(section.title().to_uppercase());
+            lines.push("-".repeat(width));
+            lines.extend(body);
+        }
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+// Wraps each line of a multi-line text separately
+fn paragraphs(text: &str, width: usize) -> Vec<String> {
+    text.lines()
+        .flat_map(|line| wrap(line, width, "", ""))
+        .collect()
+}
+
+// Joins items with a separator, breaking lines between items rather than inside them
+fn join_wrapped(items: &[String], separator: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for item in items {
+        if !current.is_empty() && current.chars().count() + separator.len() + item.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push_str(separator);
+        }
+        current.push_str(item);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// Greedy word wrap with separate prefixes for the first and following lines.
+// Words longer than the available width are kept whole on their own line.
+fn wrap(text: &str, width: usize, first_prefix: &str, rest_prefix: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = first_prefix.to_string();
+    let mut prefix_len = first_prefix.chars().count();
+    let mut current_len = prefix_len;
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        if current_len > prefix_len && current_len + 1 + word_len > width {
+            lines.push(current);
+            current = rest_prefix.to_string();
+            prefix_len = rest_prefix.chars().count();
+            current_len = prefix_len;
+        }
+        if current_len > prefix_len {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+
+    if current_len > prefix_len {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sample_data::sample_resume;
+
+    #[test]
+    fn lines_are_wrapped_at_the_width() {
+        let mut resume = sample_resume();
+        resume.personal_info.summary = "word ".repeat(40);
+        let text = export_plain_text(&resume, 30);
+        assert!(text.lines().all(|line| line.chars().count() <= 30));
+        assert!(text.lines().any(|line| line.chars().count() > 25));
+        // Too narrow a width falls back to the minimum
+        assert!(export_plain_text(&resume, 5).lines().any(|line| line == "=".repeat(MIN_TEXT_WIDTH)));
+    }
+
+    #[test]
+    fn hidden_entries_are_left_out_and_sections_follow_the_resume_order() {
+        let mut resume = sample_resume();
+        resume.experience[0].hidden = true;
+        resume.section_order = vec![Section::Projects, Section::Education];
+        let text = export_plain_text(&resume, DEFAULT_TEXT_WIDTH);
+        assert!(!text.contains("Tech Corp"));
+        assert!(text.find("Open Source CLI").unwrap() < text.find("State University").unwrap());
+    }
+
+    #[test]
+    fn degrees_without_a_field_of_study_stand_alone() {
+        let mut resume = sample_resume();
+        assert!(export_plain_text(&resume, DEFAULT_TEXT_WIDTH).lines().any(|line| line == "B.Sc. in Computer Science"));
+        resume.education[0].field_of_study.clear();
+        let text = export_plain_text(&resume, DEFAULT_TEXT_WIDTH);
+        assert!(text.lines().any(|line| line == "B.Sc."));
+        assert!(!text.contains("B.Sc. in"));
+    }
+}
@@ -0,0 +1,53 @@
+#set document(title: "John Doe - Resume")
+#set page(paper: "a4", margin: (x: 1.8cm, y: 1.6cm))
+#let accent = rgb("#1E40AF")
+#let heading-font = "Calibri"
+#set text(font: "Calibri", size: 10.5pt)
+#set par(leading: 0.6em)
+#show link: set text(fill: accent)
+#show heading.where(level: 1): it => block(above: 14pt, below: 6pt, width: 100%, stroke: (bottom: 0.6pt + accent), inset: (bottom: 3pt), text(font: heading-font, size: 12pt, weight: "bold", fill: accent, upper(it.body)))
+
+#let entry(title, date, subtitle, location) = block(above: 10pt, below: 4pt, grid(
+  columns: (1fr, auto),
+  align: (left, right),
+  row-gutter: 4pt,
+  strong(title), date,
+  emph(subtitle), location,
+))
+#let role(title, date) = block(above: 6pt, below: 4pt, grid(
+  columns: (1fr, auto),
+  align: (left, right),
+  emph(title), date,
+))
+
+#align(left, {
+  text(font: heading-font, size: 22pt, weight: "bold", fill: accent, "John Doe")
+  linebreak()
+  (link("mailto:john.doe@example.com", "john.doe@example.com"), "123-456-7890", "New York, NY", link("https://johndoe.dev", "johndoe.dev"), link("https://www.linkedin.com/in/johndoe", "LinkedIn"), link("https://github.com/johndoe", "GitHub"),).join([ | ])
+})
+
+= Summary
+#"Shipped #1 ranked app for $2M; loves *bold* ideas, snake_case and @mentions."
+
+= Education
+#entry("State University", "2015-09 - 2019-06", "B.Sc. in Computer Science", "New York, NY")
+#"GPA: 3.8" \
+#"Graduated with honors."
+
+= Experience
+#entry("Software Engineer", "2019-07 - 2022-08", "Tech Corp", "Remote")
+#"Worked on backend systems."
+- #"Improved API performance by 30%"
+- #"Cut costs by 40% with \"lean\" #infra \\ tooling"
+
+= Skills
+#strong("Languages:") #("Rust (Advanced, 3 years)",).join(", ")
+
+#strong("Frameworks:") #("React (Expert, 5 years)",).join(", ")
+
+
+= Projects
+#entry(link("https://github.com/johndoe/cli", "Open Source CLI"), "", "Lead Developer", [])
+#"A CLI tool for productivity."
+
+_Technologies:_ #"Rust, CLI"
@@ -0,0 +1,331 @@
+use crate::application::dates::employer_dates;
+use crate::domain::{Experience, PageBreaks, PaperSize, Resume, ResumeTheme, Section};
+use super::{date_range, profile_url, project_dates, skill_groups, theme_style};
+
+// Typst export
+//
+// User text is always emitted as Typst string literals rather than markup, so
+// characters such as `*`, `#` or `@` are printed as typed instead of being
+// interpreted. Each theme gets its own heading and header styling.
+
+// Renders a resume as a Typst document on the given paper size
+pub fn export_typst(resume: &Resume, paper: PaperSize) -> String {
+    let mut out = preamble(resume, paper);
+    out.push_str(&header(resume));
+
+    for section in resume.sections() {
+        out.push_str(&match section {
+            Section::Personal => summary(resume),
+            Section::Education => education(resume),
+            Section::Experience => experience(resume),
+            Section::Skills => skills(resume),
+            Section::Projects => projects(resume),
+        });
+    }
+    out
+}
+
+// Quotes text as a Typst string literal
+pub fn typst_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push(' '),
+            c if c.is_control() => {}
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn paper_name(paper: PaperSize) -> &'static str {
+    match paper {
+        PaperSize::A4 => "a4",
+        PaperSize::Letter => "us-letter",
+    }
+}
+
+// Header alignment and level-one heading rule for a theme
+fn theme_layout(theme: ResumeTheme) -> (&'static str, &'static str) {
+    match theme {
+        ResumeTheme::Professional => (
+            "left",
+            "block(above: 14pt, below: 6pt, width: 100%, stroke: (bottom: 0.6pt + accent), inset: (bottom: 3pt), text(font: heading-font, size: 12pt, weight: \"bold\", fill: accent, upper(it.body)))",
+        ),
+        ResumeTheme::Minimal => (
+            "left",
+            "block(above: 14pt, below: 6pt, text(font: heading-font, size: 11pt, fill: accent, smallcaps(it.body)))",
+        ),
+        ResumeTheme::Creative => (
+            "left",
+            "block(above: 14pt, below: 6pt, fill: accent, radius: 3pt, inset: (x: 6pt, y: 3pt), text(font: heading-font, size: 12pt, weight: \"bold\", fill: white, it.body))",
+        ),
+        ResumeTheme::Modern => (
+            "left",
+            "block(above: 14pt, below: 6pt, stroke: (left: 3pt + accent), inset: (left: 6pt), text(font: heading-font, size: 12pt, weight: \"bold\", fill: accent, it.body))",
+        ),
+        ResumeTheme::Executive => (
+            "center",
+            "block(above: 16pt, below: 8pt, width: 100%, stroke: (top: 0.5pt + accent, bottom: 0.5pt + accent), inset: (y: 3pt), align(center, text(font: heading-font, size: 12pt, tracking: 1.5pt, fill: accent, upper(it.body))))",
+        ),
+        ResumeTheme::Technical => (
+            "left",
+            "block(above: 14pt, below: 6pt, text(font: heading-font, size: 11pt, weight: \"bold\", fill: accent, [\\/\\/ ] + it.body))",
+        ),
+    }
+}
+
+fn preamble(resume: &Resume, paper: PaperSize) -> String {
+    let style = theme_style(resume.theme);
+    let (_, heading_rule) = theme_layout(resume.theme);
+
+    let mut out = String::new();
+    out.push_str(&format!("#set document(title: {})\n", typst_string(&format!("{} - Resume", resume.personal_info.name))));
+    out.push_str(&format!("#set page(paper: \"{}\", margin: (x: 1.8cm, y: 1.6cm))\n", paper_name(paper)));
+    out.push_str(&format!("#let accent = rgb(\"#{}\")\n", style.accent_color));
+    out.push_str(&format!("#let heading-font = {}\n", typst_string(style.heading_font)));
+    out.push_str(&format!("#set text(font: {}, size: 10.5pt)\n", typst_string(style.body_font)));
+    out.push_str("#set par(leading: 0.6em)\n");
+    out.push_str("#show link: set text(fill: accent)\n");
+    out.push_str(&format!("#show heading.where(level: 1): it => {}\n\n", heading_rule));
+    out.push_str("#let entry(title, date, subtitle, location) = block(above: 10pt, below: 4pt, grid(\n");
+    out.push_str("  columns: (1fr, auto),\n  align: (left, right),\n  row-gutter: 4pt,\n");
+    out.push_str("  strong(title), date,\n  emph(subtitle), location,\n))\n");
+    // A position under an employer with several
+    out.push_str("#let role(title, date) = block(above: 6pt, below: 4pt, grid(\n");
+    out.push_str("  columns: (1fr, auto),\n  align: (left, right),\n  emph(title), date,\n))\n\n");
+    out
+}
+
+fn header(resume: &Resume) -> String {
+    let info = &resume.personal_info;
+    let (alignment, _) = theme_layout(resume.theme);
+
+    let mut contacts: Vec<String> = Vec::new();
+    if !info.email.is_empty() {
+        contacts.push(format!("link({}, {})", typst_string(&format!("mailto:{}", info.email)), typst_string(&info.email)));
+    }
+    for value in [&info.phone, &info.location] {
+        if !value.is_empty() {
+            contacts.push(typst_string(value));
+        }
+    }
+    for link in info.visible_links() {
+        contacts.push(format!("link({}, {})", typst_string(&link.url()), typst_string(&link.display_label())));
+    }
+
+    let mut out = format!("#align({}, {{\n", alignment);
+    out.push_str(&format!(
+        "  text(font: heading-font, size: 22pt, weight: \"bold\", fill: accent, {})\n",
+        typst_string(&info.name)
+    ));
+    if !contacts.is_empty() {
+        out.push_str(&format!("  linebreak()\n  ({},).join([ | ])\n", contacts.join(", ")));
+    }
+    out.push_str("})\n");
+    out
+}
+
+fn bullets(items: &[String]) -> String {
+    items.iter()
+        .filter(|item| !item.trim().is_empty())
+        .map(|item| format!("- #{}\n", typst_string(item)))
+        .collect()
+}
+
+// Section heading, moved to a new page along with a first entry that starts one
+fn section_heading(title: &str, first: PageBreaks) -> String {
+    if first.new_page {
+        format!("\n#pagebreak(weak: true)\n= {}\n", title)
+    } else {
+        format!("\n= {}\n", title)
+    }
+}
+
+// Applies an entry's page-break settings to its markup
+fn entry_block(page_breaks: PageBreaks, first: bool, body: String) -> String {
+    let mut out = String::new();
+    if page_breaks.new_page && !first {
+        out.push_str("#pagebreak(weak: true)\n");
+    }
+    if page_breaks.keep_together {
+        out.push_str(&format!("#block(breakable: false)[\n{}]\n", body));
+    } else {
+        out.push_str(&body);
+    }
+    out
+}
+
+fn summary(resume: &Resume) -> String {
+    let summary = &resume.personal_info.summary;
+    if summary.is_empty() {
+        return String::new();
+    }
+    format!("\n= Summary\n#{}\n", typst_string(summary))
+}
+
+fn education(resume: &Resume) -> String {
+    let entries: Vec<_> = resume.education.iter().filter(|edu| !edu.hidden).collect();
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let mut out = section_heading("Education", entries[0].page_breaks);
+    for (position, edu) in entries.into_iter().enumerate() {
+        let mut body = format!(
+            "#entry({}, {}, {}, {})\n",
+            typst_string(&edu.institution),
+            typst_string(&date_range(&edu.start_date, &edu.end_date, false)),
+            typst_string(&format!("{} in {}", edu.degree, edu.field_of_study)),
+            typst_string(&edu.location),
+        );
+        if !edu.gpa.is_empty() {
+            body.push_str(&format!("#{} \\\n", typst_string(&format!("GPA: {}", edu.gpa))));
+        }
+        if !edu.description.is_empty() {
+            body.push_str(&format!("#{}\n", typst_string(&edu.description)));
+        }
+        out.push_str(&entry_block(edu.page_breaks, position == 0, body));
+    }
+    out
+}
+
+fn experience(resume: &Resume) -> String {
+    let employers = resume.employers();
+    let Some(first) = employers.first() else {
+        return String::new();
+    };
+
+    let mut out = section_heading("Experience", first.roles[0].1.page_breaks);
+    for (position, employer) in employers.iter().enumerate() {
+        if !employer.has_several_roles() {
+            let exp = employer.roles[0].1;
+            let mut body = format!(
+                "#entry({}, {}, {}, {})\n",
+                typst_string(&exp.position),
+                typst_string(&date_range(&exp.start_date, &exp.end_date, exp.is_current)),
+                typst_string(&exp.company),
+                typst_string(&exp.location),
+            );
+            push_experience_body(&mut body, exp);
+            out.push_str(&entry_block(exp.page_breaks, position == 0, body));
+            continue;
+        }
+
+        // Several positions at one employer are stacked under the company, which
+        // takes the first position's page break
+        if employer.roles[0].1.page_breaks.new_page && position > 0 {
+            out.push_str("#pagebreak(weak: true)\n");
+        }
+        let (start, end, is_current) = employer_dates(employer);
+        out.push_str(&format!(
+            "#entry({}, {}, {}, \"\")\n",
+            typst_string(employer.company),
+            typst_string(&date_range(start, end, is_current)),
+            typst_string(employer.location),
+        ));
+        for (role, (_, exp)) in employer.roles.iter().enumerate() {
+            let mut body = format!(
+                "#role({}, {})\n",
+                typst_string(&exp.position),
+                typst_string(&date_range(&exp.start_date, &exp.end_date, exp.is_current)),
+            );
+            push_experience_body(&mut body, exp);
+            out.push_str(&entry_block(exp.page_breaks, role == 0, format!("#pad(left: 10pt)[\n{}]\n", body)));
+        }
+    }
+    out
+}
+
+// Description and achievement bullets of a position
+fn push_experience_body(body: &mut String, exp: &Experience) {
+    if !exp.description.is_empty() {
+        body.push_str(&format!("#{}\n", typst_string(&exp.description)));
+    }
+    body.push_str(&bullets(&exp.achievements));
+}
+
+fn skills(resume: &Resume) -> String {
+    let groups = skill_groups(resume);
+    if groups.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\n= Skills\n");
+    for (category, skills) in groups {
+        let skills: Vec<String> = skills.iter().map(|skill| typst_string(skill)).collect();
+        if category.is_empty() {
+            out.push_str(&format!("#({},).join(\", \")\n\n", skills.join(", ")));
+        } else {
+            out.push_str(&format!("#strong({}) #({},).join(\", \")\n\n", typst_string(&format!("{}:", category)), skills.join(", ")));
+        }
+    }
+    out
+}
+
+fn projects(resume: &Resume) -> String {
+    let entries: Vec<_> = resume.projects.iter().filter(|project| !project.hidden).collect();
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let mut out = section_heading("Projects", entries[0].page_breaks);
+    for (position, project) in entries.into_iter().enumerate() {
+        let title = if project.url.is_empty() {
+            typst_string(&project.name)
+        } else {
+            format!("link({}, {})", typst_string(&profile_url(&project.url, "https://")), typst_string(&project.name))
+        };
+        let mut body = format!(
+            "#entry({}, {}, {}, [])\n",
+            title,
+            typst_string(&project_dates(project)),
+            typst_string(&project.role),
+        );
+        if !project.description.is_empty() {
+            body.push_str(&format!("#{}\n", typst_string(&project.description)));
+        }
+        if !project.technologies.is_empty() {
+            body.push_str(&format!(
+                "\n_Technologies:_ #{}\n",
+                typst_string(&project.technologies.join(", "))
+            ));
+        }
+        out.push_str(&entry_block(project.page_breaks, position == 0, body));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sample_data::sample_resume;
+
+    // The sample resume with markup characters in user text
+    fn golden_resume() -> Resume {
+        let mut resume = sample_resume();
+        resume.personal_info.summary = "Shipped #1 ranked app for $2M; loves *bold* ideas, snake_case and @mentions.".to_string();
+        resume.experience[0].achievements.push("Cut costs by 40% with \"lean\" #infra \\ tooling".to_string());
+        resume
+    }
+
+    #[test]
+    fn sample_resume_matches_golden_file() {
+        let golden = include_str!("testdata/sample_resume.typ");
+        assert_eq!(export_typst(&golden_resume(), PaperSize::A4), golden);
+    }
+
+    #[test]
+    fn markup_characters_are_printed_as_typed() {
+        let typst = export_typst(&golden_resume(), PaperSize::Letter);
+        assert!(typst.contains("#set page(paper: \"us-letter\""));
+        assert!(typst.contains("\"Shipped #1 ranked app for $2M; loves *bold* ideas, snake_case and @mentions.\""));
+        assert!(typst.contains("\"Cut costs by 40% with \\\"lean\\\" #infra \\\\ tooling\""));
+        // No user text is left outside a string literal
+        assert!(!typst.lines().any(|line| line.starts_with("Shipped") || line.starts_with("Cut costs")));
+    }
+}
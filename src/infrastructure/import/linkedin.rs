@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{Cursor, Read};
+
+use chrono::NaiveDate;
+use zip::ZipArchive;
+
+use crate::application::dates::month_bounds;
+use crate::domain::{Education, Experience, PageBreaks, LinkKind, PersonalInfo, ProfileLink, Project, Resume, Section, Skill, Skills};
+
+// LinkedIn "download your data" import
+//
+// The archive holds one CSV per data category. Only the files that map onto
+// resume fields are read; everything else in the archive is ignored, and a
+// missing file simply leaves the matching section empty.
+
+type Rows = Vec<HashMap<String, String>>;
+
+// Files read from the archive, as lowercase file stems
+const NEEDED_FILES: [&str; 7] = ["profile", "positions", "education", "skills", "projects", "email addresses", "phonenumbers"];
+
+// Largest CSV read from an archive; real exports stay far below this, and the
+// sizes a ZIP declares for its entries cannot be trusted
+const MAX_CSV_BYTES: u64 = 16 * 1024 * 1024;
+
+// Builds a resume from the bytes of a LinkedIn data-export ZIP archive
+pub fn import_linkedin_archive(bytes: &[u8]) -> Result<Resume, Box<dyn Error>> {
+    let files = read_csv_files(bytes)?;
+    let known = ["profile", "positions", "education", "skills", "projects"];
+    if !known.iter().any(|name| files.contains_key(*name)) {
+        return Err("No LinkedIn profile data found in the archive".into());
+    }
+    let rows = |name: &str| files.get(name).cloned().unwrap_or_default();
+
+    Ok(Resume {
+        personal_info: personal_info(&rows("profile"), &rows("email addresses"), &rows("phonenumbers")),
+        experience: group_positions(rows("positions").iter().map(experience).collect()),
+        education: rows("education").iter().map(education).collect(),
+        projects: rows("projects").iter().map(project).collect(),
+        skills: Skills {
+            skill_list: rows("skills").iter()
+                .map(|row| field(row, "Name"))
+                .filter(|name| !name.is_empty())
+                .map(|name| Skill { name, ..Skill::default() })
+                .collect(),
+            ..Default::default()
+        },
+        section_order: Section::all(),
+        ..Resume::default()
+    })
+}
+
+// Parses the CSVs the importer needs, keyed by lowercase file stem; the
+// dozens of other files in an export are never read, so one the importer
+// has no use for cannot make the import fail
+fn read_csv_files(bytes: &[u8]) -> Result<HashMap<String, Rows>, Box<dyn Error>> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+    let mut files = HashMap::new();
+
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        let path = file.name().to_string();
+        let file_name = path.rsplit('/').next().unwrap_or(&path).to_lowercase();
+        let Some(stem) = file_name.strip_suffix(".csv").map(|stem| stem.replace('_', " ")) else {
+            continue;
+        };
+        if !NEEDED_FILES.contains(&stem.as_str()) {
+            continue;
+        }
+
+        let mut contents = String::new();
+        file.by_ref().take(MAX_CSV_BYTES + 1).read_to_string(&mut contents)
+            .map_err(|err| format!("Could not read {}: {}", file_name, err))?;
+        if contents.len() as u64 > MAX_CSV_BYTES {
+            return Err(format!("{} is too large to be a LinkedIn export", file_name).into());
+        }
+        let rows = parse_csv(&contents).map_err(|err| format!("Could not read {}: {}", file_name, err))?;
+        files.insert(stem, rows);
+    }
+    Ok(files)
+}
+
+fn parse_csv(contents: &str) -> Result<Rows, Box<dyn Error>> {
+    let contents = contents.trim_start_matches('\u{feff}');
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(contents.as_bytes());
+    let headers: Vec<String> = reader.headers()?.iter().map(|header| header.trim().to_string()).collect();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let row = headers.iter()
+            .cloned()
+            .zip(record.iter().map(|value| value.trim().to_string()))
+            .collect();
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn field(row: &HashMap<String, String>, name: &str) -> String {
+    row.get(name).cloned().unwrap_or_default()
+}
+
+fn personal_info(profile: &Rows, emails: &Rows, phones: &Rows) -> PersonalInfo {
+    let mut info = PersonalInfo::default();
+
+    if let Some(row) = profile.first() {
+        info.name = format!("{} {}", field(row, "First Name"), field(row, "Last Name")).trim().to_string();
+        info.summary = field(row, "Summary");
+        info.location = field(row, "Geo Location");
+        info.links = websites(&field(row, "Websites")).iter()
+            .map(|url| ProfileLink::new(LinkKind::from_url(url).unwrap_or_default(), url))
+            .collect();
+    }
+
+    // Prefer the primary address when several are listed
+    info.email = emails.iter()
+        .find(|row| field(row, "Primary").eq_ignore_ascii_case("yes"))
+        .or_else(|| emails.first())
+        .map(|row| field(row, "Email Address"))
+        .unwrap_or_default();
+    info.phone = phones.first().map(|row| field(row, "Number")).unwrap_or_default();
+    info
+}
+
+// Extracts URLs from LinkedIn's "[PERSONAL:https://...,COMPANY:https://...]" format;
+// commas also separate the entries, so a piece only starts a new entry when
+// it begins with a label or a scheme, and URLs that contain commas stay whole
+fn websites(value: &str) -> Vec<String> {
+    let mut entries: Vec<String> = Vec::new();
+    for piece in value.trim().trim_matches(|c| c == '[' || c == ']').split(',') {
+        let starts_entry = website_label(piece).is_some() || piece.trim_start().starts_with("http");
+        match entries.last_mut() {
+            Some(entry) if !starts_entry => {
+                entry.push(',');
+                entry.push_str(piece);
+            }
+            _ => entries.push(piece.to_string()),
+        }
+    }
+    entries.iter()
+        .map(|entry| website_label(entry).unwrap_or(entry).trim().to_string())
+        .filter(|url| !url.is_empty())
+        .collect()
+}
+
+// The URL after a "PERSONAL:" style label
+fn website_label(entry: &str) -> Option<&str> {
+    let (label, url) = entry.trim_start().split_once(':')?;
+    (!label.is_empty() && label.chars().all(|c| c.is_ascii_uppercase() || c == '_')).then_some(url)
+}
+
+fn experience(row: &HashMap<String, String>) -> Experience {
+    let finished = field(row, "Finished On");
+    let (description, achievements) = split_bullets(&field(row, "Description"));
+    Experience {
+        company: field(row, "Company Name"),
+        position: field(row, "Title"),
+        location: field(row, "Location"),
+        start_date: month_text(&field(row, "Started On")),
+        end_date: month_text(&finished),
+        is_current: finished.is_empty(),
+        description,
+        achievements,
+        hidden: false,
+        page_breaks: PageBreaks::default(),
+        same_employer: false,
+    }
+}
+
+// LinkedIn lists every role separately; consecutive roles at one company
+// become positions at the same employer, as LinkedIn itself shows them
+fn group_positions(mut positions: Vec<Experience>) -> Vec<Experience> {
+    for index in 1..positions.len() {
+        let company = positions[index].company.trim().to_lowercase();
+        positions[index].same_employer = !company.is_empty() && positions[index - 1].company.trim().to_lowercase() == company;
+    }
+    positions
+}
+
+fn education(row: &HashMap<String, String>) -> Education {
+    let description = [field(row, "Notes"), field(row, "Activities")]
+        .into_iter()
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Education {
+        institution: field(row, "School Name"),
+        degree: field(row, "Degree Name"),
+        start_date: month_text(&field(row, "Start Date")),
+        end_date: month_text(&field(row, "End Date")),
+        description,
+        ..Education::default()
+    }
+}
+
+fn project(row: &HashMap<String, String>) -> Project {
+    Project {
+        name: field(row, "Title"),
+        description: field(row, "Description"),
+        url: field(row, "Url"),
+        start_date: parse_date(&field(row, "Started On")),
+        end_date: parse_date(&field(row, "Finished On")),
+        ..Project::default()
+    }
+}
+
+// Separates bullet lines ("-", "*" or "•") from the free text of a description
+fn split_bullets(text: &str) -> (String, Vec<String>) {
+    let mut description = Vec::new();
+    let mut bullets = Vec::new();
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match line.strip_prefix(['-', '*', '•']) {
+            Some(bullet) => bullets.push(bullet.trim().to_string()),
+            None => description.push(line),
+        }
+    }
+    (description.join("\n"), bullets)
+}
+
+// First day of a LinkedIn date; `None` when it cannot be read
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let (first, _) = month_bounds(value)?;
+    NaiveDate::from_ymd_opt(first.div_euclid(12), first.rem_euclid(12) as u32 + 1, 1)
+}
+
+// Converts a LinkedIn date to the "YYYY-MM" form used by the editor, or
+// "YYYY" when only the year is known
+fn month_text(value: &str) -> String {
+    match month_bounds(value) {
+        Some((first, last)) if first == last => format!("{:04}-{:02}", first.div_euclid(12), first.rem_euclid(12) + 1),
+        Some((first, _)) => format!("{:04}", first.div_euclid(12)),
+        None => value.trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, contents) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(contents).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn unneeded_files_are_not_read() {
+        let bytes = archive(&[
+            ("Profile.csv", b"First Name,Last Name,Summary\nJane,Doe,Engineer\n"),
+            ("Positions.csv", b"Company Name,Title,Started On,Finished On\nAcme,Lead,Mar 15 2021,\nAcme,Dev,Jan 2018,Feb 2021\n"),
+            ("Education.csv", b"School Name,Degree Name,Start Date,End Date\nState University,B.Sc.,2014,2018\n"),
+            ("Messages.csv", b"\xff\xfe not utf-8 \"unbalanced\n"),
+        ]);
+        let resume = import_linkedin_archive(&bytes).unwrap();
+        assert_eq!(resume.personal_info.name, "Jane Doe");
+        assert_eq!(resume.experience[1].start_date, "2018-01");
+        assert_eq!(resume.experience[1].end_date, "2021-02");
+        assert!(resume.experience[1].same_employer);
+        assert_eq!(resume.education[0].start_date, "2014");
+    }
+
+    #[test]
+    fn dates_are_read_like_everywhere_else() {
+        assert_eq!(month_text("Jan 2020"), "2020-01");
+        assert_eq!(month_text("2020-03-15"), "2020-03");
+        assert_eq!(month_text("2019"), "2019");
+        assert_eq!(month_text(" someday "), "someday");
+        assert_eq!(parse_date("Mar 2020"), NaiveDate::from_ymd_opt(2020, 3, 1));
+    }
+
+    #[test]
+    fn website_urls_may_contain_commas() {
+        assert_eq!(
+            websites("[PERSONAL:https://example.com/a,b?x=1,2,COMPANY:https://acme.com,OTHER:github.com/jane]"),
+            vec!["https://example.com/a,b?x=1,2", "https://acme.com", "github.com/jane"]
+        );
+        assert_eq!(websites("https://jane.dev, https://blog.jane.dev"), vec!["https://jane.dev", "https://blog.jane.dev"]);
+        assert!(websites("[]").is_empty());
+    }
+
+    #[test]
+    fn oversized_files_are_rejected() {
+        let mut huge = b"First Name,Last Name\n".to_vec();
+        huge.resize(MAX_CSV_BYTES as usize + 2, b'a');
+        let error = import_linkedin_archive(&archive(&[("Profile.csv", &huge)])).err().unwrap();
+        assert!(error.to_string().contains("too large"));
+
+        // Large files the importer does not need are skipped unread
+        let resume = import_linkedin_archive(&archive(&[("Profile.csv", b"First Name\nJane\n"), ("Messages.csv", &huge)])).unwrap();
+        assert_eq!(resume.personal_info.name, "Jane");
+    }
+}
@@ -0,0 +1,7 @@
+// Import module - builds resumes from data exported by other tools
+pub mod linkedin;
+pub mod text_resume;
+
+// Re-export importers for easier access
+pub use linkedin::import_linkedin_archive;
+pub use text_resume::import_text_resume;
@@ -0,0 +1,953 @@
+use chrono::NaiveDate;
+
+use crate::domain::{Confidence, Education, Experience, ImportDraft, LinkKind, ProfileLink, Project, Resume, Section, Skill};
+
+// Markdown and plain-text resume import
+//
+// Old resumes have no fixed structure, so this importer works line by line:
+// it finds section headings, splits each section into entries, and pulls
+// dates, titles and bullets out of every entry. Each extracted field is marked
+// with how confident the guess is so the user knows what to check.
+
+// Builds a draft resume from a Markdown or plain-text document
+pub fn import_text_resume(text: &str) -> ImportDraft {
+    let lines = classify_lines(text);
+    let mut draft = ImportDraft {
+        resume: Resume { section_order: Section::all(), ..Resume::default() },
+        fields: Vec::new(),
+    };
+
+    // Everything before the first section heading is the resume header
+    let first_section = lines.iter().position(|line| section_kind(line).is_some()).unwrap_or(lines.len());
+    parse_header(&lines[..first_section], &mut draft);
+
+    let mut index = first_section;
+    while index < lines.len() {
+        let Some((kind, heading_confidence)) = section_kind(&lines[index]) else {
+            index += 1;
+            continue;
+        };
+        let end = lines[index + 1..].iter()
+            .position(|line| section_kind(line).is_some())
+            .map_or(lines.len(), |offset| index + 1 + offset);
+        let body = &lines[index + 1..end];
+
+        match kind {
+            SectionKind::Summary => parse_summary(body, heading_confidence, &mut draft),
+            SectionKind::Experience => {
+                for entry in split_entries(body) {
+                    parse_experience(&entry, heading_confidence, &mut draft);
+                }
+            }
+            SectionKind::Education => {
+                for entry in split_entries(body) {
+                    parse_education(&entry, heading_confidence, &mut draft);
+                }
+            }
+            SectionKind::Projects => {
+                for entry in split_entries(body) {
+                    parse_project(&entry, heading_confidence, &mut draft);
+                }
+            }
+            SectionKind::Skills => parse_skills(body, heading_confidence, &mut draft),
+            SectionKind::Other => {
+                let content: Vec<&str> = body.iter().filter(|line| !line.text.is_empty()).map(|line| line.text.as_str()).collect();
+                draft.mark(format!("Not imported: {}", lines[index].text), &content.join("; "), Confidence::Low);
+            }
+        }
+        index = end;
+    }
+    draft
+}
+
+// --- Line classification ---
+
+#[derive(Clone, Debug)]
+struct Line {
+    text: String,
+    // Markdown heading level, or 1/2 for setext-style underlined headings
+    heading: Option<usize>,
+    bullet: bool,
+}
+
+impl Line {
+    fn is_blank(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+fn classify_lines(text: &str) -> Vec<Line> {
+    let mut lines: Vec<Line> = Vec::new();
+    for raw in text.lines() {
+        let trimmed = raw.trim();
+
+        // "===" or "---" under a line turns it into a heading; on its own it is a rule
+        if trimmed.len() >= 3 && (trimmed.chars().all(|c| c == '=') || trimmed.chars().all(|c| c == '-')) {
+            match lines.last_mut() {
+                Some(previous) if !previous.is_blank() && previous.heading.is_none() && !previous.bullet => {
+                    previous.heading = Some(if trimmed.starts_with('=') { 1 } else { 2 });
+                }
+                _ => lines.push(Line { text: String::new(), heading: None, bullet: false }),
+            }
+            continue;
+        }
+
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        let (text, heading, bullet) = if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            (&trimmed[hashes..], Some(hashes), false)
+        } else if let Some(rest) = strip_bullet(trimmed) {
+            (rest, None, true)
+        } else {
+            (trimmed, None, false)
+        };
+
+        lines.push(Line { text: clean_inline(text.trim().trim_end_matches('#').trim()), heading, bullet });
+    }
+    lines
+}
+
+fn strip_bullet(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "+ ", "• ", "· ", "▪ ", "– "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some(rest);
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && digits < 3 {
+        let rest = &line[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some(rest);
+        }
+    }
+    None
+}
+
+// Removes Markdown emphasis and turns links into "text (url)"
+fn clean_inline(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let Some(middle) = rest[start..].find("](").map(|offset| start + offset) else {
+            break;
+        };
+        let Some(end) = rest[middle..].find(')').map(|offset| middle + offset) else {
+            break;
+        };
+        let label = &rest[start + 1..middle];
+        let url = &rest[middle + 2..end];
+        cleaned.push_str(&rest[..start]);
+        let bare_url = url.trim_start_matches("mailto:").trim_start_matches("https://").trim_start_matches("http://");
+        if label.is_empty() || label == url || label == bare_url {
+            cleaned.push_str(url.trim_start_matches("mailto:"));
+        } else {
+            cleaned.push_str(&format!("{} ({})", label, url.trim_start_matches("mailto:")));
+        }
+        rest = &rest[end + 1..];
+    }
+    cleaned.push_str(rest);
+    cleaned.replace("**", "").replace("__", "").replace('`', "").trim().to_string()
+}
+
+// --- Sections ---
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum SectionKind {
+    Summary,
+    Experience,
+    Education,
+    Skills,
+    Projects,
+    Other,
+}
+
+fn section_kind(line: &Line) -> Option<(SectionKind, Confidence)> {
+    // Deeper Markdown headings name entries, and "Label: value" lines are content
+    let label_line = line.text.trim_end_matches(':').contains(':');
+    if line.bullet || line.is_blank() || label_line || line.heading.is_some_and(|level| level > 2) || line.text.split_whitespace().count() > 4 {
+        return None;
+    }
+    let title = line.text.trim_end_matches(':').to_lowercase();
+    let kind = if ["summary", "profile", "about", "objective", "overview"].iter().any(|word| title.contains(word)) {
+        SectionKind::Summary
+    } else if ["experience", "employment", "work history", "career history"].iter().any(|word| title.contains(word)) {
+        SectionKind::Experience
+    } else if ["education", "academic", "qualifications"].iter().any(|word| title.contains(word)) {
+        SectionKind::Education
+    } else if ["skill", "technologies", "competenc", "tech stack", "expertise"].iter().any(|word| title.contains(word)) {
+        SectionKind::Skills
+    } else if title.contains("project") {
+        SectionKind::Projects
+    } else if ["certification", "award", "language", "interest", "publication", "reference", "volunteer", "hobbies", "courses"]
+        .iter()
+        .any(|word| title.contains(word))
+    {
+        SectionKind::Other
+    } else {
+        return None;
+    };
+
+    // Markdown headings are explicit; plain lines only count when they look like a heading
+    let letters: Vec<char> = line.text.chars().filter(|c| c.is_alphabetic()).collect();
+    let upper_case = !letters.is_empty() && letters.iter().all(|c| c.is_uppercase());
+    if line.heading.is_some() {
+        Some((kind, Confidence::High))
+    } else if upper_case || line.text.ends_with(':') {
+        Some((kind, Confidence::Medium))
+    } else if line.text.split_whitespace().count() <= 2 {
+        Some((kind, Confidence::Low))
+    } else {
+        None
+    }
+}
+
+// Splits a section body into entries at sub-headings, or where a new dated header follows a blank line or bullets
+fn split_entries(lines: &[Line]) -> Vec<Vec<Line>> {
+    let mut entries: Vec<Vec<Line>> = Vec::new();
+    let mut current: Vec<Line> = Vec::new();
+    let mut after_blank = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        if line.is_blank() {
+            after_blank = true;
+            continue;
+        }
+        let next_has_date = lines.get(index + 1).is_some_and(|next| !next.bullet && find_date_range(&next.text).is_some());
+        let has_bullets = current.iter().any(|line| line.bullet);
+        let starts_entry = line.heading.is_some()
+            || (!line.bullet && has_bullets)
+            || (!line.bullet && after_blank && (find_date_range(&line.text).is_some() || next_has_date));
+
+        if starts_entry && !current.is_empty() {
+            entries.push(std::mem::take(&mut current));
+        }
+        current.push(line.clone());
+        after_blank = false;
+    }
+    if !current.is_empty() {
+        entries.push(current);
+    }
+    entries
+}
+
+// Header lines of an entry, followed by the remaining description lines and bullets
+fn entry_parts(entry: &[Line]) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let mut header = Vec::new();
+    let mut description = Vec::new();
+    let mut bullets = Vec::new();
+
+    for (index, line) in entry.iter().enumerate() {
+        let short = line.text.split_whitespace().count() <= 10 && !line.text.ends_with('.');
+        // "GPA: 3.8" or "Technologies: ..." lines are details, not part of the title
+        let labelled = line.text.split_once(':')
+            .is_some_and(|(label, value)| label.split_whitespace().count() <= 2 && !value.starts_with("//"));
+        if line.bullet {
+            bullets.push(line.text.clone());
+        } else if index == 0 || (index < 3 && bullets.is_empty() && description.is_empty() && short && !labelled) {
+            header.push(line.text.clone());
+        } else {
+            description.push(line.text.clone());
+        }
+    }
+    (header, description, bullets)
+}
+
+fn parse_header(lines: &[Line], draft: &mut ImportDraft) {
+    let mut lines = lines.iter().filter(|line| !line.is_blank()).peekable();
+
+    if let Some(first) = lines.peek() {
+        if contact_kind(&first.text).is_none() {
+            let looks_like_name = first.text.split_whitespace().count() <= 4
+                && first.text.chars().any(char::is_alphabetic)
+                && !first.text.chars().any(|c| c.is_ascii_digit() || c == '@');
+            let confidence = match (first.heading, looks_like_name) {
+                (Some(_), true) => Confidence::High,
+                (_, true) => Confidence::Medium,
+                _ => Confidence::Low,
+            };
+            let name = title_case_if_upper(&first.text);
+            draft.mark("Name", &name, confidence);
+            draft.resume.personal_info.name = name;
+            lines.next();
+        }
+    }
+
+    let mut summary: Vec<String> = Vec::new();
+    // Short lines without contact details, such as a headline, are left for the user
+    let mut unplaced: Vec<&str> = Vec::new();
+    for line in lines {
+        let segments: Vec<&str> = line.text
+            .split(['|', '·', '•'])
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        let has_contacts = segments.iter().any(|segment| contact_kind(segment).is_some() || take_url(segment).1.is_some());
+        if !has_contacts && segments.len() == 1 && line.text.split_whitespace().count() > 6 {
+            summary.push(line.text.clone());
+            continue;
+        }
+        if !has_contacts {
+            unplaced.push(&line.text);
+            continue;
+        }
+
+        for segment in segments {
+            // "LinkedIn (https://...)" keeps only the link
+            let (_, url) = take_url(segment);
+            let value = url.as_deref().unwrap_or_else(|| strip_label(segment));
+            let info = &mut draft.resume.personal_info;
+            let (field, target, confidence) = match contact_kind(value) {
+                Some(ContactKind::Email) => ("Email", &mut info.email, Confidence::High),
+                Some(ContactKind::Phone) => ("Phone", &mut info.phone, Confidence::High),
+                Some(ContactKind::Link(kind)) => {
+                    // Only the first link to each profile site is kept
+                    let duplicate = info.links.iter().any(|link| link.kind == kind && kind != LinkKind::Website);
+                    if !duplicate {
+                        info.links.push(ProfileLink::new(kind, value));
+                        draft.mark(kind.name(), value, Confidence::High);
+                    }
+                    continue;
+                }
+                // Whatever short text is left on a contact line is most likely the location
+                None if value.split_whitespace().count() <= 5 => ("Location", &mut info.location, Confidence::Low),
+                None => continue,
+            };
+            if target.is_empty() {
+                *target = value.to_string();
+                draft.mark(field, value, confidence);
+            }
+        }
+    }
+
+    if !summary.is_empty() && draft.resume.personal_info.summary.is_empty() {
+        let text = summary.join(" ");
+        draft.mark("Summary", &text, Confidence::Medium);
+        draft.resume.personal_info.summary = text;
+    }
+    draft.mark("Not imported: Header", &unplaced.join("; "), Confidence::Low);
+}
+
+// "JOHN DOE" becomes "John Doe"; names with mixed case are kept as written
+fn title_case_if_upper(text: &str) -> String {
+    if text.chars().any(char::is_lowercase) {
+        return text.to_string();
+    }
+    text.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or(String::new(), |first| first.to_string() + &chars.as_str().to_lowercase())
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ContactKind {
+    Email,
+    Phone,
+    Link(LinkKind),
+}
+
+fn contact_kind(segment: &str) -> Option<ContactKind> {
+    let value = strip_label(segment).to_lowercase();
+    if value.contains(' ') && !value.starts_with('+') && !value.starts_with('(') {
+        return None;
+    }
+    if let Some(kind) = LinkKind::from_url(&value) {
+        Some(ContactKind::Link(kind))
+    } else if value.contains('@') && value.rsplit('@').next().is_some_and(|domain| domain.contains('.')) {
+        Some(ContactKind::Email)
+    } else if value.starts_with("http") || value.starts_with("www.") {
+        Some(ContactKind::Link(LinkKind::Website))
+    } else {
+        let digits = value.chars().filter(|c| c.is_ascii_digit()).count();
+        let phone_chars = value.chars().all(|c| c.is_ascii_digit() || "+-(). ".contains(c));
+        (phone_chars && (7..=15).contains(&digits)).then_some(ContactKind::Phone)
+    }
+}
+
+// Drops a leading "Email:" style label
+fn strip_label(segment: &str) -> &str {
+    match segment.split_once(':') {
+        Some((label, value)) if !value.starts_with("//") && label.split_whitespace().count() <= 2 && label.chars().all(|c| c.is_alphabetic() || c == ' ') => value.trim(),
+        _ => segment.trim(),
+    }
+}
+
+fn parse_summary(body: &[Line], section: Confidence, draft: &mut ImportDraft) {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in body {
+        if line.is_blank() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(&line.text);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+
+    let text = paragraphs.join("\n");
+    if !text.is_empty() {
+        draft.mark("Summary", &text, section);
+        draft.resume.personal_info.summary = text;
+    }
+}
+
+fn parse_experience(entry: &[Line], section: Confidence, draft: &mut ImportDraft) {
+    let number = draft.resume.experience.len() + 1;
+    let (header, description, bullets) = entry_parts(entry);
+    let (header, dates) = take_dates(&header);
+    let (pieces, location) = header_pieces(&header);
+
+    let mut exp = Experience::default();
+    let said_at = header.iter().any(|line| line.to_lowercase().contains(" at "));
+    let role = pieces.iter().position(|piece| is_role(piece));
+    let (position, company, confidence) = match (pieces.as_slice(), role) {
+        ([], _) => (String::new(), String::new(), Confidence::Low),
+        ([single], Some(_)) => (single.clone(), String::new(), Confidence::Medium),
+        ([single], None) => (String::new(), single.clone(), Confidence::Low),
+        ([first, second, ..], _) if said_at => (first.clone(), second.clone(), Confidence::High),
+        (_, Some(role)) => {
+            let company = pieces.iter().enumerate().find(|(index, _)| *index != role).map(|(_, piece)| piece.clone());
+            (pieces[role].clone(), company.unwrap_or_default(), Confidence::Medium)
+        }
+        ([first, second, ..], None) => (first.clone(), second.clone(), Confidence::Low),
+    };
+    // A third piece next to position and company is most likely the location
+    let location = location.or_else(|| {
+        pieces.iter()
+            .find(|piece| **piece != position && **piece != company && piece.split_whitespace().count() <= 3)
+            .map(|piece| (piece.clone(), Confidence::Low))
+    });
+    let confidence = confidence.min(section);
+    draft.mark(format!("Experience {}: Position", number), &position, confidence);
+    draft.mark(format!("Experience {}: Company", number), &company, confidence);
+    exp.position = position;
+    exp.company = company;
+
+    if let Some((location, confidence)) = location {
+        draft.mark(format!("Experience {}: Location", number), &location, confidence.min(section));
+        exp.location = location;
+    }
+    if let Some(dates) = dates {
+        draft.mark(format!("Experience {}: Dates", number), &dates.label(), dates.confidence.min(section));
+        exp.start_date = dates.start.unwrap_or_default();
+        exp.end_date = dates.end.unwrap_or_default();
+        exp.is_current = dates.is_current;
+    }
+
+    exp.description = description.join("\n");
+    draft.mark(format!("Experience {}: Description", number), &exp.description, Confidence::Medium.min(section));
+    draft.mark(format!("Experience {}: Achievements", number), &bullets.join("; "), section);
+    exp.achievements = bullets;
+    draft.resume.experience.push(exp);
+}
+
+fn parse_education(entry: &[Line], section: Confidence, draft: &mut ImportDraft) {
+    let number = draft.resume.education.len() + 1;
+    let (header, mut description, bullets) = entry_parts(entry);
+    let (header, dates) = take_dates(&header);
+    let (pieces, location) = header_pieces(&header);
+    let mut edu = Education::default();
+
+    let institution = pieces.iter().find(|piece| is_institution(piece));
+    let degree = pieces.iter().find(|piece| is_degree(piece));
+    let (institution, degree, confidence) = match (institution, degree) {
+        (Some(institution), Some(degree)) => (institution.clone(), degree.clone(), Confidence::High),
+        (Some(institution), None) => (
+            institution.clone(),
+            pieces.iter().find(|piece| *piece != institution).cloned().unwrap_or_default(),
+            Confidence::Medium,
+        ),
+        (None, Some(degree)) => (
+            pieces.iter().find(|piece| *piece != degree).cloned().unwrap_or_default(),
+            degree.clone(),
+            Confidence::Medium,
+        ),
+        (None, None) => (
+            pieces.first().cloned().unwrap_or_default(),
+            pieces.get(1).cloned().unwrap_or_default(),
+            Confidence::Low,
+        ),
+    };
+    let confidence = confidence.min(section);
+    draft.mark(format!("Education {}: Institution", number), &institution, confidence);
+    edu.institution = institution;
+
+    // "BSc in Computer Science" carries the field of study
+    match degree.split_once(" in ") {
+        Some((degree, field)) => {
+            edu.degree = degree.trim().to_string();
+            edu.field_of_study = field.trim().to_string();
+        }
+        None => edu.degree = degree,
+    }
+    draft.mark(format!("Education {}: Degree", number), &edu.degree, confidence);
+    draft.mark(format!("Education {}: Field of study", number), &edu.field_of_study, confidence);
+
+    if let Some((location, confidence)) = location {
+        draft.mark(format!("Education {}: Location", number), &location, confidence.min(section));
+        edu.location = location;
+    }
+    if let Some(dates) = dates {
+        draft.mark(format!("Education {}: Dates", number), &dates.label(), dates.confidence.min(section));
+        // A single date on an education entry is usually the graduation date
+        match (dates.start, dates.end) {
+            (Some(start), None) if !dates.is_current => edu.end_date = start,
+            (start, end) => {
+                edu.start_date = start.unwrap_or_default();
+                edu.end_date = if dates.is_current { "Present".to_string() } else { end.unwrap_or_default() };
+            }
+        }
+    }
+
+    description.extend(bullets);
+    description.retain(|line| {
+        // ASCII lowercasing keeps byte offsets valid for slicing the original line
+        match line.to_ascii_lowercase().find("gpa") {
+            Some(position) => {
+                edu.gpa = line[position + 3..].trim_start_matches([':', ' ']).split_whitespace().next().unwrap_or_default().to_string();
+                false
+            }
+            None => true,
+        }
+    });
+    draft.mark(format!("Education {}: GPA", number), &edu.gpa, section);
+    edu.description = description.join("\n");
+    draft.mark(format!("Education {}: Description", number), &edu.description, Confidence::Medium.min(section));
+    draft.resume.education.push(edu);
+}
+
+fn parse_project(entry: &[Line], section: Confidence, draft: &mut ImportDraft) {
+    let number = draft.resume.projects.len() + 1;
+    let (header, description, bullets) = entry_parts(entry);
+    let (header, dates) = take_dates(&header);
+    let mut project = Project::default();
+
+    let first = header.first().cloned().unwrap_or_default();
+    let (first, url) = take_url(&first);
+    let mut pieces = split_pieces(&first);
+    let name = if pieces.is_empty() { String::new() } else { pieces.remove(0) };
+    let confidence = if entry.first().is_some_and(|line| line.heading.is_some()) { section } else { Confidence::Medium.min(section) };
+    draft.mark(format!("Project {}: Name", number), &name, confidence);
+    project.name = name;
+
+    if let Some(url) = url {
+        draft.mark(format!("Project {}: URL", number), &url, section);
+        project.url = url;
+    }
+    if let Some(dates) = dates {
+        draft.mark(format!("Project {}: Dates", number), &dates.label(), dates.confidence.min(section));
+        project.start_date = dates.start.as_deref().and_then(to_naive_date);
+        project.end_date = dates.end.as_deref().and_then(to_naive_date);
+    }
+
+    // Remaining header pieces and lines are either the role or the description
+    let mut lines: Vec<String> = Vec::new();
+    for piece in pieces.into_iter().chain(header.into_iter().skip(1)) {
+        if project.role.is_empty() && is_role(&piece) && piece.split_whitespace().count() <= 4 {
+            draft.mark(format!("Project {}: Role", number), &piece, Confidence::Low);
+            project.role = piece;
+        } else {
+            lines.push(piece);
+        }
+    }
+    for line in description.into_iter().chain(bullets) {
+        let lower = line.to_ascii_lowercase();
+        let technologies = ["technologies:", "tech stack:", "stack:", "built with:", "tools:"]
+            .iter()
+            .find_map(|label| lower.starts_with(label).then(|| line[label.len()..].to_string()));
+        match technologies {
+            Some(list) => project.technologies = split_list(&list),
+            None => lines.push(line),
+        }
+    }
+    draft.mark(format!("Project {}: Technologies", number), &project.technologies.join(", "), section);
+    project.description = lines.join("\n");
+    draft.mark(format!("Project {}: Description", number), &project.description, Confidence::Medium.min(section));
+    draft.resume.projects.push(project);
+}
+
+fn parse_skills(body: &[Line], section: Confidence, draft: &mut ImportDraft) {
+    let skills = &mut draft.resume.skills;
+    for line in body.iter().filter(|line| !line.is_blank()) {
+        // "Languages: Rust, Go" lines name a category
+        let (category, list) = match line.text.split_once(':') {
+            Some((category, list)) if category.split_whitespace().count() <= 3 => (Some(category.trim().to_string()), list),
+            _ => (None, line.text.as_str()),
+        };
+        let category = category.unwrap_or_default();
+        skills.add_category(&category);
+        for name in split_list(list) {
+            match skills.skill_list.iter_mut().find(|skill| skill.name.eq_ignore_ascii_case(&name)) {
+                Some(skill) if skill.category.is_empty() => skill.category = category.clone(),
+                Some(_) => {}
+                None => skills.skill_list.push(Skill { name, category: category.clone(), ..Skill::default() }),
+            }
+        }
+    }
+    let names: Vec<String> = draft.resume.skills.skill_list.iter().map(|skill| skill.name.clone()).collect();
+    draft.mark("Skills", &names.join(", "), section);
+}
+
+// --- Field heuristics ---
+
+const ROLE_WORDS: &[&str] = &[
+    "engineer", "developer", "manager", "designer", "analyst", "lead", "intern", "director", "consultant",
+    "scientist", "architect", "specialist", "officer", "head", "president", "vp", "administrator",
+    "coordinator", "assistant", "programmer", "founder", "co-founder", "cto", "ceo", "owner", "technician",
+    "researcher", "teacher", "writer", "editor", "maintainer", "contributor", "freelancer", "sre",
+];
+
+const INSTITUTION_WORDS: &[&str] = &["university", "college", "school", "institute", "academy", "polytechnic", "universität", "école"];
+
+const DEGREE_WORDS: &[&str] = &[
+    "bachelor", "bachelors", "master", "masters", "bsc", "b.sc", "bs", "b.s", "ba", "b.a", "msc", "m.sc", "ms",
+    "m.s", "ma", "m.a", "mba", "phd", "ph.d", "doctorate", "diploma", "associate", "beng", "meng", "btech",
+    "b.tech", "mtech", "m.tech", "certificate", "degree",
+];
+
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !(c.is_alphanumeric() || c == '.' || c == '-'))
+        .map(|word| word.trim_end_matches('.').trim_end_matches("'s").to_string())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn is_role(text: &str) -> bool {
+    words(text).iter().any(|word| ROLE_WORDS.contains(&word.as_str()))
+}
+
+fn is_institution(text: &str) -> bool {
+    words(text).iter().any(|word| INSTITUTION_WORDS.contains(&word.as_str()))
+}
+
+fn is_degree(text: &str) -> bool {
+    words(text).iter().any(|word| DEGREE_WORDS.contains(&word.as_str()))
+}
+
+fn is_location(text: &str) -> Option<Confidence> {
+    let lower = text.to_lowercase();
+    if ["remote", "hybrid", "on-site", "onsite"].contains(&lower.as_str()) {
+        return Some(Confidence::Medium);
+    }
+    // "Austin, TX" style city and region
+    let (city, region) = text.rsplit_once(", ")?;
+    let region = region.trim();
+    let short_region = region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase());
+    (short_region && city.split_whitespace().count() <= 3).then_some(Confidence::Medium)
+}
+
+// Splits header lines into title pieces and an optional location
+fn header_pieces(header: &[String]) -> (Vec<String>, Option<(String, Confidence)>) {
+    let mut pieces = Vec::new();
+    let mut location = None;
+    for line in header {
+        // A whole "City, ST" piece must be checked before commas split it
+        if location.is_none() {
+            if let Some(confidence) = is_location(line) {
+                location = Some((line.clone(), confidence));
+                continue;
+            }
+        }
+        for piece in split_pieces(line) {
+            match is_location(&piece) {
+                Some(confidence) if location.is_none() => location = Some((piece, confidence)),
+                _ => pieces.push(piece),
+            }
+        }
+    }
+    (pieces, location)
+}
+
+// Splits "Engineer at Acme | Berlin" style lines at common separators
+fn split_pieces(line: &str) -> Vec<String> {
+    let mut pieces: Vec<String> = vec![line.to_string()];
+    for separator in [" at ", " @ ", " | ", " - ", " — ", " – ", " · ", " / "] {
+        pieces = pieces.iter()
+            .flat_map(|piece| piece.split(separator).map(str::to_string).collect::<Vec<_>>())
+            .collect();
+    }
+    // Commas only separate pieces when nothing else did, and not before company suffixes
+    if pieces.len() == 1 {
+        if let Some((first, second)) = line.split_once(", ") {
+            let suffix = ["inc", "inc.", "llc", "ltd", "ltd.", "gmbh", "co.", "corp", "corp."].contains(&second.trim().to_lowercase().as_str());
+            if !suffix && is_location(line).is_none() {
+                pieces = vec![first.to_string(), second.to_string()];
+            }
+        }
+    }
+    pieces.into_iter()
+        .map(|piece| piece.trim_matches(|c: char| c.is_whitespace() || ",;|-()".contains(c)).to_string())
+        .filter(|piece| !piece.is_empty())
+        .collect()
+}
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split([',', ';', '|', '•', '·'])
+        .map(|item| item.trim().trim_end_matches('.').to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+// Removes the first URL from a line
+fn take_url(text: &str) -> (String, Option<String>) {
+    let url = text.split_whitespace()
+        .map(|word| word.trim_matches(|c| "()<>[],".contains(c)))
+        .find(|word| word.starts_with("http://") || word.starts_with("https://") || word.starts_with("www."))
+        .map(str::to_string);
+    match url {
+        Some(url) => {
+            let remaining = text.replace(&format!("({})", url), "").replace(&url, "");
+            (remaining.trim().to_string(), Some(url))
+        }
+        None => (text.to_string(), None),
+    }
+}
+
+// --- Dates ---
+
+#[derive(Clone, PartialEq, Debug)]
+struct DateRange {
+    // "YYYY-MM" or "YYYY"
+    start: Option<String>,
+    end: Option<String>,
+    is_current: bool,
+    confidence: Confidence,
+}
+
+impl DateRange {
+    fn label(&self) -> String {
+        let end = if self.is_current { Some("Present".to_string()) } else { self.end.clone() };
+        [self.start.clone(), end].into_iter().flatten().collect::<Vec<_>>().join(" - ")
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum DatePart {
+    Date(String),
+    Present,
+}
+
+const MONTHS: &[&str] = &[
+    "january", "february", "march", "april", "may", "june",
+    "july", "august", "september", "october", "november", "december",
+];
+
+// Removes the first date range found in the header lines
+fn take_dates(header: &[String]) -> (Vec<String>, Option<DateRange>) {
+    let mut dates = None;
+    let lines = header.iter()
+        .map(|line| {
+            if dates.is_none() {
+                if let Some((range, remaining)) = find_date_range(line) {
+                    dates = Some(range);
+                    return remaining;
+                }
+            }
+            line.clone()
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    (lines, dates)
+}
+
+// Finds a date or date range in a line and returns it with the rest of the line
+fn find_date_range(line: &str) -> Option<(DateRange, String)> {
+    let spaced = line.replace(['–', '—'], " - ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+
+    for start in 0..tokens.len() {
+        let Some((first, first_len)) = date_at(&tokens, start) else {
+            continue;
+        };
+        let mut end = start + first_len;
+        let mut second = None;
+
+        // "2019-2021" arrives as a single token
+        if let Some((from, to)) = clean_token(tokens[start]).split_once('-') {
+            if first_len == 1 && from.len() == 4 && (to.len() == 4 || is_present(to)) {
+                second = part_from_token(to);
+            }
+        }
+        if second.is_none() {
+            let separator = tokens.get(end).map(|token| clean_token(token).to_lowercase());
+            if matches!(separator.as_deref(), Some("-") | Some("to") | Some("until")) {
+                if let Some((part, len)) = date_at(&tokens, end + 1) {
+                    second = Some(part);
+                    end += 1 + len;
+                }
+            }
+        }
+
+        let start_date = match &first {
+            DatePart::Date(date) => Some(date.clone()),
+            DatePart::Present => continue,
+        };
+        let (end_date, is_current) = match &second {
+            Some(DatePart::Date(date)) => (Some(date.clone()), false),
+            Some(DatePart::Present) => (None, true),
+            None => (None, false),
+        };
+        let confidence = if second.is_some() { Confidence::High } else { Confidence::Medium };
+        let remaining: Vec<&str> = tokens[..start].iter().chain(tokens[end..].iter()).copied().collect();
+        let remaining = remaining.join(" ")
+            .trim_matches(|c: char| c.is_whitespace() || ",;|-()".contains(c))
+            .replace("()", "");
+        return Some((DateRange { start: start_date, end: end_date, is_current, confidence }, remaining));
+    }
+    None
+}
+
+fn clean_token(token: &str) -> &str {
+    token.trim_matches(|c| "()[],;|".contains(c))
+}
+
+fn is_present(token: &str) -> bool {
+    ["present", "current", "now", "today", "ongoing"].contains(&token.to_lowercase().as_str())
+}
+
+// Reads a date starting at a token, returning it with the number of tokens used
+fn date_at(tokens: &[&str], index: usize) -> Option<(DatePart, usize)> {
+    let token = clean_token(tokens.get(index)?);
+    // Full month names and abbreviations such as "Sep", "Sept." or "September"
+    let lower = token.to_lowercase();
+    let lower = lower.trim_end_matches('.');
+    let month = MONTHS.iter().position(|month| lower.len() >= 3 && month.starts_with(lower));
+    if let Some(month) = month {
+        let year = clean_token(tokens.get(index + 1)?);
+        let year = year.trim_end_matches(['-', '–']).parse::<i32>().ok().filter(|year| (1950..=2100).contains(year))?;
+        return Some((DatePart::Date(format!("{:04}-{:02}", year, month + 1)), 2));
+    }
+    part_from_token(token.split_once('-').filter(|(from, _)| from.len() == 4 && token.len() != 7).map_or(token, |(from, _)| from))
+        .map(|part| (part, 1))
+}
+
+// Parses "2020", "03/2020", "2020-03" or "Present"
+fn part_from_token(token: &str) -> Option<DatePart> {
+    if is_present(token) {
+        return Some(DatePart::Present);
+    }
+    let year_ok = |year: &str| year.len() == 4 && year.parse::<i32>().is_ok_and(|year| (1950..=2100).contains(&year));
+    let month_ok = |month: &str| month.len() <= 2 && month.parse::<u32>().is_ok_and(|month| (1..=12).contains(&month));
+
+    if year_ok(token) {
+        return Some(DatePart::Date(token.to_string()));
+    }
+    if let Some((month, year)) = token.split_once('/') {
+        if month_ok(month) && year_ok(year) {
+            return Some(DatePart::Date(format!("{}-{:02}", year, month.parse::<u32>().ok()?)));
+        }
+    }
+    if let Some((year, month)) = token.split_once('-') {
+        if year_ok(year) && month_ok(month) {
+            return Some(DatePart::Date(format!("{}-{:02}", year, month.parse::<u32>().ok()?)));
+        }
+    }
+    None
+}
+
+fn to_naive_date(value: &str) -> Option<NaiveDate> {
+    let (year, month) = match value.split_once('-') {
+        Some((year, month)) => (year.parse().ok()?, month.parse().ok()?),
+        None => (value.parse().ok()?, 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gpa_after_characters_that_change_length_when_lowercased() {
+        let draft = import_text_resume("Jane Doe\n\nEducation\nState University\nB.Sc. in Physics\n2015 - 2019\nİİİ Honors, GPA: 3.9\n");
+        let edu = &draft.resume.education[0];
+        assert_eq!(edu.gpa, "3.9");
+        assert!(!edu.description.contains("GPA"));
+    }
+
+    fn confidence(draft: &ImportDraft, field: &str) -> Option<Confidence> {
+        draft.fields.iter().find(|marked| marked.field == field).map(|marked| marked.confidence)
+    }
+
+    #[test]
+    fn markdown_resume_is_split_into_sections() {
+        let draft = import_text_resume(include_str!("testdata/resume.md"));
+        let resume = &draft.resume;
+        assert_eq!(resume.experience.len(), 2);
+        assert_eq!(resume.education.len(), 1);
+        assert_eq!(resume.personal_info.summary, "Backend engineer with ten years of experience building reliable services.");
+        let skills: Vec<(&str, &str)> = resume.skills.skill_list.iter().map(|skill| (skill.category.as_str(), skill.name.as_str())).collect();
+        assert_eq!(skills, vec![("Languages", "Rust"), ("Languages", "Go"), ("Languages", "Python"), ("Tools", "Docker"), ("Tools", "Kubernetes")]);
+        // Sections without a place in the resume are reported, not dropped
+        assert_eq!(confidence(&draft, "Not imported: Hobbies"), Some(Confidence::Low));
+    }
+
+    #[test]
+    fn contact_details_are_taken_from_the_header() {
+        let draft = import_text_resume(include_str!("testdata/resume.md"));
+        let info = &draft.resume.personal_info;
+        assert_eq!(info.name, "Jane Doe");
+        assert_eq!(confidence(&draft, "Name"), Some(Confidence::High));
+        assert_eq!(info.email, "jane.doe@example.com");
+        assert_eq!(info.phone, "+1 555 123 4567");
+        assert_eq!(info.location, "Berlin, Germany");
+        let links: Vec<(LinkKind, &str)> = info.links.iter().map(|link| (link.kind, link.value.as_str())).collect();
+        assert_eq!(links, vec![(LinkKind::GitHub, "https://github.com/janedoe"), (LinkKind::LinkedIn, "linkedin.com/in/jane-doe")]);
+        // The headline is left for the user instead of becoming the location
+        assert_eq!(confidence(&draft, "Not imported: Header"), Some(Confidence::Low));
+
+        let plain = import_text_resume(include_str!("testdata/resume.txt"));
+        let info = &plain.resume.personal_info;
+        assert_eq!(info.name, "John Smith");
+        assert_eq!(info.email, "john.smith@example.org");
+        assert_eq!(info.phone, "(020) 7946 0958");
+    }
+
+    #[test]
+    fn entries_get_titles_dates_and_bullets() {
+        let draft = import_text_resume(include_str!("testdata/resume.md"));
+        let [acme, globex] = &draft.resume.experience[..] else { panic!("two positions expected") };
+        assert_eq!((acme.position.as_str(), acme.company.as_str(), acme.location.as_str()), ("Staff Engineer", "Acme Corp", "Berlin"));
+        assert_eq!((acme.start_date.as_str(), acme.end_date.as_str(), acme.is_current), ("2020-01", "", true));
+        assert_eq!(acme.achievements, vec!["Led the migration to Rust, cutting latency by 40%", "Mentored six engineers"]);
+        assert_eq!((globex.position.as_str(), globex.company.as_str()), ("Software Engineer", "Globex"));
+        assert_eq!((globex.start_date.as_str(), globex.end_date.as_str(), globex.is_current), ("2015-03", "2019-12", false));
+        assert_eq!(globex.achievements.len(), 2);
+
+        let edu = &draft.resume.education[0];
+        assert_eq!((edu.institution.as_str(), edu.degree.as_str(), edu.field_of_study.as_str()), ("Technical University of Munich", "M.Sc.", "Computer Science"));
+        assert_eq!((edu.start_date.as_str(), edu.end_date.as_str()), ("2013", "2015"));
+    }
+
+    #[test]
+    fn plain_text_headings_and_date_ranges_are_recognized() {
+        let draft = import_text_resume(include_str!("testdata/resume.txt"));
+        let exp = &draft.resume.experience[0];
+        assert_eq!((exp.position.as_str(), exp.company.as_str()), ("Data Analyst", "Initech"));
+        assert_eq!((exp.start_date.as_str(), exp.end_date.as_str()), ("2018-06", "2021-05"));
+        assert_eq!(exp.achievements, vec!["Automated weekly reports", "Cleaned up the sales database"]);
+        assert_eq!(draft.resume.education[0].institution, "University of Leeds");
+        assert_eq!(draft.resume.skills.skill_list.len(), 3);
+    }
+
+    #[test]
+    fn unparseable_input_gives_an_empty_draft_to_review() {
+        let empty = import_text_resume("");
+        assert!(empty.fields.is_empty());
+        assert!(empty.resume.personal_info.name.is_empty());
+
+        let draft = import_text_resume("%%% ~~~\n\n12345\n@@@\n## \n");
+        assert!(draft.resume.experience.is_empty() && draft.resume.education.is_empty());
+        assert!(draft.resume.skills.skill_list.is_empty());
+        assert_eq!(confidence(&draft, "Name"), Some(Confidence::Low));
+        assert_eq!(draft.fields_to_review().len(), draft.fields.len());
+        assert!(draft.fields.iter().any(|field| field.value.contains("12345")));
+    }
+}
@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::error::Error;
+
+use js_sys::{Function, Promise};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+use crate::application::repository::{AsyncResumeRepository, DocumentStore, RepositoryFuture};
+use crate::domain::Resume;
+use crate::infrastructure::storage::LocalStorageResumeRepository;
+
+// IndexedDB storage for web builds
+//
+// Unlike localStorage, IndexedDB is asynchronous and is not limited to a few
+// megabytes per origin. Records are stored as JSON strings in one object store
+// keyed by name, so later data such as snapshots can share the same database.
+
+const DATABASE_VERSION: u32 = 1;
+const STORE_NAME: &str = "records";
+
+// IndexedDB repository implementation for web applications
+pub struct IndexedDbResumeRepository {
+    database_name: String,
+    storage_key: String,
+    database: RefCell<Option<IdbDatabase>>,
+}
+
+impl IndexedDbResumeRepository {
+    pub fn new(database_name: &str, storage_key: &str) -> Self {
+        Self {
+            database_name: database_name.to_string(),
+            storage_key: storage_key.to_string(),
+            database: RefCell::new(None),
+        }
+    }
+
+    // Opens the database on first use and keeps the connection for later calls
+    async fn database(&self) -> Result<IdbDatabase, Box<dyn Error>> {
+        if let Some(database) = self.database.borrow().as_ref() {
+            return Ok(database.clone());
+        }
+
+        let factory = web_sys::window()
+            .ok_or("Failed to get window")?
+            .indexed_db()
+            .map_err(|_| "Failed to get IndexedDB")?
+            .ok_or("IndexedDB not available")?;
+        let request = factory
+            .open_with_u32(&self.database_name, DATABASE_VERSION)
+            .map_err(|_| "Failed to open IndexedDB")?;
+
+        // Only runs when the database is created or its version is raised
+        let upgrading = request.clone();
+        let on_upgrade = Closure::once_into_js(move || {
+            if let Ok(result) = upgrading.result() {
+                let database: IdbDatabase = result.unchecked_into();
+                let _ = database.create_object_store(STORE_NAME);
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+        let database: IdbDatabase = wait(&request).await?.unchecked_into();
+        self.database.replace(Some(database.clone()));
+        Ok(database)
+    }
+
+    async fn store(&self, mode: IdbTransactionMode) -> Result<IdbObjectStore, Box<dyn Error>> {
+        self.database()
+            .await?
+            .transaction_with_str_and_mode(STORE_NAME, mode)
+            .and_then(|transaction| transaction.object_store(STORE_NAME))
+            .map_err(|_| "Failed to start IndexedDB transaction".into())
+    }
+
+    async fn get(&self) -> Result<Option<String>, Box<dyn Error>> {
+        let request = self.store(IdbTransactionMode::Readonly)
+            .await?
+            .get(&JsValue::from_str(&self.storage_key))
+            .map_err(|_| "Failed to load from IndexedDB")?;
+        Ok(wait(&request).await?.as_string())
+    }
+
+    async fn put(&self, json: &str) -> Result<(), Box<dyn Error>> {
+        let request = self.store(IdbTransactionMode::Readwrite)
+            .await?
+            .put_with_key(&JsValue::from_str(json), &JsValue::from_str(&self.storage_key))
+            .map_err(|_| "Failed to save to IndexedDB")?;
+        wait(&request).await?;
+        Ok(())
+    }
+
+    // Deletes the stored resume
+    pub async fn remove(&self) -> Result<(), Box<dyn Error>> {
+        let request = self.store(IdbTransactionMode::Readwrite)
+            .await?
+            .delete(&JsValue::from_str(&self.storage_key))
+            .map_err(|_| "Failed to delete from IndexedDB")?;
+        wait(&request).await?;
+        Ok(())
+    }
+
+    // Moves a resume saved by the localStorage repository into IndexedDB
+    async fn migrate_local_storage(&self) -> Result<Option<String>, Box<dyn Error>> {
+        let legacy = LocalStorageResumeRepository::new(&self.storage_key);
+        let Some(document) = legacy.load_document().await? else {
+            return Ok(None);
+        };
+
+        self.put(&document).await?;
+        legacy.remove()?;
+        tracing::debug!("Migrated resume from local storage to IndexedDB");
+        Ok(Some(document))
+    }
+}
+
+impl DocumentStore for IndexedDbResumeRepository {
+    fn save_document<'a>(&'a self, document: &'a str) -> RepositoryFuture<'a, ()> {
+        Box::pin(self.put(document))
+    }
+
+    fn load_document(&self) -> RepositoryFuture<'_, Option<String>> {
+        Box::pin(async move {
+            match self.get().await? {
+                Some(document) => Ok(Some(document)),
+                None => self.migrate_local_storage().await,
+            }
+        })
+    }
+}
+
+impl AsyncResumeRepository for IndexedDbResumeRepository {
+    fn save<'a>(&'a self, resume: &'a Resume) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move {
+            let json = serde_json::to_string(resume)?;
+            self.put(&json).await
+        })
+    }
+
+    fn load(&self) -> RepositoryFuture<'_, Resume> {
+        Box::pin(async move {
+            let json = self.load_document().await?.ok_or("Resume not found in storage")?;
+            Ok(serde_json::from_str(&json)?)
+        })
+    }
+
+    fn exists(&self) -> RepositoryFuture<'_, bool> {
+        Box::pin(async move { Ok(self.load_document().await?.is_some()) })
+    }
+}
+
+// Resolves with the request's result once it succeeds, or fails with its error
+async fn wait(request: &IdbRequest) -> Result<JsValue, Box<dyn Error>> {
+    // A request fires exactly one of success or error, so one callback serves both
+    let promise = Promise::new(&mut |resolve: Function, _reject: Function| {
+        let on_done = Closure::once_into_js(move || {
+            let _ = resolve.call0(&JsValue::NULL);
+        });
+        request.set_onsuccess(Some(on_done.unchecked_ref()));
+        request.set_onerror(Some(on_done.unchecked_ref()));
+    });
+    JsFuture::from(promise)
+        .await
+        .map_err(|_| "IndexedDB request was interrupted")?;
+
+    if let Ok(Some(error)) = request.error() {
+        return Err(format!("IndexedDB request failed: {}", error.message()).into());
+    }
+    request.result().map_err(|_| "IndexedDB request has no result".into())
+}
@@ -1,5 +1,24 @@
 // Infrastructure layer - contains external interfaces implementations
 pub mod storage;
+pub mod encryption;
+pub mod sync;
+#[cfg(feature = "web")]
+pub mod indexed_db;
+#[cfg(feature = "web")]
+pub mod sync_http;
+#[cfg(feature = "sync-server")]
+pub mod sync_server;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod export;
+pub mod backup;
+pub mod import;
 
 // Re-export storage implementations for easier access
-pub use storage::*; 
\ No newline at end of file
+pub use storage::*;
+pub use encryption::*;
+pub use sync::*;
+#[cfg(feature = "web")]
+pub use indexed_db::*;
+#[cfg(feature = "web")]
+pub use sync_http::*;
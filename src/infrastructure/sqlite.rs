@@ -0,0 +1,984 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::application::repository::{ApplicationRepository, LibraryRepository, ResumeRepository};
+use crate::domain::{JobApplication, LibraryEntry, Education, EntryOrder, Experience, LinkKind, PageBreaks, PaperSize, PersonalInfo, PhotoShape, ProfileLink, ProfilePhoto, Project, Resume, ResumeTheme, Section, Skill, Skills};
+
+// SQLite storage for native builds
+//
+// One database file holds any number of resumes, each identified by a key.
+// Resumes are stored in a normalized schema: personal details on the resume
+// row, profile links in `profile_links`, section order in `sections`, every
+// education, experience, project and skill entry in `entries` with its bullet
+// lists in `entry_items`, and the skill categories and proficiency labels in
+// `skill_categories` and `skill_levels`. Each save replaces the resume's rows
+// in a single transaction, records a JSON snapshot when the content changed,
+// and refreshes the FTS5 index used by `search`. The desktop app keeps its
+// resume library and tracked applications as JSON in `documents`.
+
+// Schema migrations, applied in order; the database's user_version is the number applied so far
+const MIGRATIONS: &[&str] = &[
+    "
+    CREATE TABLE resumes (
+        id INTEGER PRIMARY KEY,
+        key TEXT NOT NULL UNIQUE,
+        theme TEXT NOT NULL,
+        name TEXT NOT NULL,
+        email TEXT NOT NULL,
+        phone TEXT NOT NULL,
+        website TEXT NOT NULL,
+        linkedin TEXT NOT NULL,
+        github TEXT NOT NULL,
+        location TEXT NOT NULL,
+        summary TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE sections (
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        PRIMARY KEY (resume_id, kind)
+    );
+    CREATE TABLE entries (
+        id INTEGER PRIMARY KEY,
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        title TEXT NOT NULL,
+        subtitle TEXT NOT NULL,
+        detail TEXT NOT NULL,
+        location TEXT NOT NULL,
+        start_date TEXT NOT NULL,
+        end_date TEXT NOT NULL,
+        description TEXT NOT NULL,
+        url TEXT NOT NULL,
+        score TEXT NOT NULL,
+        level INTEGER NOT NULL,
+        is_current INTEGER NOT NULL,
+        hidden INTEGER NOT NULL
+    );
+    CREATE INDEX entries_by_resume ON entries (resume_id, kind, position);
+    CREATE TABLE entry_items (
+        entry_id INTEGER NOT NULL REFERENCES entries(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        text TEXT NOT NULL,
+        PRIMARY KEY (entry_id, position)
+    );
+    CREATE TABLE skill_categories (
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        category TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        skill TEXT NOT NULL,
+        PRIMARY KEY (resume_id, category, position)
+    );
+    CREATE TABLE snapshots (
+        id INTEGER PRIMARY KEY,
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE VIRTUAL TABLE search_index USING fts5(resume_key UNINDEXED, section UNINDEXED, content);
+    ",
+    "
+    ALTER TABLE resumes ADD COLUMN paper_size TEXT NOT NULL DEFAULT 'A4';
+    ALTER TABLE entries ADD COLUMN keep_together INTEGER NOT NULL DEFAULT 0;
+    ALTER TABLE entries ADD COLUMN new_page INTEGER NOT NULL DEFAULT 0;
+    ",
+    "
+    ALTER TABLE resumes ADD COLUMN photo BLOB;
+    ALTER TABLE resumes ADD COLUMN photo_shape TEXT;
+    ",
+    "
+    CREATE TABLE profile_links (
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        kind TEXT NOT NULL,
+        value TEXT NOT NULL,
+        label TEXT NOT NULL,
+        PRIMARY KEY (resume_id, position)
+    );
+    INSERT INTO profile_links (resume_id, position, kind, value, label)
+        SELECT id, 0, 'Website', website, '' FROM resumes WHERE website != ''
+        UNION ALL SELECT id, 1, 'LinkedIn', linkedin, '' FROM resumes WHERE linkedin != ''
+        UNION ALL SELECT id, 2, 'GitHub', github, '' FROM resumes WHERE github != '';
+    ALTER TABLE resumes DROP COLUMN website;
+    ALTER TABLE resumes DROP COLUMN linkedin;
+    ALTER TABLE resumes DROP COLUMN github;
+    ",
+    "
+    ALTER TABLE skill_categories RENAME TO legacy_skill_categories;
+    CREATE TABLE skill_categories (
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        PRIMARY KEY (resume_id, position)
+    );
+    INSERT INTO skill_categories (resume_id, position, name)
+        SELECT resume_id, ROW_NUMBER() OVER (PARTITION BY resume_id ORDER BY category) - 1, category
+        FROM (SELECT DISTINCT resume_id, category FROM legacy_skill_categories);
+    ALTER TABLE entries ADD COLUMN years INTEGER;
+    ALTER TABLE entries ADD COLUMN featured INTEGER NOT NULL DEFAULT 0;
+    UPDATE entries SET subtitle = (
+        SELECT MIN(legacy.category) FROM legacy_skill_categories legacy
+        WHERE legacy.resume_id = entries.resume_id AND lower(legacy.skill) = lower(entries.title)
+    )
+    WHERE kind = 'skill' AND EXISTS (
+        SELECT 1 FROM legacy_skill_categories legacy
+        WHERE legacy.resume_id = entries.resume_id AND lower(legacy.skill) = lower(entries.title)
+    );
+    INSERT INTO entries (resume_id, kind, position, title, subtitle, detail, location, start_date, end_date,
+                         description, url, score, level, is_current, hidden)
+        SELECT resume_id, 'skill',
+               (SELECT COUNT(*) FROM entries WHERE entries.resume_id = named.resume_id AND kind = 'skill')
+                   + ROW_NUMBER() OVER (PARTITION BY resume_id ORDER BY category, position) - 1,
+               skill, category, '', '', '', '', '', '', '', 0, 0, 0
+        FROM (
+            SELECT resume_id, skill, MIN(category) AS category, MIN(position) AS position
+            FROM legacy_skill_categories legacy
+            WHERE NOT EXISTS (
+                SELECT 1 FROM entries
+                WHERE entries.resume_id = legacy.resume_id AND kind = 'skill' AND lower(title) = lower(legacy.skill)
+            )
+            GROUP BY resume_id, lower(skill)
+        ) named;
+    DROP TABLE legacy_skill_categories;
+    CREATE TABLE skill_levels (
+        resume_id INTEGER NOT NULL REFERENCES resumes(id) ON DELETE CASCADE,
+        level INTEGER NOT NULL,
+        label TEXT NOT NULL,
+        PRIMARY KEY (resume_id, level)
+    );
+    ",
+    "
+    ALTER TABLE resumes ADD COLUMN education_order TEXT NOT NULL DEFAULT 'Newest first';
+    ALTER TABLE resumes ADD COLUMN experience_order TEXT NOT NULL DEFAULT 'Newest first';
+    ",
+    "
+    ALTER TABLE entries ADD COLUMN same_employer INTEGER NOT NULL DEFAULT 0;
+    ",
+    "
+    CREATE TABLE documents (
+        key TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    ",
+];
+
+// Entry kinds stored in the `entries` table
+const EDUCATION: &str = "education";
+const EXPERIENCE: &str = "experience";
+const PROJECT: &str = "project";
+const SKILL: &str = "skill";
+
+// A saved version of a resume
+pub struct SnapshotInfo {
+    pub id: i64,
+    pub created_at: String,
+}
+
+// A full-text search match
+pub struct SearchHit {
+    pub resume_key: String,
+    pub section: String,
+    pub snippet: String,
+}
+
+// Columns shared by every row in `entries`, filled per entry kind
+#[derive(Default)]
+struct EntryRow {
+    title: String,
+    subtitle: String,
+    detail: String,
+    location: String,
+    start_date: String,
+    end_date: String,
+    description: String,
+    url: String,
+    score: String,
+    level: i32,
+    is_current: bool,
+    hidden: bool,
+    page_breaks: PageBreaks,
+    years: Option<u32>,
+    featured: bool,
+    // Experience entry listed under the employer of the one before it
+    same_employer: bool,
+    items: Vec<String>,
+}
+
+// SQLite repository implementation for native applications
+pub struct SqliteResumeRepository {
+    connection: Connection,
+    resume_key: String,
+}
+
+impl SqliteResumeRepository {
+    // Opens (or creates) a database file and migrates it to the current schema
+    pub fn open(path: impl AsRef<Path>, resume_key: &str) -> Result<Self, Box<dyn Error>> {
+        Self::with_connection(Connection::open(path)?, resume_key)
+    }
+
+    // Database that only lives as long as the repository
+    pub fn in_memory(resume_key: &str) -> Result<Self, Box<dyn Error>> {
+        Self::with_connection(Connection::open_in_memory()?, resume_key)
+    }
+
+    fn with_connection(connection: Connection, resume_key: &str) -> Result<Self, Box<dyn Error>> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        migrate(&connection)?;
+        Ok(Self {
+            connection,
+            resume_key: resume_key.to_string(),
+        })
+    }
+
+    // Switches to another resume in the same database
+    pub fn select(&mut self, resume_key: &str) {
+        self.resume_key = resume_key.to_string();
+    }
+
+    // Keys of all stored resumes, sorted
+    pub fn resume_keys(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        let mut statement = self.connection.prepare("SELECT key FROM resumes ORDER BY key")?;
+        let keys = statement.query_map([], |row| row.get(0))?.collect::<Result<_, _>>()?;
+        Ok(keys)
+    }
+
+    // Saved versions of this resume, newest first
+    pub fn snapshots(&self) -> Result<Vec<SnapshotInfo>, Box<dyn Error>> {
+        let mut statement = self.connection.prepare(
+            "SELECT snapshots.id, snapshots.created_at FROM snapshots
+             JOIN resumes ON resumes.id = snapshots.resume_id
+             WHERE resumes.key = ?1 ORDER BY snapshots.id DESC",
+        )?;
+        let snapshots = statement
+            .query_map([&self.resume_key], |row| Ok(SnapshotInfo { id: row.get(0)?, created_at: row.get(1)? }))?
+            .collect::<Result<_, _>>()?;
+        Ok(snapshots)
+    }
+
+    pub fn load_snapshot(&self, id: i64) -> Result<Resume, Box<dyn Error>> {
+        let data: String = self.connection
+            .query_row(
+                "SELECT snapshots.data FROM snapshots
+                 JOIN resumes ON resumes.id = snapshots.resume_id
+                 WHERE snapshots.id = ?1 AND resumes.key = ?2",
+                params![id, self.resume_key],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or("Snapshot not found")?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    // Full-text search across every stored resume; words are matched as
+    // typed, may end in `*` to match a prefix and can be combined with AND, OR and NOT
+    pub fn search(&self, query: &str) -> Result<Vec<SearchHit>, Box<dyn Error>> {
+        let query = fts_query(query);
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut statement = self.connection.prepare(
+            "SELECT resume_key, section, snippet(search_index, 2, '[', ']', '...', 12)
+             FROM search_index WHERE search_index MATCH ?1 ORDER BY rank",
+        )?;
+        let hits = statement
+            .query_map([query], |row| {
+                Ok(SearchHit { resume_key: row.get(0)?, section: row.get(1)?, snippet: row.get(2)? })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(hits)
+    }
+
+    // Deletes this resume together with its entries, snapshots and search index rows
+    pub fn delete(&self) -> Result<(), Box<dyn Error>> {
+        let transaction = self.connection.unchecked_transaction()?;
+        transaction.execute("DELETE FROM search_index WHERE resume_key = ?1", [&self.resume_key])?;
+        transaction.execute("DELETE FROM resumes WHERE key = ?1", [&self.resume_key])?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn resume_id(&self) -> Result<Option<i64>, Box<dyn Error>> {
+        Ok(self.connection
+            .query_row("SELECT id FROM resumes WHERE key = ?1", [&self.resume_key], |row| row.get(0))
+            .optional()?)
+    }
+
+    fn write(&self, transaction: &Transaction, resume: &Resume) -> Result<(), Box<dyn Error>> {
+        let info = &resume.personal_info;
+        let now = chrono::Utc::now().to_rfc3339();
+        transaction.execute(
+            "INSERT INTO resumes (key, theme, name, email, phone, location, summary, updated_at, paper_size, photo, photo_shape,
+                                  education_order, experience_order)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+             ON CONFLICT (key) DO UPDATE SET
+                theme = excluded.theme, name = excluded.name, email = excluded.email, phone = excluded.phone,
+                location = excluded.location, summary = excluded.summary, updated_at = excluded.updated_at,
+                paper_size = excluded.paper_size, photo = excluded.photo, photo_shape = excluded.photo_shape,
+                education_order = excluded.education_order, experience_order = excluded.experience_order",
+            params![
+                self.resume_key, resume.theme.name(), info.name, info.email, info.phone,
+                info.location, info.summary, now,
+                resume.paper_size.name(),
+                info.photo.as_ref().map(|photo| &photo.jpeg),
+                info.photo.as_ref().map(|photo| photo.shape.name()),
+                resume.education_order.name(),
+                resume.experience_order.name()
+            ],
+        )?;
+        let resume_id: i64 = transaction.query_row("SELECT id FROM resumes WHERE key = ?1", [&self.resume_key], |row| row.get(0))?;
+
+        // Child rows are replaced wholesale; entry_items go with their entries
+        transaction.execute("DELETE FROM profile_links WHERE resume_id = ?1", [resume_id])?;
+        transaction.execute("DELETE FROM sections WHERE resume_id = ?1", [resume_id])?;
+        transaction.execute("DELETE FROM entries WHERE resume_id = ?1", [resume_id])?;
+        transaction.execute("DELETE FROM skill_categories WHERE resume_id = ?1", [resume_id])?;
+        transaction.execute("DELETE FROM skill_levels WHERE resume_id = ?1", [resume_id])?;
+
+        for (position, link) in info.links.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO profile_links (resume_id, position, kind, value, label) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![resume_id, position, link.kind.name(), link.value, link.label],
+            )?;
+        }
+
+        for (position, section) in resume.section_order.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO sections (resume_id, kind, position) VALUES (?1, ?2, ?3)",
+                params![resume_id, section_kind(*section), position],
+            )?;
+        }
+
+        let entries = resume.education.iter().map(|edu| (EDUCATION, education_row(edu)))
+            .chain(resume.experience.iter().map(|exp| (EXPERIENCE, experience_row(exp))))
+            .chain(resume.projects.iter().map(|project| (PROJECT, project_row(project))))
+            .chain(resume.skills.skill_list.iter().map(|skill| (SKILL, skill_row(skill))));
+        let mut positions = std::collections::HashMap::new();
+        for (kind, entry) in entries {
+            let position = positions.entry(kind).or_insert(0);
+            insert_entry(transaction, resume_id, kind, *position, &entry)?;
+            *position += 1;
+        }
+
+        for (position, category) in resume.skills.categories.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO skill_categories (resume_id, position, name) VALUES (?1, ?2, ?3)",
+                params![resume_id, position, category],
+            )?;
+        }
+        for (index, label) in resume.skills.level_labels.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO skill_levels (resume_id, level, label) VALUES (?1, ?2, ?3)",
+                params![resume_id, index + 1, label],
+            )?;
+        }
+
+        let json = serde_json::to_string(resume)?;
+        let latest: Option<String> = transaction
+            .query_row("SELECT data FROM snapshots WHERE resume_id = ?1 ORDER BY id DESC LIMIT 1", [resume_id], |row| row.get(0))
+            .optional()?;
+        if latest.as_deref() != Some(json.as_str()) {
+            transaction.execute(
+                "INSERT INTO snapshots (resume_id, created_at, data) VALUES (?1, ?2, ?3)",
+                params![resume_id, now, json],
+            )?;
+        }
+
+        index_resume(transaction, &self.resume_key, resume)
+    }
+
+    fn read(&self, resume_id: i64) -> Result<Resume, Box<dyn Error>> {
+        let (theme, paper_size, entry_orders, mut personal_info) = self.connection.query_row(
+            "SELECT theme, name, email, phone, location, summary, paper_size, photo, photo_shape, education_order, experience_order
+             FROM resumes WHERE id = ?1",
+            [resume_id],
+            |row| {
+                let theme: String = row.get(0)?;
+                let paper_size: String = row.get(6)?;
+                let photo: Option<Vec<u8>> = row.get(7)?;
+                let photo_shape: Option<String> = row.get(8)?;
+                let entry_orders: (String, String) = (row.get(9)?, row.get(10)?);
+                Ok((theme, paper_size, entry_orders, PersonalInfo {
+                    name: row.get(1)?,
+                    email: row.get(2)?,
+                    phone: row.get(3)?,
+                    location: row.get(4)?,
+                    summary: row.get(5)?,
+                    links: Vec::new(),
+                    photo: photo.map(|jpeg| ProfilePhoto {
+                        shape: photo_shape.and_then(|name| PhotoShape::from_name(&name)).unwrap_or_default(),
+                        jpeg,
+                    }),
+                }))
+            },
+        )?;
+
+        let mut statement = self.connection.prepare("SELECT kind, value, label FROM profile_links WHERE resume_id = ?1 ORDER BY position")?;
+        personal_info.links = statement
+            .query_map([resume_id], |row| {
+                let kind: String = row.get(0)?;
+                Ok(ProfileLink {
+                    kind: LinkKind::from_name(&kind).unwrap_or_default(),
+                    value: row.get(1)?,
+                    label: row.get(2)?,
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+
+        let mut statement = self.connection.prepare("SELECT kind FROM sections WHERE resume_id = ?1 ORDER BY position")?;
+        let section_order = statement
+            .query_map([resume_id], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?
+            .iter()
+            .filter_map(|kind| Section::all().into_iter().find(|section| section_kind(*section) == kind))
+            .collect();
+
+        let mut statement = self.connection.prepare("SELECT name FROM skill_categories WHERE resume_id = ?1 ORDER BY position")?;
+        let categories = statement.query_map([resume_id], |row| row.get(0))?.collect::<Result<_, _>>()?;
+
+        // Resumes saved before level labels were configurable have none stored
+        let mut statement = self.connection.prepare("SELECT label FROM skill_levels WHERE resume_id = ?1 ORDER BY level")?;
+        let mut level_labels: Vec<String> = statement.query_map([resume_id], |row| row.get(0))?.collect::<Result<_, _>>()?;
+        if level_labels.is_empty() {
+            level_labels = Skills::default_level_labels();
+        }
+
+        Ok(Resume {
+            personal_info,
+            education: self.read_entries(resume_id, EDUCATION)?.into_iter().map(education_from_row).collect(),
+            experience: self.read_entries(resume_id, EXPERIENCE)?.into_iter().map(experience_from_row).collect(),
+            projects: self.read_entries(resume_id, PROJECT)?.into_iter().map(project_from_row).collect(),
+            skills: Skills {
+                categories,
+                skill_list: self.read_entries(resume_id, SKILL)?.into_iter().map(skill_from_row).collect(),
+                level_labels,
+            },
+            theme: ResumeTheme::all().into_iter().find(|candidate| candidate.name() == theme).unwrap_or_default(),
+            section_order,
+            paper_size: PaperSize::from_name(&paper_size).unwrap_or_default(),
+            education_order: EntryOrder::from_name(&entry_orders.0).unwrap_or_default(),
+            experience_order: EntryOrder::from_name(&entry_orders.1).unwrap_or_default(),
+        })
+    }
+
+    fn read_entries(&self, resume_id: i64, kind: &str) -> Result<Vec<EntryRow>, Box<dyn Error>> {
+        let mut statement = self.connection.prepare(
+            "SELECT id, title, subtitle, detail, location, start_date, end_date, description, url, score, level, is_current, hidden,
+                    keep_together, new_page, years, featured, same_employer
+             FROM entries WHERE resume_id = ?1 AND kind = ?2 ORDER BY position",
+        )?;
+        let rows = statement
+            .query_map(params![resume_id, kind], |row| {
+                Ok((row.get::<_, i64>(0)?, EntryRow {
+                    title: row.get(1)?,
+                    subtitle: row.get(2)?,
+                    detail: row.get(3)?,
+                    location: row.get(4)?,
+                    start_date: row.get(5)?,
+                    end_date: row.get(6)?,
+                    description: row.get(7)?,
+                    url: row.get(8)?,
+                    score: row.get(9)?,
+                    level: row.get(10)?,
+                    is_current: row.get(11)?,
+                    hidden: row.get(12)?,
+                    page_breaks: PageBreaks {
+                        keep_together: row.get(13)?,
+                        new_page: row.get(14)?,
+                    },
+                    years: row.get(15)?,
+                    featured: row.get(16)?,
+                    same_employer: row.get(17)?,
+                    items: Vec::new(),
+                }))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut items = self.connection.prepare("SELECT text FROM entry_items WHERE entry_id = ?1 ORDER BY position")?;
+        rows.into_iter()
+            .map(|(id, mut entry)| {
+                entry.items = items.query_map([id], |row| row.get(0))?.collect::<Result<_, _>>()?;
+                Ok(entry)
+            })
+            .collect()
+    }
+}
+
+impl ResumeRepository for SqliteResumeRepository {
+    fn save(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
+        let transaction = self.connection.unchecked_transaction()?;
+        self.write(&transaction, resume)?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn load(&self) -> Result<Resume, Box<dyn Error>> {
+        let resume_id = self.resume_id()?.ok_or("Resume not found in storage")?;
+        self.read(resume_id)
+    }
+
+    fn exists(&self) -> bool {
+        matches!(self.resume_id(), Ok(Some(_)))
+    }
+}
+
+// JSON document stored under a key in the `documents` table
+pub struct SqliteDocumentStore {
+    connection: Connection,
+    key: String,
+}
+
+impl SqliteDocumentStore {
+    pub fn open(path: impl AsRef<Path>, key: &str) -> Result<Self, Box<dyn Error>> {
+        let connection = Connection::open(path)?;
+        migrate(&connection)?;
+        Ok(Self { connection, key: key.to_string() })
+    }
+
+    fn read(&self) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(self.connection
+            .query_row("SELECT data FROM documents WHERE key = ?1", [&self.key], |row| row.get(0))
+            .optional()?)
+    }
+
+    fn write(&self, data: &str) -> Result<(), Box<dyn Error>> {
+        self.connection.execute(
+            "INSERT INTO documents (key, data) VALUES (?1, ?2) ON CONFLICT (key) DO UPDATE SET data = excluded.data",
+            params![self.key, data],
+        )?;
+        Ok(())
+    }
+}
+
+// Library of the desktop app, kept next to the resumes it lists
+pub struct SqliteLibraryRepository {
+    documents: SqliteDocumentStore,
+}
+
+impl SqliteLibraryRepository {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        Ok(Self { documents: SqliteDocumentStore::open(path, "resume-library")? })
+    }
+}
+
+impl LibraryRepository for SqliteLibraryRepository {
+    fn save_all(&self, entries: &[LibraryEntry]) -> Result<(), Box<dyn Error>> {
+        self.documents.write(&serde_json::to_string(entries)?)
+    }
+
+    fn load_all(&self) -> Result<Vec<LibraryEntry>, Box<dyn Error>> {
+        match self.documents.read()? {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+// Tracked applications of the desktop app
+pub struct SqliteApplicationRepository {
+    documents: SqliteDocumentStore,
+}
+
+impl SqliteApplicationRepository {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        Ok(Self { documents: SqliteDocumentStore::open(path, "resume-applications")? })
+    }
+}
+
+impl ApplicationRepository for SqliteApplicationRepository {
+    fn save_all(&self, applications: &[JobApplication]) -> Result<(), Box<dyn Error>> {
+        self.documents.write(&serde_json::to_string(applications)?)
+    }
+
+    fn load_all(&self) -> Result<Vec<JobApplication>, Box<dyn Error>> {
+        match self.documents.read()? {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+// Database used by the desktop app: $RESUME_BUILDER_DB if set, otherwise
+// library.db in the user's data directory, which is created when missing
+pub fn default_database_path() -> PathBuf {
+    if let Some(path) = std::env::var_os("RESUME_BUILDER_DB") {
+        return PathBuf::from(path);
+    }
+    let data_dir = std::env::var_os("XDG_DATA_HOME").map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")));
+    match data_dir {
+        Some(dir) => {
+            let dir = dir.join("resume-builder");
+            if let Err(err) = std::fs::create_dir_all(&dir) {
+                tracing::warn!("Could not create {}: {}", dir.display(), err);
+            }
+            dir.join("library.db")
+        }
+        None => PathBuf::from("resume-builder.db"),
+    }
+}
+
+fn migrate(connection: &Connection) -> Result<(), Box<dyn Error>> {
+    let applied: usize = connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if applied > MIGRATIONS.len() {
+        return Err("The database was created by a newer version of the app".into());
+    }
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(applied) {
+        let transaction = connection.unchecked_transaction()?;
+        transaction.execute_batch(migration)?;
+        transaction.pragma_update(None, "user_version", index + 1)?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+// Turns what the user typed into an FTS5 query: every word becomes a quoted
+// string, so "C++", "node.js" or a stray quote cannot break the query syntax;
+// operators are kept only between two words
+fn fts_query(query: &str) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    for word in query.split_whitespace() {
+        if matches!(word, "AND" | "OR" | "NOT") {
+            if parts.last().is_some_and(|last| !matches!(last.as_str(), "AND" | "OR" | "NOT")) {
+                parts.push(word.to_string());
+            }
+            continue;
+        }
+        let (text, prefix) = match word.strip_suffix('*') {
+            Some(text) => (text, "*"),
+            None => (word, ""),
+        };
+        if text.chars().any(char::is_alphanumeric) {
+            parts.push(format!("\"{}\"{}", text.replace('"', "\"\""), prefix));
+        }
+    }
+    while parts.last().is_some_and(|last| matches!(last.as_str(), "AND" | "OR" | "NOT")) {
+        parts.pop();
+    }
+    parts.join(" ")
+}
+
+// Rebuilds the search rows for one resume, one row per section
+fn index_resume(transaction: &Transaction, resume_key: &str, resume: &Resume) -> Result<(), Box<dyn Error>> {
+    transaction.execute("DELETE FROM search_index WHERE resume_key = ?1", [resume_key])?;
+
+    let info = &resume.personal_info;
+    let mut skills: Vec<String> = resume.skills.skill_list.iter().map(|skill| skill.name.clone()).collect();
+    skills.extend(resume.skills.categories.iter().cloned());
+    let sections = [
+        (Section::Personal, vec![info.name.clone(), info.location.clone(), info.summary.clone()]),
+        (Section::Education, resume.education.iter()
+            .map(|edu| [edu.institution.as_str(), &edu.degree, &edu.field_of_study, &edu.location, &edu.description].join("\n"))
+            .collect()),
+        (Section::Experience, resume.experience.iter()
+            .map(|exp| [exp.company.clone(), exp.position.clone(), exp.location.clone(), exp.description.clone(), exp.achievements.join("\n")].join("\n"))
+            .collect()),
+        (Section::Skills, skills),
+        (Section::Projects, resume.projects.iter()
+            .map(|project| [project.name.clone(), project.role.clone(), project.description.clone(), project.technologies.join(" ")].join("\n"))
+            .collect()),
+    ];
+
+    for (section, parts) in sections {
+        let content = parts.join("\n");
+        if !content.trim().is_empty() {
+            transaction.execute(
+                "INSERT INTO search_index (resume_key, section, content) VALUES (?1, ?2, ?3)",
+                params![resume_key, section.title(), content],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn insert_entry(transaction: &Transaction, resume_id: i64, kind: &str, position: usize, entry: &EntryRow) -> Result<(), Box<dyn Error>> {
+    transaction.execute(
+        "INSERT INTO entries (resume_id, kind, position, title, subtitle, detail, location, start_date, end_date,
+                              description, url, score, level, is_current, hidden, keep_together, new_page, years, featured,
+                              same_employer)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+        params![
+            resume_id, kind, position, entry.title, entry.subtitle, entry.detail, entry.location,
+            entry.start_date, entry.end_date, entry.description, entry.url, entry.score, entry.level,
+            entry.is_current, entry.hidden, entry.page_breaks.keep_together, entry.page_breaks.new_page,
+            entry.years, entry.featured, entry.same_employer
+        ],
+    )?;
+    let entry_id = transaction.last_insert_rowid();
+    for (position, item) in entry.items.iter().enumerate() {
+        transaction.execute(
+            "INSERT INTO entry_items (entry_id, position, text) VALUES (?1, ?2, ?3)",
+            params![entry_id, position, item],
+        )?;
+    }
+    Ok(())
+}
+
+fn section_kind(section: Section) -> &'static str {
+    match section {
+        Section::Personal => "personal",
+        Section::Education => "education",
+        Section::Experience => "experience",
+        Section::Skills => "skills",
+        Section::Projects => "projects",
+    }
+}
+
+fn education_row(edu: &Education) -> EntryRow {
+    EntryRow {
+        title: edu.institution.clone(),
+        subtitle: edu.degree.clone(),
+        detail: edu.field_of_study.clone(),
+        location: edu.location.clone(),
+        start_date: edu.start_date.clone(),
+        end_date: edu.end_date.clone(),
+        description: edu.description.clone(),
+        score: edu.gpa.clone(),
+        hidden: edu.hidden,
+        page_breaks: edu.page_breaks,
+        ..EntryRow::default()
+    }
+}
+
+fn education_from_row(row: EntryRow) -> Education {
+    Education {
+        institution: row.title,
+        degree: row.subtitle,
+        field_of_study: row.detail,
+        location: row.location,
+        start_date: row.start_date,
+        end_date: row.end_date,
+        description: row.description,
+        gpa: row.score,
+        hidden: row.hidden,
+        page_breaks: row.page_breaks,
+    }
+}
+
+fn experience_row(exp: &Experience) -> EntryRow {
+    EntryRow {
+        title: exp.company.clone(),
+        subtitle: exp.position.clone(),
+        location: exp.location.clone(),
+        start_date: exp.start_date.clone(),
+        end_date: exp.end_date.clone(),
+        description: exp.description.clone(),
+        is_current: exp.is_current,
+        hidden: exp.hidden,
+        page_breaks: exp.page_breaks,
+        same_employer: exp.same_employer,
+        items: exp.achievements.clone(),
+        ..EntryRow::default()
+    }
+}
+
+fn experience_from_row(row: EntryRow) -> Experience {
+    Experience {
+        company: row.title,
+        position: row.subtitle,
+        location: row.location,
+        start_date: row.start_date,
+        end_date: row.end_date,
+        description: row.description,
+        achievements: row.items,
+        is_current: row.is_current,
+        hidden: row.hidden,
+        page_breaks: row.page_breaks,
+        same_employer: row.same_employer,
+    }
+}
+
+fn project_row(project: &Project) -> EntryRow {
+    EntryRow {
+        title: project.name.clone(),
+        subtitle: project.role.clone(),
+        start_date: project.start_date.map(|date| date.to_string()).unwrap_or_default(),
+        end_date: project.end_date.map(|date| date.to_string()).unwrap_or_default(),
+        description: project.description.clone(),
+        url: project.url.clone(),
+        hidden: project.hidden,
+        page_breaks: project.page_breaks,
+        items: project.technologies.clone(),
+        ..EntryRow::default()
+    }
+}
+
+fn project_from_row(row: EntryRow) -> Project {
+    Project {
+        name: row.title,
+        role: row.subtitle,
+        start_date: NaiveDate::parse_from_str(&row.start_date, "%Y-%m-%d").ok(),
+        end_date: NaiveDate::parse_from_str(&row.end_date, "%Y-%m-%d").ok(),
+        description: row.description,
+        technologies: row.items,
+        url: row.url,
+        hidden: row.hidden,
+        page_breaks: row.page_breaks,
+    }
+}
+
+fn skill_row(skill: &Skill) -> EntryRow {
+    EntryRow {
+        title: skill.name.clone(),
+        subtitle: skill.category.clone(),
+        level: skill.level,
+        years: skill.years,
+        featured: skill.featured,
+        hidden: skill.hidden,
+        ..EntryRow::default()
+    }
+}
+
+fn skill_from_row(row: EntryRow) -> Skill {
+    Skill {
+        name: row.title,
+        level: row.level,
+        category: row.subtitle,
+        years: row.years,
+        featured: row.featured,
+        hidden: row.hidden,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sample_data::sample_resume;
+
+    #[test]
+    fn migrations_bring_an_old_database_up_to_date() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(MIGRATIONS[0]).unwrap();
+        connection.pragma_update(None, "user_version", 1).unwrap();
+        connection.execute(
+            "INSERT INTO resumes (key, theme, name, email, phone, website, linkedin, github, location, summary, updated_at)
+             VALUES ('old', 'Professional', 'Jane', '', '', 'https://jane.dev', 'jane', '', '', '', '')",
+            [],
+        ).unwrap();
+        connection.execute(
+            "INSERT INTO skill_categories (resume_id, category, position, skill) VALUES (1, 'Languages', 0, 'Rust')",
+            [],
+        ).unwrap();
+
+        let repository = SqliteResumeRepository::with_connection(connection, "old").unwrap();
+        let version: usize = repository.connection.pragma_query_value(None, "user_version", |row| row.get(0)).unwrap();
+        assert_eq!(version, MIGRATIONS.len());
+
+        let resume = repository.load().unwrap();
+        assert_eq!(resume.personal_info.links, vec![
+            ProfileLink::new(LinkKind::Website, "https://jane.dev"),
+            ProfileLink::new(LinkKind::LinkedIn, "jane"),
+        ]);
+        assert_eq!(resume.skills.categories, vec!["Languages".to_string()]);
+        assert_eq!(resume.skills.skill_list[0].name, "Rust");
+        assert_eq!(resume.skills.skill_list[0].category, "Languages");
+        assert_eq!(resume.experience_order, EntryOrder::Chronological);
+
+        // Migrating again is a no-op
+        migrate(&repository.connection).unwrap();
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let repository = SqliteResumeRepository::in_memory("sample").unwrap();
+        assert!(!repository.exists());
+
+        let mut resume = sample_resume();
+        resume.paper_size = PaperSize::Letter;
+        resume.experience_order = EntryOrder::Manual;
+        resume.experience.push(Experience {
+            position: "Lead".to_string(),
+            same_employer: true,
+            ..resume.experience[0].clone()
+        });
+        resume.personal_info.photo = Some(ProfilePhoto { jpeg: vec![1, 2, 3], shape: PhotoShape::Circle });
+        repository.save(&resume).unwrap();
+
+        assert!(repository.exists());
+        assert!(repository.load().unwrap() == resume);
+        assert_eq!(repository.resume_keys().unwrap(), vec!["sample".to_string()]);
+    }
+
+    #[test]
+    fn snapshots_are_kept_per_change() {
+        let mut repository = SqliteResumeRepository::in_memory("a").unwrap();
+        let mut resume = sample_resume();
+        repository.save(&resume).unwrap();
+        repository.save(&resume).unwrap();
+        assert_eq!(repository.snapshots().unwrap().len(), 1);
+
+        resume.personal_info.name = "Jane Doe".to_string();
+        repository.save(&resume).unwrap();
+        let snapshots = repository.snapshots().unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(repository.load_snapshot(snapshots[0].id).unwrap().personal_info.name, "Jane Doe");
+        assert_eq!(repository.load_snapshot(snapshots[1].id).unwrap().personal_info.name, "John Doe");
+
+        // Snapshots belong to their resume
+        repository.select("b");
+        assert!(repository.snapshots().unwrap().is_empty());
+        assert!(repository.load_snapshot(snapshots[0].id).is_err());
+
+        repository.select("a");
+        repository.delete().unwrap();
+        assert!(!repository.exists());
+        assert!(repository.snapshots().unwrap().is_empty());
+    }
+
+    #[test]
+    fn search_finds_words_as_typed() {
+        let mut repository = SqliteResumeRepository::in_memory("backend").unwrap();
+        let mut resume = sample_resume();
+        resume.skills.skill_list[0].name = "C++".to_string();
+        resume.experience[0].description = "Built services in node.js and Rust".to_string();
+        repository.save(&resume).unwrap();
+        repository.select("frontend");
+        repository.save(&Resume { personal_info: PersonalInfo { summary: "React and TypeScript".to_string(), ..PersonalInfo::default() }, ..Resume::default() }).unwrap();
+
+        let keys = |query: &str| -> Vec<String> {
+            let mut keys: Vec<String> = repository.search(query).unwrap().into_iter().map(|hit| hit.resume_key).collect();
+            keys.sort();
+            keys.dedup();
+            keys
+        };
+        assert_eq!(keys("node.js"), vec!["backend"]);
+        assert_eq!(keys("C++"), vec!["backend"]);
+        assert_eq!(keys("Type*"), vec!["frontend"]);
+        assert_eq!(keys("rust AND node.js"), vec!["backend"]);
+        assert!(keys("rust AND typescript").is_empty());
+        assert_eq!(keys("rust OR typescript"), vec!["backend", "frontend"]);
+        assert!(keys("\"unbalanced").is_empty());
+        assert!(keys("AND").is_empty());
+        assert!(keys("  \" ( ").is_empty());
+        assert_eq!(keys("rust OR"), vec!["backend"]);
+    }
+
+    #[test]
+    fn desktop_data_survives_reopening_the_database() {
+        let path = std::env::temp_dir().join(format!("resume-builder-test-{}.db", std::process::id()));
+        let entries = vec![LibraryEntry { id: 1, title: "Backend".to_string(), updated_on: None }];
+        let applications = vec![JobApplication { company: "Acme".to_string(), ..JobApplication::default() }];
+        {
+            SqliteLibraryRepository::open(&path).unwrap().save_all(&entries).unwrap();
+            SqliteApplicationRepository::open(&path).unwrap().save_all(&applications).unwrap();
+            SqliteResumeRepository::open(&path, "resume-data").unwrap().save(&sample_resume()).unwrap();
+        }
+        assert!(SqliteLibraryRepository::open(&path).unwrap().load_all().unwrap() == entries);
+        assert!(SqliteApplicationRepository::open(&path).unwrap().load_all().unwrap() == applications);
+        assert!(SqliteResumeRepository::open(&path, "resume-data").unwrap().load().unwrap() == sample_resume());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn search_queries_are_quoted() {
+        assert_eq!(fts_query("C++ node.js"), "\"C++\" \"node.js\"");
+        assert_eq!(fts_query("say \"hi"), "\"say\" \"\"\"hi\"");
+        assert_eq!(fts_query("NOT rust AND AND go*"), "\"rust\" AND \"go\"*");
+        assert_eq!(fts_query("\""), "");
+    }
+}
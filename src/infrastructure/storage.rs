@@ -1,5 +1,7 @@
-use crate::application::repository::ResumeRepository;
-use crate::domain::Resume;
+use crate::application::repository::{ResumeRepository, ApplicationRepository, LibraryRepository};
+#[cfg(feature = "web")]
+use crate::application::repository::TextStore;
+use crate::domain::{Resume, JobApplication, LibraryEntry};
 use std::error::Error;
 use std::cell::RefCell;
 use dioxus::prelude::*;
@@ -56,36 +58,106 @@ impl LocalStorageResumeRepository {
             .map_err(|_| "Failed to get local storage")?
             .ok_or_else(|| "Local storage not available".into())
     }
+
+    fn read_item(&self) -> Result<Option<String>, Box<dyn Error>> {
+        let storage = self.get_local_storage()?;
+        storage
+            .get_item(&self.storage_key)
+            .map_err(|_| "Failed to load from local storage".into())
+    }
+
+    fn write_item(&self, value: &str) -> Result<(), Box<dyn Error>> {
+        let storage = self.get_local_storage()?;
+        storage
+            .set_item(&self.storage_key, value)
+            .map_err(|_| "Failed to save to local storage")?;
+        Ok(())
+    }
+
+    // Delete the stored resume, used once its data has moved elsewhere
+    pub fn remove(&self) -> Result<(), Box<dyn Error>> {
+        let storage = self.get_local_storage()?;
+        storage
+            .remove_item(&self.storage_key)
+            .map_err(|_| "Failed to remove from local storage")?;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "web")]
 impl ResumeRepository for LocalStorageResumeRepository {
     fn save(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
-        let storage = self.get_local_storage()?;
         let json = serde_json::to_string(resume)?;
-        storage
-            .set_item(&self.storage_key, &json)
-            .map_err(|_| "Failed to save to local storage")?;
-        Ok(())
+        self.write_item(&json)
     }
     
     fn load(&self) -> Result<Resume, Box<dyn Error>> {
-        let storage = self.get_local_storage()?;
-        let json = storage
-            .get_item(&self.storage_key)
-            .map_err(|_| "Failed to load from local storage")?
-            .ok_or("Resume not found in storage")?;
+        let json = self.read_item()?.ok_or("Resume not found in storage")?;
         
         let resume: Resume = serde_json::from_str(&json)?;
         Ok(resume)
     }
     
     fn exists(&self) -> bool {
-        if let Ok(storage) = self.get_local_storage() {
-            if let Ok(Some(_)) = storage.get_item(&self.storage_key) {
-                return true;
-            }
+        matches!(self.read_item(), Ok(Some(_)))
+    }
+}
+
+#[cfg(feature = "web")]
+impl TextStore for LocalStorageResumeRepository {
+    fn save_text(&self, document: &str) -> Result<(), Box<dyn Error>> {
+        self.write_item(document)
+    }
+
+    fn load_text(&self) -> Result<Option<String>, Box<dyn Error>> {
+        self.read_item()
+    }
+}
+
+// In-memory application repository for Dioxus applications
+pub struct InMemoryApplicationRepository {
+    applications_signal: RefCell<Signal<Vec<JobApplication>>>,
+}
+
+impl InMemoryApplicationRepository {
+    pub fn new(applications_signal: Signal<Vec<JobApplication>>) -> Self {
+        Self {
+            applications_signal: RefCell::new(applications_signal),
         }
-        false
     }
-} 
\ No newline at end of file
+}
+
+impl ApplicationRepository for InMemoryApplicationRepository {
+    fn save_all(&self, applications: &[JobApplication]) -> Result<(), Box<dyn Error>> {
+        self.applications_signal.borrow_mut().set(applications.to_vec());
+        Ok(())
+    }
+
+    fn load_all(&self) -> Result<Vec<JobApplication>, Box<dyn Error>> {
+        Ok(self.applications_signal.borrow().read().clone())
+    }
+}
+
+// In-memory library repository for Dioxus applications
+pub struct InMemoryLibraryRepository {
+    entries_signal: RefCell<Signal<Vec<LibraryEntry>>>,
+}
+
+impl InMemoryLibraryRepository {
+    pub fn new(entries_signal: Signal<Vec<LibraryEntry>>) -> Self {
+        Self {
+            entries_signal: RefCell::new(entries_signal),
+        }
+    }
+}
+
+impl LibraryRepository for InMemoryLibraryRepository {
+    fn save_all(&self, entries: &[LibraryEntry]) -> Result<(), Box<dyn Error>> {
+        self.entries_signal.borrow_mut().set(entries.to_vec());
+        Ok(())
+    }
+
+    fn load_all(&self) -> Result<Vec<LibraryEntry>, Box<dyn Error>> {
+        Ok(self.entries_signal.borrow().read().clone())
+    }
+}
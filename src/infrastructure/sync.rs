@@ -0,0 +1,483 @@
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::repository::{AsyncResumeRepository, DocumentStore, RepositoryFuture};
+use crate::domain::Resume;
+
+// Sync with a self-hosted server
+//
+// The server keeps one document per resume key with a revision number that
+// grows by one on every accepted write. A client pushes its changes together
+// with the revision they were based on; when someone else wrote in between,
+// the server refuses with the current document and the client has to resolve
+// the conflict before pushing again. The wire format is documented in
+// docs/sync_api.md and served by the `resume-sync-server` binary.
+//
+// Local saves always succeed and never wait for the server. Whether a change
+// still has to be pushed is kept in a small persisted state, which acts as the
+// queue: successive saves collapse into one push of the latest resume, made
+// by the next `sync`.
+
+// A resume as stored on the server
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteResume {
+    pub key: String,
+    pub revision: u64,
+    pub updated_at: String,
+    pub resume: Resume,
+}
+
+// Listing entry and reply to an accepted push
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteSummary {
+    pub key: String,
+    pub revision: u64,
+    pub updated_at: String,
+}
+
+// Body of a push; base_revision is 0 when the resume does not exist on the server yet
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushRequest {
+    pub base_revision: u64,
+    pub resume: Resume,
+}
+
+// Error body returned by the server
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    pub error: String,
+}
+
+pub enum PushOutcome {
+    Accepted { revision: u64 },
+    Conflict(Box<RemoteResume>),
+    // The push was based on a revision but the server has no such resume
+    Missing,
+}
+
+// Transport to the sync server
+pub trait SyncApi {
+    // Fetch the server copy, None if the key has never been pushed
+    fn fetch(&self) -> RepositoryFuture<'_, Option<RemoteResume>>;
+
+    // Push a resume based on the given revision
+    fn push<'a>(&'a self, base_revision: u64, resume: &'a Resume) -> RepositoryFuture<'a, PushOutcome>;
+}
+
+// Address of a resume on the server; the key is typed by the user, so it is
+// percent-encoded to stay one path segment
+pub fn resume_url(settings: &SyncSettings) -> String {
+    let mut key = String::new();
+    for byte in settings.key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => key.push(byte as char),
+            byte => key.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    format!("{}/api/resumes/{}", settings.server_url.trim_end_matches('/'), key)
+}
+
+// Reply to `GET /api/resumes/{key}`, read from its status code and body
+pub fn fetch_outcome(status: u16, body: &str) -> Result<Option<RemoteResume>, Box<dyn Error>> {
+    match status {
+        200 => Ok(Some(serde_json::from_str(body)?)),
+        404 => Ok(None),
+        status => Err(server_error(status, body)),
+    }
+}
+
+// Reply to `PUT /api/resumes/{key}`, read from its status code and body
+pub fn push_outcome(status: u16, body: &str) -> Result<PushOutcome, Box<dyn Error>> {
+    match status {
+        200 => {
+            let summary: RemoteSummary = serde_json::from_str(body)?;
+            Ok(PushOutcome::Accepted { revision: summary.revision })
+        }
+        409 => Ok(PushOutcome::Conflict(Box::new(serde_json::from_str(body)?))),
+        412 => Ok(PushOutcome::Missing),
+        status => Err(server_error(status, body)),
+    }
+}
+
+fn server_error(status: u16, body: &str) -> Box<dyn Error> {
+    match serde_json::from_str::<ApiError>(body) {
+        Ok(error) => format!("Sync server error {}: {}", status, error.error).into(),
+        Err(_) => format!("Sync server error {}", status).into(),
+    }
+}
+
+// Where and as what the resume is synced
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncSettings {
+    pub server_url: String,
+    pub token: String,
+    pub key: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SyncStatus {
+    Disabled,
+    Synced { revision: u64 },
+    Offline,
+    Conflict,
+}
+
+impl SyncStatus {
+    pub fn description(&self) -> String {
+        match self {
+            Self::Disabled => "Sync is off".to_string(),
+            Self::Synced { revision } => format!("Up to date (revision {})", revision),
+            Self::Offline => "Server unreachable; changes will be pushed later".to_string(),
+            Self::Conflict => "The server has changes that conflict with yours".to_string(),
+        }
+    }
+}
+
+// Persisted sync bookkeeping: the last revision both sides agreed on and whether local changes are queued
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct SyncState {
+    settings: Option<SyncSettings>,
+    revision: u64,
+    pending: bool,
+}
+
+// Repository wrapper that keeps a local repository in sync with the server
+pub struct SyncedResumeRepository {
+    local: Rc<dyn AsyncResumeRepository>,
+    state_store: Rc<dyn DocumentStore>,
+    connect: fn(&SyncSettings) -> Rc<dyn SyncApi>,
+    api: RefCell<Option<Rc<dyn SyncApi>>>,
+    conflict: RefCell<Option<RemoteResume>>,
+    status: Cell<SyncStatus>,
+}
+
+impl SyncedResumeRepository {
+    pub fn new(
+        local: Rc<dyn AsyncResumeRepository>,
+        state_store: Rc<dyn DocumentStore>,
+        connect: fn(&SyncSettings) -> Rc<dyn SyncApi>,
+    ) -> Self {
+        Self {
+            local,
+            state_store,
+            connect,
+            api: RefCell::new(None),
+            conflict: RefCell::new(None),
+            status: Cell::new(SyncStatus::Disabled),
+        }
+    }
+
+    // Status after the last operation
+    pub fn status(&self) -> SyncStatus {
+        self.status.get()
+    }
+
+    // Server copy that conflicts with the local resume, if any
+    pub fn conflict(&self) -> Option<RemoteResume> {
+        self.conflict.borrow().clone()
+    }
+
+    pub async fn settings(&self) -> Result<Option<SyncSettings>, Box<dyn Error>> {
+        Ok(self.load_state().await?.settings)
+    }
+
+    // Starts syncing with the given server; a new server or key starts from scratch
+    pub async fn configure(&self, settings: SyncSettings) -> Result<SyncStatus, Box<dyn Error>> {
+        let state = self.load_state().await?;
+        if state.settings.as_ref() != Some(&settings) {
+            let state = SyncState {
+                settings: Some(settings),
+                revision: 0,
+                pending: self.local.exists().await?,
+            };
+            self.save_state(&state).await?;
+        }
+        self.api.replace(None);
+        self.conflict.replace(None);
+        self.sync().await
+    }
+
+    // Stops syncing; the local resume is kept
+    pub async fn disconnect(&self) -> Result<(), Box<dyn Error>> {
+        self.save_state(&SyncState::default()).await?;
+        self.api.replace(None);
+        self.conflict.replace(None);
+        self.status.set(SyncStatus::Disabled);
+        Ok(())
+    }
+
+    // Pushes queued changes or pulls newer ones; unreachable servers leave the queue intact
+    pub async fn sync(&self) -> Result<SyncStatus, Box<dyn Error>> {
+        let status = match self.try_sync().await {
+            Ok(status) => status,
+            Err(err) => {
+                tracing::warn!("Sync failed: {}", err);
+                SyncStatus::Offline
+            }
+        };
+        self.status.set(status);
+        Ok(status)
+    }
+
+    // Stores the resolved resume and pushes it on top of the conflicting server revision
+    pub async fn resolve(&self, resume: &Resume) -> Result<SyncStatus, Box<dyn Error>> {
+        let conflict = self.conflict.take().ok_or("There is no sync conflict to resolve")?;
+        self.local.save(resume).await?;
+        let mut state = self.load_state().await?;
+        state.revision = conflict.revision;
+        state.pending = true;
+        self.save_state(&state).await?;
+        self.sync().await
+    }
+
+    async fn try_sync(&self) -> Result<SyncStatus, Box<dyn Error>> {
+        let mut state = self.load_state().await?;
+        let Some(api) = self.api(&state) else {
+            return Ok(SyncStatus::Disabled);
+        };
+        if self.conflict.borrow().is_some() {
+            return Ok(SyncStatus::Conflict);
+        }
+
+        if !state.pending {
+            match api.fetch().await? {
+                Some(remote) if remote.revision > state.revision => {
+                    self.local.save(&remote.resume).await?;
+                    state.revision = remote.revision;
+                }
+                // The server lost the resume it had; push ours as a new one
+                None if state.revision > 0 => {
+                    state.revision = 0;
+                    state.pending = true;
+                }
+                _ => {}
+            }
+        }
+
+        if state.pending {
+            let resume = self.local.load().await?;
+            let mut outcome = api.push(state.revision, &resume).await?;
+            if matches!(outcome, PushOutcome::Missing) && state.revision > 0 {
+                state.revision = 0;
+                outcome = api.push(0, &resume).await?;
+            }
+            match outcome {
+                PushOutcome::Accepted { revision } => {
+                    state.revision = revision;
+                    state.pending = false;
+                }
+                PushOutcome::Conflict(remote) => {
+                    self.save_state(&state).await?;
+                    self.conflict.replace(Some(*remote));
+                    return Ok(SyncStatus::Conflict);
+                }
+                PushOutcome::Missing => return Err("Sync server refused the resume as a new one".into()),
+            }
+        }
+
+        self.save_state(&state).await?;
+        Ok(SyncStatus::Synced { revision: state.revision })
+    }
+
+    // Client for the configured server, created on first use
+    fn api(&self, state: &SyncState) -> Option<Rc<dyn SyncApi>> {
+        let settings = state.settings.as_ref()?;
+        let api = self.api.borrow_mut().get_or_insert_with(|| (self.connect)(settings)).clone();
+        Some(api)
+    }
+
+    async fn load_state(&self) -> Result<SyncState, Box<dyn Error>> {
+        match self.state_store.load_document().await? {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(SyncState::default()),
+        }
+    }
+
+    async fn save_state(&self, state: &SyncState) -> Result<(), Box<dyn Error>> {
+        self.state_store.save_document(&serde_json::to_string(state)?).await
+    }
+}
+
+impl AsyncResumeRepository for SyncedResumeRepository {
+    // Queues the change; it is pushed by the next `sync`
+    fn save<'a>(&'a self, resume: &'a Resume) -> RepositoryFuture<'a, ()> {
+        Box::pin(async move {
+            self.local.save(resume).await?;
+            let mut state = self.load_state().await?;
+            if state.settings.is_some() && !state.pending {
+                state.pending = true;
+                self.save_state(&state).await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn load(&self) -> RepositoryFuture<'_, Resume> {
+        Box::pin(async move {
+            self.sync().await?;
+            self.local.load().await
+        })
+    }
+
+    fn exists(&self) -> RepositoryFuture<'_, bool> {
+        Box::pin(async move {
+            self.sync().await?;
+            self.local.exists().await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::application::repository::ResumeRepository;
+
+    // The fakes below never wait, so every future is ready when first polled
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match std::pin::pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future was not ready"),
+        }
+    }
+
+    #[derive(Default)]
+    struct Memory {
+        resume: RefCell<Option<Resume>>,
+        document: RefCell<Option<String>>,
+    }
+
+    impl ResumeRepository for Memory {
+        fn save(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
+            self.resume.replace(Some(resume.clone()));
+            Ok(())
+        }
+
+        fn load(&self) -> Result<Resume, Box<dyn Error>> {
+            Ok(self.resume.borrow().clone().unwrap_or_default())
+        }
+
+        fn exists(&self) -> bool {
+            self.resume.borrow().is_some()
+        }
+    }
+
+    impl DocumentStore for Memory {
+        fn save_document<'a>(&'a self, document: &'a str) -> RepositoryFuture<'a, ()> {
+            self.document.replace(Some(document.to_string()));
+            Box::pin(async { Ok(()) })
+        }
+
+        fn load_document(&self) -> RepositoryFuture<'_, Option<String>> {
+            let document = self.document.borrow().clone();
+            Box::pin(async move { Ok(document) })
+        }
+    }
+
+    // Server that follows docs/sync_api.md and counts pushes
+    #[derive(Default)]
+    struct FakeServer {
+        document: RefCell<Option<RemoteResume>>,
+        pushes: Cell<u32>,
+    }
+
+    // `connect` is a plain function, so each test's server is reached through the thread
+    thread_local! {
+        static SERVER: RefCell<Rc<FakeServer>> = RefCell::default();
+    }
+
+    impl SyncApi for FakeServer {
+        fn fetch(&self) -> RepositoryFuture<'_, Option<RemoteResume>> {
+            let document = self.document.borrow().clone();
+            Box::pin(async move { Ok(document) })
+        }
+
+        fn push<'a>(&'a self, base_revision: u64, resume: &'a Resume) -> RepositoryFuture<'a, PushOutcome> {
+            self.pushes.set(self.pushes.get() + 1);
+            let current = self.document.borrow().clone();
+            let outcome = match current {
+                Some(document) if document.revision != base_revision => PushOutcome::Conflict(Box::new(document)),
+                None if base_revision != 0 => PushOutcome::Missing,
+                current => {
+                    let revision = current.map_or(0, |document| document.revision) + 1;
+                    self.document.replace(Some(RemoteResume {
+                        key: "main".to_string(),
+                        revision,
+                        updated_at: String::new(),
+                        resume: resume.clone(),
+                    }));
+                    PushOutcome::Accepted { revision }
+                }
+            };
+            Box::pin(async move { Ok(outcome) })
+        }
+    }
+
+    fn connect(_: &SyncSettings) -> Rc<dyn SyncApi> {
+        server()
+    }
+
+    fn server() -> Rc<FakeServer> {
+        SERVER.with(|server| server.borrow().clone())
+    }
+
+    fn named(name: &str) -> Resume {
+        let mut resume = Resume::default();
+        resume.personal_info.name = name.to_string();
+        resume
+    }
+
+    fn synced() -> (SyncedResumeRepository, Rc<Memory>) {
+        SERVER.with(|server| server.replace(Rc::default()));
+        let local = Rc::new(Memory::default());
+        local.resume.replace(Some(named("Ada")));
+        let repository = SyncedResumeRepository::new(local.clone(), Rc::new(Memory::default()), connect);
+        let settings = SyncSettings { server_url: "http://localhost".to_string(), token: String::new(), key: "main".to_string() };
+        assert_eq!(ready(repository.configure(settings)).unwrap(), SyncStatus::Synced { revision: 1 });
+        (repository, local)
+    }
+
+    #[test]
+    fn saves_are_queued_until_the_next_sync() {
+        let (repository, _) = synced();
+        let pushes = server().pushes.get();
+        ready(repository.save(&named("Ada Lovelace"))).unwrap();
+        ready(repository.save(&named("Ada King"))).unwrap();
+        assert_eq!(server().pushes.get(), pushes);
+
+        assert_eq!(ready(repository.sync()).unwrap(), SyncStatus::Synced { revision: 2 });
+        assert_eq!(server().pushes.get(), pushes + 1);
+        assert!(server().document.borrow().as_ref().unwrap().resume == named("Ada King"));
+    }
+
+    #[test]
+    fn newer_server_copies_are_pulled_and_stale_pushes_conflict() {
+        let (repository, local) = synced();
+        ready(server().push(1, &named("Grace"))).unwrap();
+        assert_eq!(ready(repository.sync()).unwrap(), SyncStatus::Synced { revision: 2 });
+        assert!(ResumeRepository::load(&*local).unwrap() == named("Grace"));
+
+        ready(server().push(2, &named("Grace Hopper"))).unwrap();
+        ready(repository.save(&named("Ada"))).unwrap();
+        assert_eq!(ready(repository.sync()).unwrap(), SyncStatus::Conflict);
+        assert!(repository.conflict().unwrap().resume == named("Grace Hopper"));
+    }
+
+    #[test]
+    fn resumes_missing_on_the_server_are_pushed_again() {
+        let (repository, _) = synced();
+        server().document.replace(None);
+        ready(repository.save(&named("Ada Lovelace"))).unwrap();
+        assert_eq!(ready(repository.sync()).unwrap(), SyncStatus::Synced { revision: 1 });
+        assert!(server().document.borrow().as_ref().unwrap().resume == named("Ada Lovelace"));
+
+        server().document.replace(None);
+        assert_eq!(ready(repository.sync()).unwrap(), SyncStatus::Synced { revision: 1 });
+        assert!(server().document.borrow().is_some());
+    }
+}
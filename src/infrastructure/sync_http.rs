@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::rc::Rc;
+
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Request, RequestInit, Response};
+
+use crate::application::repository::RepositoryFuture;
+use crate::domain::Resume;
+use crate::infrastructure::sync::{fetch_outcome, push_outcome, resume_url, PushOutcome, PushRequest, RemoteResume, SyncApi, SyncSettings};
+
+// Sync client that talks to the server with the browser's fetch API
+pub struct HttpSyncApi {
+    url: String,
+    token: String,
+}
+
+impl HttpSyncApi {
+    pub fn new(settings: &SyncSettings) -> Self {
+        Self {
+            url: resume_url(settings),
+            token: settings.token.clone(),
+        }
+    }
+
+    // Used as the connect function of `SyncedResumeRepository`
+    pub fn connect(settings: &SyncSettings) -> Rc<dyn SyncApi> {
+        Rc::new(Self::new(settings))
+    }
+
+    // Sends a request and returns the status code with the response body
+    async fn send(&self, method: &str, body: Option<String>) -> Result<(u16, String), Box<dyn Error>> {
+        let init = RequestInit::new();
+        init.set_method(method);
+        if let Some(body) = body {
+            init.set_body(&JsValue::from_str(&body));
+        }
+        let request = Request::new_with_str_and_init(&self.url, &init).map_err(|_| "Invalid sync server URL")?;
+        let headers = request.headers();
+        headers.set("Content-Type", "application/json").map_err(|_| "Failed to set request headers")?;
+        if !self.token.is_empty() {
+            headers
+                .set("Authorization", &format!("Bearer {}", self.token))
+                .map_err(|_| "Failed to set request headers")?;
+        }
+
+        let window = web_sys::window().ok_or("Failed to get window")?;
+        let response: Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(|_| "Sync server is unreachable")?
+            .unchecked_into();
+        let text = JsFuture::from(response.text().map_err(|_| "Failed to read sync response")?)
+            .await
+            .map_err(|_| "Failed to read sync response")?
+            .as_string()
+            .unwrap_or_default();
+        Ok((response.status(), text))
+    }
+}
+
+impl SyncApi for HttpSyncApi {
+    fn fetch(&self) -> RepositoryFuture<'_, Option<RemoteResume>> {
+        Box::pin(async move {
+            let (status, body) = self.send("GET", None).await?;
+            fetch_outcome(status, &body)
+        })
+    }
+
+    fn push<'a>(&'a self, base_revision: u64, resume: &'a Resume) -> RepositoryFuture<'a, PushOutcome> {
+        Box::pin(async move {
+            let body = serde_json::to_string(&PushRequest { base_revision, resume: resume.clone() })?;
+            let (status, body) = self.send("PUT", Some(body)).await?;
+            push_outcome(status, &body)
+        })
+    }
+}
@@ -0,0 +1,411 @@
+use std::error::Error;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::infrastructure::sync::{ApiError, PushRequest, RemoteResume, RemoteSummary};
+
+// Sync server
+//
+// Serves the API in docs/sync_api.md for the `resume-sync-server` binary.
+// Resumes are kept as one JSON file per key in the data directory. Requests
+// are handled one at a time, so checking a push's base revision and writing
+// the new revision cannot interleave with another push.
+
+// Request bodies larger than this are refused
+const MAX_BODY_BYTES: u64 = 16 * 1024 * 1024;
+
+pub struct ServerConfig {
+    pub data_dir: PathBuf,
+    // Bearer token every request must carry; None accepts unauthenticated requests
+    pub token: Option<String>,
+}
+
+// Answers requests until the server is shut down
+pub fn run(server: &Server, config: &ServerConfig) {
+    for mut request in server.incoming_requests() {
+        let (status, body) = handle(config, &mut request);
+        let mut response = Response::from_string(body).with_status_code(status);
+        for header in cors_headers() {
+            response.add_header(header);
+        }
+        if let Err(err) = request.respond(response) {
+            eprintln!("Failed to send response: {}", err);
+        }
+    }
+}
+
+// Routes a request and returns the status code with a JSON body
+fn handle(config: &ServerConfig, request: &mut Request) -> (u16, String) {
+    // Browsers ask before cross-origin requests with custom headers
+    if *request.method() == Method::Options {
+        return (204, String::new());
+    }
+    if let Some(token) = &config.token {
+        let expected = format!("Bearer {}", token);
+        let authorized = request.headers().iter()
+            .any(|header| header.field.equiv("Authorization") && header.value.as_str() == expected);
+        if !authorized {
+            return error(401, "Missing or wrong token");
+        }
+    }
+
+    let path = request.url().split('?').next().unwrap_or_default().trim_end_matches('/').to_string();
+    let result = match (request.method().clone(), path.strip_prefix("/api/resumes")) {
+        (Method::Get, Some("")) => list(&config.data_dir),
+        (Method::Get, Some(key)) => match valid_key(key) {
+            Some(key) => fetch(&config.data_dir, key),
+            None => Ok(error(404, "Unknown resume key")),
+        },
+        (Method::Put, Some(key)) => match valid_key(key) {
+            Some(key) => push(&config.data_dir, key, request),
+            None => Ok(error(404, "Unknown resume key")),
+        },
+        (_, Some(_)) => Ok(error(405, "Method not allowed")),
+        _ => Ok(error(404, "Not found")),
+    };
+
+    result.unwrap_or_else(|err| {
+        eprintln!("Request to {} failed: {}", path, err);
+        error(500, "Internal server error")
+    })
+}
+
+fn list(data_dir: &Path) -> Result<(u16, String), Box<dyn Error>> {
+    let mut summaries = Vec::new();
+    for entry in fs::read_dir(data_dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "json") {
+            let document: RemoteResume = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            summaries.push(RemoteSummary {
+                key: document.key,
+                revision: document.revision,
+                updated_at: document.updated_at,
+            });
+        }
+    }
+    summaries.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok((200, serde_json::to_string(&summaries)?))
+}
+
+fn fetch(data_dir: &Path, key: &str) -> Result<(u16, String), Box<dyn Error>> {
+    match load(data_dir, key)? {
+        Some(document) => Ok((200, serde_json::to_string(&document)?)),
+        None => Ok(error(404, "Resume not found")),
+    }
+}
+
+// Accepts the push only when it is based on the current revision
+fn push(data_dir: &Path, key: &str, request: &mut Request) -> Result<(u16, String), Box<dyn Error>> {
+    let mut body = String::new();
+    request.as_reader().take(MAX_BODY_BYTES + 1).read_to_string(&mut body)?;
+    if body.len() as u64 > MAX_BODY_BYTES {
+        return Ok(error(413, "Resume is too large"));
+    }
+    let push: PushRequest = match serde_json::from_str(&body) {
+        Ok(push) => push,
+        Err(err) => return Ok(error(400, &format!("Invalid request body: {}", err))),
+    };
+
+    let current = load(data_dir, key)?;
+    let current_revision = current.as_ref().map_or(0, |document| document.revision);
+    if push.base_revision != current_revision {
+        return match current {
+            Some(document) => Ok((409, serde_json::to_string(&document)?)),
+            None => Ok(error(412, "Resume does not exist on the server; push with base_revision 0")),
+        };
+    }
+
+    let document = RemoteResume {
+        key: key.to_string(),
+        revision: current_revision + 1,
+        updated_at: chrono::Utc::now().to_rfc3339(),
+        resume: push.resume,
+    };
+    // Write to a temporary file first so a crash never leaves half a document
+    let path = document_path(data_dir, key);
+    let temporary = path.with_extension("json.tmp");
+    fs::write(&temporary, serde_json::to_string_pretty(&document)?)?;
+    fs::rename(&temporary, &path)?;
+
+    let summary = RemoteSummary {
+        key: document.key,
+        revision: document.revision,
+        updated_at: document.updated_at,
+    };
+    Ok((200, serde_json::to_string(&summary)?))
+}
+
+fn load(data_dir: &Path, key: &str) -> Result<Option<RemoteResume>, Box<dyn Error>> {
+    match fs::read_to_string(document_path(data_dir, key)) {
+        Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn document_path(data_dir: &Path, key: &str) -> PathBuf {
+    data_dir.join(format!("{}.json", key))
+}
+
+// Keys become file names, so only a safe subset of characters is allowed
+fn valid_key(path: &str) -> Option<&str> {
+    let key = path.strip_prefix('/')?;
+    let valid = !key.is_empty()
+        && key.len() <= 64
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    valid.then_some(key)
+}
+
+fn error(status: u16, message: &str) -> (u16, String) {
+    let body = serde_json::to_string(&ApiError { error: message.to_string() }).unwrap_or_default();
+    (status, body)
+}
+
+fn cors_headers() -> Vec<Header> {
+    [
+        ("Content-Type", "application/json"),
+        ("Access-Control-Allow-Origin", "*"),
+        ("Access-Control-Allow-Methods", "GET, PUT, OPTIONS"),
+        ("Access-Control-Allow-Headers", "Content-Type, Authorization"),
+    ]
+    .iter()
+    .filter_map(|(field, value)| Header::from_bytes(field.as_bytes(), value.as_bytes()).ok())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::future::{self, Future};
+    use std::io::Write;
+    use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::application::repository::{AsyncResumeRepository, RepositoryFuture, ResumeRepository, TextStore};
+    use crate::domain::Resume;
+    use crate::infrastructure::sync::{
+        fetch_outcome, push_outcome, resume_url, PushOutcome, SyncApi, SyncSettings, SyncStatus, SyncedResumeRepository,
+    };
+
+    // Starts a server on a free port with its own data directory
+    fn start(name: &str, token: Option<&str>) -> SocketAddr {
+        let data_dir = data_dir(name);
+        let _ = fs::remove_dir_all(&data_dir);
+        fs::create_dir_all(&data_dir).unwrap();
+        let config = ServerConfig { data_dir, token: token.map(str::to_string) };
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        std::thread::spawn(move || run(&server, &config));
+        addr
+    }
+
+    fn data_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("resume-sync-{}-{}", name, std::process::id()))
+    }
+
+    fn send(addr: impl ToSocketAddrs, method: &str, path: &str, headers: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n{}\r\n{}",
+            method, path, body.len(), headers, body
+        ).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").map_or("", |(_, body)| body).to_string();
+        (status, body)
+    }
+
+    fn push_body(base_revision: u64, name: &str) -> String {
+        let mut resume = Resume::default();
+        resume.personal_info.name = name.to_string();
+        serde_json::to_string(&PushRequest { base_revision, resume }).unwrap()
+    }
+
+    #[test]
+    fn pushed_resumes_can_be_pulled() {
+        let addr = start("push", None);
+        let (status, body) = send(addr, "PUT", "/api/resumes/main", "", &push_body(0, "Ada"));
+        assert_eq!(status, 200);
+        assert_eq!(serde_json::from_str::<RemoteSummary>(&body).unwrap().revision, 1);
+        let (status, _) = send(addr, "PUT", "/api/resumes/main", "", &push_body(1, "Ada Lovelace"));
+        assert_eq!(status, 200);
+
+        let (status, body) = send(addr, "GET", "/api/resumes/main", "", "");
+        assert_eq!(status, 200);
+        let document: RemoteResume = serde_json::from_str(&body).unwrap();
+        assert_eq!((document.revision, document.resume.personal_info.name.as_str()), (2, "Ada Lovelace"));
+
+        let (status, body) = send(addr, "GET", "/api/resumes", "", "");
+        assert_eq!(status, 200);
+        let summaries: Vec<RemoteSummary> = serde_json::from_str(&body).unwrap();
+        assert_eq!(summaries.iter().map(|summary| (summary.key.as_str(), summary.revision)).collect::<Vec<_>>(), [("main", 2)]);
+        assert_eq!(send(addr, "GET", "/api/resumes/other", "", "").0, 404);
+    }
+
+    #[test]
+    fn stale_pushes_get_the_current_document() {
+        let addr = start("conflict", None);
+        send(addr, "PUT", "/api/resumes/main", "", &push_body(0, "Ada"));
+        let (status, body) = send(addr, "PUT", "/api/resumes/main", "", &push_body(0, "Grace"));
+        assert_eq!(status, 409);
+        let document: RemoteResume = serde_json::from_str(&body).unwrap();
+        assert_eq!((document.revision, document.resume.personal_info.name.as_str()), (1, "Ada"));
+    }
+
+    #[test]
+    fn pushes_on_top_of_a_missing_resume_are_refused() {
+        let addr = start("missing", None);
+        let (status, body) = send(addr, "PUT", "/api/resumes/main", "", &push_body(3, "Ada"));
+        assert_eq!(status, 412);
+        assert!(serde_json::from_str::<ApiError>(&body).is_ok());
+        assert_eq!(send(addr, "GET", "/api/resumes/main", "", "").0, 404);
+    }
+
+    #[test]
+    fn requests_need_the_token_and_a_valid_key() {
+        let addr = start("token", Some("secret"));
+        assert_eq!(send(addr, "GET", "/api/resumes", "", "").0, 401);
+        assert_eq!(send(addr, "GET", "/api/resumes", "Authorization: Bearer secret\r\n", "").0, 200);
+        assert_eq!(send(addr, "GET", "/api/resumes/..%2Fsecret", "Authorization: Bearer secret\r\n", "").0, 404);
+    }
+
+    // The client runs its blocking requests inside the future, so it is ready when first polled
+    fn ready<T>(future: impl Future<Output = T>) -> T {
+        match std::pin::pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("future was not ready"),
+        }
+    }
+
+    // Sync client over plain TCP that reads replies with the same functions as the browser client
+    struct TcpSyncApi {
+        addr: String,
+        path: String,
+    }
+
+    impl TcpSyncApi {
+        fn connect(settings: &SyncSettings) -> Rc<dyn SyncApi> {
+            let url = resume_url(settings);
+            let (addr, path) = url.strip_prefix("http://").unwrap().split_once('/').unwrap();
+            Rc::new(Self { addr: addr.to_string(), path: format!("/{}", path) })
+        }
+    }
+
+    impl SyncApi for TcpSyncApi {
+        fn fetch(&self) -> RepositoryFuture<'_, Option<RemoteResume>> {
+            let (status, body) = send(self.addr.as_str(), "GET", &self.path, "", "");
+            Box::pin(future::ready(fetch_outcome(status, &body)))
+        }
+
+        fn push<'a>(&'a self, base_revision: u64, resume: &'a Resume) -> RepositoryFuture<'a, PushOutcome> {
+            let request = serde_json::to_string(&PushRequest { base_revision, resume: resume.clone() }).unwrap();
+            let (status, body) = send(self.addr.as_str(), "PUT", &self.path, "", &request);
+            Box::pin(future::ready(push_outcome(status, &body)))
+        }
+    }
+
+    // Local resume and sync state of one device
+    #[derive(Default)]
+    struct Device {
+        resume: RefCell<Option<Resume>>,
+        state: RefCell<Option<String>>,
+    }
+
+    impl ResumeRepository for Device {
+        fn save(&self, resume: &Resume) -> Result<(), Box<dyn Error>> {
+            self.resume.replace(Some(resume.clone()));
+            Ok(())
+        }
+
+        fn load(&self) -> Result<Resume, Box<dyn Error>> {
+            Ok(self.resume.borrow().clone().unwrap_or_default())
+        }
+
+        fn exists(&self) -> bool {
+            self.resume.borrow().is_some()
+        }
+    }
+
+    impl TextStore for Device {
+        fn save_text(&self, document: &str) -> Result<(), Box<dyn Error>> {
+            self.state.replace(Some(document.to_string()));
+            Ok(())
+        }
+
+        fn load_text(&self) -> Result<Option<String>, Box<dyn Error>> {
+            Ok(self.state.borrow().clone())
+        }
+    }
+
+    fn named(name: &str) -> Resume {
+        let mut resume = Resume::default();
+        resume.personal_info.name = name.to_string();
+        resume
+    }
+
+    // A device holding the named resume, synced with the server under the key
+    fn device(addr: SocketAddr, key: &str, name: &str) -> (SyncedResumeRepository, Rc<Device>) {
+        let local = Rc::new(Device::default());
+        local.resume.replace(Some(named(name)));
+        let repository = SyncedResumeRepository::new(local.clone(), local.clone(), TcpSyncApi::connect);
+        let settings = SyncSettings { server_url: format!("http://{}/", addr), token: String::new(), key: key.to_string() };
+        ready(repository.configure(settings)).unwrap();
+        (repository, local)
+    }
+
+    #[test]
+    fn devices_sync_through_the_server() {
+        let addr = start("devices", None);
+        let (laptop, _) = device(addr, "main", "Ada");
+        assert_eq!(laptop.status(), SyncStatus::Synced { revision: 1 });
+
+        // A second device with its own copy meets the server's in a conflict
+        let (phone, phone_local) = device(addr, "main", "Ada L.");
+        assert_eq!(phone.status(), SyncStatus::Conflict);
+        assert!(phone.conflict().unwrap().resume == named("Ada"));
+        assert_eq!(ready(phone.resolve(&named("Ada Lovelace"))).unwrap(), SyncStatus::Synced { revision: 2 });
+        assert!(ResumeRepository::load(&*phone_local).unwrap() == named("Ada Lovelace"));
+
+        // The first device pulls the resolved resume
+        assert_eq!(ready(laptop.sync()).unwrap(), SyncStatus::Synced { revision: 2 });
+        assert!(ready(AsyncResumeRepository::load(&laptop)).unwrap() == named("Ada Lovelace"));
+    }
+
+    #[test]
+    fn resumes_lost_by_the_server_are_pushed_again() {
+        let addr = start("lost", None);
+        let (laptop, _) = device(addr, "main", "Ada");
+        ready(laptop.save(&named("Ada Lovelace"))).unwrap();
+        fs::remove_file(data_dir("lost").join("main.json")).unwrap();
+
+        // The push on top of revision 1 gets 412 and is repeated as a new resume
+        assert_eq!(ready(laptop.sync()).unwrap(), SyncStatus::Synced { revision: 1 });
+        fs::remove_file(data_dir("lost").join("main.json")).unwrap();
+        // With nothing queued the fetch gets 404 and the resume is pushed again
+        assert_eq!(ready(laptop.sync()).unwrap(), SyncStatus::Synced { revision: 1 });
+        assert!(data_dir("lost").join("main.json").exists());
+    }
+
+    #[test]
+    fn keys_are_encoded_and_server_errors_are_reported() {
+        let addr = start("keys", None);
+        let settings = |key: &str| SyncSettings { server_url: format!("http://{}", addr), token: String::new(), key: key.to_string() };
+        assert!(resume_url(&settings("../secret")).ends_with("/api/resumes/..%2Fsecret"));
+
+        let api = TcpSyncApi::connect(&settings("../secret"));
+        let err = ready(api.push(0, &named("Ada"))).err().unwrap();
+        assert_eq!(err.to_string(), "Sync server error 404: Unknown resume key");
+        assert!(ready(api.fetch()).unwrap().is_none());
+
+        let (device, _) = device(addr, "my resume", "Ada");
+        assert_eq!(device.status(), SyncStatus::Offline);
+        assert_eq!(fs::read_dir(data_dir("keys")).unwrap().count(), 0);
+    }
+}
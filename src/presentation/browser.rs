@@ -0,0 +1,227 @@
+use dioxus::prelude::*;
+
+// Browser helpers for actions that have no Rust-side API in Dioxus
+
+// Offers text content to the user as a file download
+pub fn download_text(file_name: &str, mime_type: &str, contents: &str) {
+    download_bytes(file_name, mime_type, contents.as_bytes());
+}
+
+// Offers binary content to the user as a file download
+pub fn download_bytes(file_name: &str, mime_type: &str, contents: &[u8]) {
+    let eval = document::eval(
+        r#"
+        const [fileName, mimeType, contents] = await dioxus.recv();
+        const blob = new Blob([new Uint8Array(contents)], { type: mimeType });
+        const link = document.createElement("a");
+        link.href = URL.createObjectURL(blob);
+        link.download = fileName;
+        link.click();
+        URL.revokeObjectURL(link.href);
+        "#,
+    );
+    if let Err(err) = eval.send((file_name, mime_type, contents)) {
+        tracing::error!("Failed to start download: {:?}", err);
+    }
+}
+
+// Copies text to the system clipboard
+pub fn copy_to_clipboard(text: &str) {
+    let eval = document::eval(
+        r#"
+        const text = await dioxus.recv();
+        await navigator.clipboard.writeText(text);
+        "#,
+    );
+    if let Err(err) = eval.send(text) {
+        tracing::error!("Failed to copy to clipboard: {:?}", err);
+    }
+}
+
+// Scrolls an element into view and focuses its first form field
+pub fn focus_first_field(id: &str) {
+    let eval = document::eval(
+        r#"
+        const id = await dioxus.recv();
+        // Let the form fill in the picked item before focusing it
+        await new Promise((resolve) => setTimeout(resolve, 50));
+        const element = document.getElementById(id);
+        if (element) {
+            element.scrollIntoView({ behavior: "smooth", block: "start" });
+            const field = element.querySelector("input, textarea, select");
+            if (field) {
+                field.focus({ preventScroll: true });
+            }
+        }
+        "#,
+    );
+    if let Err(err) = eval.send(id) {
+        tracing::error!("Failed to focus element: {:?}", err);
+    }
+}
+
+// Scrolls the element's scrollable container so the element is visible, without moving the page
+pub fn scroll_within_container(id: &str) {
+    let eval = document::eval(
+        r#"
+        const id = await dioxus.recv();
+        const element = document.getElementById(id);
+        const container = element && element.closest("[data-scroll-container]");
+        if (container) {
+            const top = element.getBoundingClientRect().top - container.getBoundingClientRect().top;
+            if (top < 0 || top > container.clientHeight - element.offsetHeight) {
+                container.scrollBy({ top: top - 16, behavior: "smooth" });
+            }
+        }
+        "#,
+    );
+    if let Err(err) = eval.send(id) {
+        tracing::error!("Failed to scroll to element: {:?}", err);
+    }
+}
+
+// Measures the children of an element in CSS pixels, including the margins between them
+pub async fn measure_children(id: &str) -> Vec<f64> {
+    let mut eval = document::eval(
+        r#"
+        const id = await dioxus.recv();
+        // Wait until the latest render has been laid out
+        await new Promise((resolve) => requestAnimationFrame(() => setTimeout(resolve, 0)));
+        const container = document.getElementById(id);
+        if (!container) {
+            dioxus.send([]);
+            return;
+        }
+        const base = container.getBoundingClientRect().top;
+        const tops = Array.from(container.children, (child) => child.getBoundingClientRect().top - base);
+        tops[0] = 0;
+        tops.push(container.scrollHeight);
+        dioxus.send(tops.slice(1).map((bottom, index) => bottom - tops[index]));
+        "#,
+    );
+    if let Err(err) = eval.send(id) {
+        tracing::error!("Failed to measure element: {:?}", err);
+        return Vec::new();
+    }
+    eval.recv::<Vec<f64>>().await.unwrap_or_default()
+}
+
+// Resolves after the given number of milliseconds
+pub async fn wait(milliseconds: u32) {
+    let mut eval = document::eval(
+        r#"
+        const milliseconds = await dioxus.recv();
+        await new Promise((resolve) => setTimeout(resolve, milliseconds));
+        dioxus.send(true);
+        "#,
+    );
+    if let Err(err) = eval.send(milliseconds) {
+        tracing::error!("Failed to start timer: {:?}", err);
+        return;
+    }
+    let _ = eval.recv::<bool>().await;
+}
+
+// Zooms out the element's content whenever the element gets narrower than the given width
+pub fn fit_to_width(id: &str, width_px: f64) {
+    let eval = document::eval(
+        r#"
+        const [id, width] = await dioxus.recv();
+        const element = document.getElementById(id);
+        if (element) {
+            const content = element.firstElementChild;
+            const fit = () => {
+                if (content) {
+                    content.style.zoom = Math.min(1, element.clientWidth / width);
+                }
+            };
+            if (element.fitObserver) {
+                element.fitObserver.disconnect();
+            }
+            element.fitObserver = new ResizeObserver(fit);
+            element.fitObserver.observe(element);
+            fit();
+        }
+        "#,
+    );
+    if let Err(err) = eval.send((id, width_px)) {
+        tracing::error!("Failed to fit element: {:?}", err);
+    }
+}
+
+// Opens the browser's print dialog, where the resume can also be saved as PDF
+pub fn print_document() {
+    // Give the page a moment to close dialogs before it is printed
+    document::eval("setTimeout(() => window.print(), 100);");
+}
+
+// Decodes an uploaded image and keeps it as an object URL for cropping;
+// `None` when the browser cannot read the file as an image
+pub async fn open_image(contents: &[u8]) -> Option<String> {
+    let mut eval = document::eval(
+        r#"
+        const contents = await dioxus.recv();
+        const blob = new Blob([new Uint8Array(contents)]);
+        try {
+            const bitmap = await createImageBitmap(blob);
+            bitmap.close();
+            dioxus.send(URL.createObjectURL(blob));
+        } catch (err) {
+            dioxus.send(null);
+        }
+        "#,
+    );
+    if let Err(err) = eval.send(contents) {
+        tracing::error!("Failed to open image: {:?}", err);
+        return None;
+    }
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
+
+// Releases an image opened with `open_image`
+pub fn close_image(url: &str) {
+    let eval = document::eval(
+        r#"
+        URL.revokeObjectURL(await dioxus.recv());
+        "#,
+    );
+    if let Err(err) = eval.send(url) {
+        tracing::error!("Failed to close image: {:?}", err);
+    }
+}
+
+// Crops a square out of an opened image and scales it down to a JPEG of the
+// given size. `zoom` of 1 takes the largest square that fits; the offsets move
+// the square from one edge (-1) to the other (1).
+pub async fn crop_image(url: &str, zoom: f64, offset_x: f64, offset_y: f64, size_px: u32) -> Option<Vec<u8>> {
+    let mut eval = document::eval(
+        r##"
+        const [url, zoom, offsetX, offsetY, size] = await dioxus.recv();
+        try {
+            const bitmap = await createImageBitmap(await (await fetch(url)).blob());
+            const side = Math.min(bitmap.width, bitmap.height) / Math.max(zoom, 1);
+            const left = (bitmap.width - side) * (offsetX + 1) / 2;
+            const top = (bitmap.height - side) * (offsetY + 1) / 2;
+            const canvas = document.createElement("canvas");
+            canvas.width = size;
+            canvas.height = size;
+            const context = canvas.getContext("2d");
+            // Transparent areas would turn black in a JPEG
+            context.fillStyle = "#fff";
+            context.fillRect(0, 0, size, size);
+            context.imageSmoothingQuality = "high";
+            context.drawImage(bitmap, left, top, side, side, 0, 0, size, size);
+            bitmap.close();
+            const jpeg = await new Promise((resolve) => canvas.toBlob(resolve, "image/jpeg", 0.85));
+            dioxus.send(Array.from(new Uint8Array(await jpeg.arrayBuffer())));
+        } catch (err) {
+            dioxus.send(null);
+        }
+        "##,
+    );
+    if let Err(err) = eval.send((url, zoom, offset_x, offset_y, size_px)) {
+        tracing::error!("Failed to crop image: {:?}", err);
+        return None;
+    }
+    eval.recv::<Option<Vec<u8>>>().await.ok().flatten()
+}
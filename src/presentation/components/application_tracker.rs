@@ -0,0 +1,369 @@
+use dioxus::prelude::*;
+use chrono::NaiveDate;
+use crate::domain::models::Resume;
+use crate::domain::job_application::{ApplicationStatus, JobApplication};
+
+/// Component for tracking job applications as a list or a status board
+#[component]
+pub fn ApplicationTracker(
+    applications: Vec<JobApplication>,
+    // Resume opened last, offered as the snapshot to attach
+    current_resume: Option<Resume>,
+    on_add: EventHandler<JobApplication>,
+    on_update: EventHandler<JobApplication>,
+    on_status_change: EventHandler<(u64, ApplicationStatus)>,
+    on_remove: EventHandler<u64>,
+    on_export_csv: EventHandler<()>,
+) -> Element {
+    // State for form inputs
+    let mut company = use_signal(String::new);
+    let mut role = use_signal(String::new);
+    let mut posting_url = use_signal(String::new);
+    let mut posting_text = use_signal(String::new);
+    let mut status = use_signal(ApplicationStatus::default);
+    let mut applied_date = use_signal(String::new);
+    let mut notes = use_signal(String::new);
+    let mut resume_label = use_signal(String::new);
+    let mut cover_letter = use_signal(String::new);
+    let mut attach_snapshot = use_signal(|| true);
+    let mut editing_id = use_signal(|| Option::<u64>::None);
+    let mut show_board = use_signal(|| true);
+
+    let applications_for_edit = applications.clone();
+    let has_resume = current_resume.is_some();
+
+    let mut reset_form = move || {
+        company.set(String::new());
+        role.set(String::new());
+        posting_url.set(String::new());
+        posting_text.set(String::new());
+        status.set(ApplicationStatus::default());
+        applied_date.set(String::new());
+        notes.set(String::new());
+        resume_label.set(String::new());
+        cover_letter.set(String::new());
+        attach_snapshot.set(true);
+        editing_id.set(None);
+    };
+
+    // Function to handle form submission
+    let handle_submit = move |_| {
+        // Validation: company should not be empty
+        if company.read().trim().is_empty() {
+            return;
+        }
+
+        let existing = editing_id().and_then(|id| applications_for_edit.iter().find(|app| app.id == id).cloned());
+        let resume_snapshot = if attach_snapshot() && current_resume.is_some() {
+            current_resume.clone()
+        } else {
+            existing.as_ref().and_then(|app| app.resume_snapshot.clone())
+        };
+
+        let application = JobApplication {
+            id: existing.as_ref().map(|app| app.id).unwrap_or_default(),
+            company: company(),
+            role: role(),
+            posting_url: posting_url(),
+            posting_text: posting_text(),
+            status: status(),
+            applied_date: applied_date.read().parse::<NaiveDate>().ok(),
+            last_update: Some(chrono::Local::now().date_naive()),
+            notes: notes(),
+            resume_label: resume_label(),
+            resume_snapshot,
+            cover_letter: cover_letter(),
+        };
+
+        if existing.is_some() {
+            on_update.call(application);
+        } else {
+            on_add.call(application);
+        }
+        reset_form();
+    };
+
+    // Function to load an application into the form for editing
+    let applications_for_load = applications.clone();
+    let handle_edit = EventHandler::new(move |id: u64| {
+        if let Some(app) = applications_for_load.iter().find(|app| app.id == id) {
+            company.set(app.company.clone());
+            role.set(app.role.clone());
+            posting_url.set(app.posting_url.clone());
+            posting_text.set(app.posting_text.clone());
+            status.set(app.status);
+            applied_date.set(app.applied_date.map(|date| date.to_string()).unwrap_or_default());
+            notes.set(app.notes.clone());
+            resume_label.set(app.resume_label.clone());
+            cover_letter.set(app.cover_letter.clone());
+            attach_snapshot.set(false);
+            editing_id.set(Some(app.id));
+        }
+    });
+
+    let statuses = ApplicationStatus::all();
+
+    rsx! {
+        div { class: "space-y-6",
+            div { class: "flex justify-between items-center",
+                h2 { class: "text-xl font-bold", "Job Applications" }
+                div { class: "flex items-center gap-2",
+                    div { class: "flex items-center bg-gray-200 rounded-full p-1",
+                        button {
+                            class: format!("px-4 py-1 rounded-full transition-colors {}",
+                                if show_board() { "bg-white text-blue-800 shadow" } else { "text-gray-700" }
+                            ),
+                            onclick: move |_| show_board.set(true),
+                            "Board"
+                        }
+                        button {
+                            class: format!("px-4 py-1 rounded-full transition-colors {}",
+                                if !show_board() { "bg-white text-blue-800 shadow" } else { "text-gray-700" }
+                            ),
+                            onclick: move |_| show_board.set(false),
+                            "List"
+                        }
+                    }
+                    button {
+                        class: "px-4 py-2 bg-green-500 text-white rounded hover:bg-green-600 transition-colors duration-300",
+                        onclick: move |_| on_export_csv.call(()),
+                        "Export CSV"
+                    }
+                }
+            }
+
+            // Application form
+            div { class: "p-4 bg-white rounded-lg shadow-md space-y-4",
+                h3 { class: "text-lg font-semibold",
+                    if editing_id().is_some() { "Edit Application" } else { "Add Application" }
+                }
+                div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Company" }
+                        input {
+                            class: "p-2 border rounded-md",
+                            value: "{company}",
+                            oninput: move |evt| company.set(evt.value())
+                        }
+                    }
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Role" }
+                        input {
+                            class: "p-2 border rounded-md",
+                            value: "{role}",
+                            oninput: move |evt| role.set(evt.value())
+                        }
+                    }
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Status" }
+                        select {
+                            class: "p-2 border rounded-md",
+                            value: "{status().name()}",
+                            onchange: move |evt| {
+                                if let Some(selected) = ApplicationStatus::from_name(&evt.value()) {
+                                    status.set(selected);
+                                }
+                            },
+                            for option in statuses.iter() {
+                                option { value: "{option.name()}", "{option.name()}" }
+                            }
+                        }
+                    }
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Applied On" }
+                        input {
+                            r#type: "date",
+                            class: "p-2 border rounded-md",
+                            value: "{applied_date}",
+                            oninput: move |evt| applied_date.set(evt.value())
+                        }
+                    }
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Posting URL" }
+                        input {
+                            class: "p-2 border rounded-md",
+                            placeholder: "https://jobs.example.com/123",
+                            value: "{posting_url}",
+                            oninput: move |evt| posting_url.set(evt.value())
+                        }
+                    }
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Resume Version" }
+                        input {
+                            class: "p-2 border rounded-md",
+                            placeholder: "e.g. Backend - one page",
+                            value: "{resume_label}",
+                            oninput: move |evt| resume_label.set(evt.value())
+                        }
+                    }
+                }
+                div { class: "flex flex-col space-y-1",
+                    label { class: "text-sm font-medium text-gray-700", "Posting Text" }
+                    textarea {
+                        class: "p-2 border rounded-md h-24",
+                        value: "{posting_text}",
+                        oninput: move |evt| posting_text.set(evt.value())
+                    }
+                }
+                div { class: "flex flex-col space-y-1",
+                    label { class: "text-sm font-medium text-gray-700", "Cover Letter" }
+                    textarea {
+                        class: "p-2 border rounded-md h-24",
+                        value: "{cover_letter}",
+                        oninput: move |evt| cover_letter.set(evt.value())
+                    }
+                }
+                div { class: "flex flex-col space-y-1",
+                    label { class: "text-sm font-medium text-gray-700", "Notes" }
+                    textarea {
+                        class: "p-2 border rounded-md",
+                        value: "{notes}",
+                        oninput: move |evt| notes.set(evt.value())
+                    }
+                }
+                if has_resume {
+                    label { class: "flex items-center text-sm font-medium text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            class: "mr-2",
+                            checked: attach_snapshot(),
+                            onchange: move |evt| attach_snapshot.set(evt.checked())
+                        }
+                        if editing_id().is_some() {
+                            "Replace the attached resume with the last opened resume"
+                        } else {
+                            "Attach a snapshot of the last opened resume"
+                        }
+                    }
+                } else {
+                    p { class: "text-sm text-gray-600", "Open a resume first to attach a snapshot of it." }
+                }
+                div { class: "flex space-x-2",
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700",
+                        onclick: handle_submit,
+                        if editing_id().is_some() { "Update Application" } else { "Add Application" }
+                    }
+                    if editing_id().is_some() {
+                        button {
+                            class: "px-4 py-2 bg-gray-300 text-gray-700 rounded-md hover:bg-gray-400",
+                            onclick: move |_| reset_form(),
+                            "Cancel"
+                        }
+                    }
+                }
+            }
+
+            if applications.is_empty() {
+                div { class: "p-4 border rounded bg-gray-50 text-gray-500 italic",
+                    "No applications tracked yet. Add your first one using the form."
+                }
+            } else if show_board() {
+                // Board view - one column per status
+                div { class: "grid grid-cols-1 md:grid-cols-4 gap-4",
+                    for (column, column_status) in statuses.iter().copied().enumerate() {
+                        div { class: "bg-gray-50 rounded-lg p-3 space-y-3",
+                            key: "{column_status.name()}",
+                            h3 { class: "font-semibold text-gray-700",
+                                "{column_status.name()} ({applications.iter().filter(|app| app.status == column_status).count()})"
+                            }
+                            for app in applications.iter().filter(|app| app.status == column_status) {
+                                div { class: "p-3 bg-white rounded shadow-sm",
+                                    key: "{app.id}",
+                                    div { class: "font-medium", "{app.company}" }
+                                    div { class: "text-sm text-gray-600", "{app.role}" }
+                                    if let Some(date) = app.applied_date {
+                                        div { class: "text-xs text-gray-500", "Applied {date}" }
+                                    }
+                                    div { class: "mt-2 flex justify-between text-sm",
+                                        if column > 0 {
+                                            button {
+                                                class: "p-1 bg-gray-200 rounded hover:bg-gray-300",
+                                                onclick: {
+                                                    let id = app.id;
+                                                    let previous = statuses[column - 1];
+                                                    move |_| on_status_change.call((id, previous))
+                                                },
+                                                "←"
+                                            }
+                                        }
+                                        button {
+                                            class: "p-1 text-blue-600 hover:text-blue-800",
+                                            onclick: {
+                                                let id = app.id;
+                                                move |_| handle_edit.call(id)
+                                            },
+                                            "Edit"
+                                        }
+                                        if column + 1 < statuses.len() {
+                                            button {
+                                                class: "p-1 bg-gray-200 rounded hover:bg-gray-300",
+                                                onclick: {
+                                                    let id = app.id;
+                                                    let next = statuses[column + 1];
+                                                    move |_| on_status_change.call((id, next))
+                                                },
+                                                "→"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                // List view
+                table { class: "w-full bg-white rounded-lg shadow-md text-sm",
+                    thead {
+                        tr { class: "text-left border-b",
+                            th { class: "p-2", "Company" }
+                            th { class: "p-2", "Role" }
+                            th { class: "p-2", "Status" }
+                            th { class: "p-2", "Applied" }
+                            th { class: "p-2", "Resume" }
+                            th { class: "p-2", "" }
+                        }
+                    }
+                    tbody {
+                        for app in applications.iter() {
+                            tr { class: "border-b",
+                                key: "{app.id}",
+                                td { class: "p-2 font-medium", "{app.company}" }
+                                td { class: "p-2", "{app.role}" }
+                                td { class: "p-2", "{app.status.name()}" }
+                                td { class: "p-2",
+                                    if let Some(date) = app.applied_date { "{date}" }
+                                }
+                                td { class: "p-2",
+                                    "{app.resume_label}"
+                                    if app.resume_snapshot.is_some() {
+                                        span { class: "ml-1 text-xs text-green-700", "(snapshot)" }
+                                    }
+                                }
+                                td { class: "p-2 flex space-x-2",
+                                    button {
+                                        class: "p-1 text-blue-600 hover:text-blue-800",
+                                        onclick: {
+                                            let id = app.id;
+                                            move |_| handle_edit.call(id)
+                                        },
+                                        "Edit"
+                                    }
+                                    button {
+                                        class: "p-1 text-red-600 hover:text-red-800",
+                                        onclick: {
+                                            let id = app.id;
+                                            move |_| on_remove.call(id)
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use dioxus::prelude::*;
+use crate::domain::Workspace;
+use crate::infrastructure::backup::{decrypt_backup, import_backup, is_encrypted_backup, BackupManifest};
+
+/// Dialog for downloading a workspace backup, optionally encrypted, and restoring one after it has been verified
+#[component]
+pub fn BackupModal(
+    show: bool,
+    on_close: EventHandler<()>,
+    on_download: EventHandler<Option<String>>,
+    on_merge: EventHandler<Workspace>,
+    on_replace: EventHandler<Workspace>
+) -> Element {
+    let mut restored = use_signal(|| Option::<(BackupManifest, Workspace)>::None);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut backup_passphrase = use_signal(String::new);
+    // Encrypted archive waiting for its passphrase, with its file name
+    let mut encrypted = use_signal(|| Option::<(String, Vec<u8>)>::None);
+    let mut restore_passphrase = use_signal(String::new);
+
+    if !show {
+        return rsx!{};
+    }
+
+    // Reads and verifies the selected archive; nothing is applied until the user confirms
+    let handle_file = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else {
+            return;
+        };
+        let Some(file_name) = engine.files().into_iter().next() else {
+            return;
+        };
+        encrypted.set(None);
+        match engine.read_file(&file_name).await {
+            Some(bytes) if is_encrypted_backup(&bytes) => {
+                restored.set(None);
+                error.set(None);
+                encrypted.set(Some((file_name, bytes)));
+            }
+            Some(bytes) => match import_backup(&bytes) {
+                Ok(backup) => {
+                    restored.set(Some(backup));
+                    error.set(None);
+                }
+                Err(err) => {
+                    restored.set(None);
+                    error.set(Some(format!("Could not restore {}: {}", file_name, err)));
+                }
+            },
+            None => error.set(Some(format!("Could not read {}", file_name))),
+        }
+    };
+
+    let open_encrypted = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some((file_name, bytes)) = encrypted() else {
+            return;
+        };
+        match decrypt_backup(&bytes, &restore_passphrase()).and_then(|archive| import_backup(&archive)) {
+            Ok(backup) => {
+                restored.set(Some(backup));
+                encrypted.set(None);
+                error.set(None);
+            }
+            Err(err) => error.set(Some(format!("Could not restore {}: {}", file_name, err))),
+        }
+        restore_passphrase.set(String::new());
+    };
+
+    // Passphrases are not kept around once the dialog is closed
+    let mut close = move || {
+        restored.set(None);
+        encrypted.set(None);
+        error.set(None);
+        backup_passphrase.set(String::new());
+        restore_passphrase.set(String::new());
+        on_close.call(());
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            div {
+                class: "bg-white rounded-lg p-6 w-[32rem] max-h-[90vh] overflow-y-auto shadow-xl",
+                h3 {
+                    class: "text-xl font-bold mb-4",
+                    "Backup & Restore"
+                },
+
+                p {
+                    class: "mb-2 text-sm text-gray-600",
+                    "Download everything, meaning every resume in your library with its theme and all tracked applications with their resume snapshots and cover letters, as one archive."
+                },
+                input {
+                    r#type: "password",
+                    class: "w-full p-2 border rounded mb-2",
+                    placeholder: "Passphrase to encrypt the backup (optional)",
+                    value: "{backup_passphrase}",
+                    oninput: move |evt| backup_passphrase.set(evt.value())
+                },
+                if backup_passphrase().is_empty() {
+                    p {
+                        class: "mb-2 text-sm text-amber-700",
+                        "Without a passphrase the backup is not encrypted: anyone with the file can read your resumes and applications, even if they are encrypted on this device."
+                    }
+                }
+                button {
+                    class: "mb-6 px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                    onclick: move |_| {
+                        let passphrase = backup_passphrase();
+                        on_download.call(Some(passphrase).filter(|passphrase| !passphrase.is_empty()));
+                        backup_passphrase.set(String::new());
+                    },
+                    "Download Backup"
+                },
+
+                p {
+                    class: "mb-2 text-sm text-gray-600",
+                    "Restore a backup made on this or another browser. The archive is checked before anything is changed."
+                },
+                input {
+                    r#type: "file",
+                    accept: ".zip,.enc",
+                    class: "w-full mb-4",
+                    onchange: handle_file
+                },
+
+                if let Some((file_name, _)) = encrypted() {
+                    form {
+                        class: "mb-4 flex gap-2",
+                        onsubmit: open_encrypted,
+                        input {
+                            r#type: "password",
+                            class: "flex-1 p-2 border rounded",
+                            placeholder: "Passphrase of {file_name}",
+                            value: "{restore_passphrase}",
+                            oninput: move |evt| restore_passphrase.set(evt.value())
+                        },
+                        button {
+                            r#type: "submit",
+                            class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                            disabled: restore_passphrase().is_empty(),
+                            "Open"
+                        }
+                    }
+                }
+
+                if let Some(message) = error() {
+                    p {
+                        class: "mb-4 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+
+                if let Some((manifest, workspace)) = restored() {
+                    div {
+                        class: "mb-4 p-3 border rounded bg-gray-50 text-sm space-y-1",
+                        p {
+                            class: "font-bold",
+                            "{workspace.library.len()} resumes"
+                        },
+                        ul {
+                            class: "list-disc ml-5",
+                            for entry in workspace.library.iter() {
+                                li { key: "{entry.id}", "{entry.title}" }
+                            }
+                        },
+                        p { "Created {manifest.created_at} with version {manifest.app_version}" },
+                        p { "{workspace.applications.len()} tracked applications" },
+                        p {
+                            class: "text-gray-600",
+                            "Merge adds the resumes you do not have yet and the applications you do not track yet. Replace Everything makes the library and applications exactly those of the backup."
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex justify-between",
+                    button {
+                        class: "px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| close(),
+                        "Close"
+                    },
+                    if let Some((_, workspace)) = restored() {
+                        div {
+                            class: "flex gap-2",
+                            button {
+                                class: "px-4 py-2 bg-green-500 text-white rounded hover:bg-green-600 transition-colors",
+                                onclick: {
+                                    let workspace = workspace.clone();
+                                    move |_| {
+                                        on_merge.call(workspace.clone());
+                                        close();
+                                    }
+                                },
+                                "Merge"
+                            },
+                            button {
+                                class: "px-4 py-2 bg-red-500 text-white rounded hover:bg-red-600 transition-colors",
+                                onclick: move |_| {
+                                    on_replace.call(workspace.clone());
+                                    close();
+                                },
+                                "Replace Everything"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use dioxus::prelude::*;
+use crate::application::dates::{duration_text, month_text};
+use crate::application::timeline::analyze_timeline;
+use crate::domain::models::{Resume, Section};
+use super::resume_preview::PreviewTarget;
+
+/// Experience and education laid out on a shared time axis, with total
+/// experience, time per employer, and the gaps and overlaps between positions
+#[component]
+pub fn CareerTimeline(
+    resume: Resume,
+    gap_months: u32,
+    on_gap_change: EventHandler<u32>,
+    on_select: EventHandler<PreviewTarget>,
+) -> Element {
+    let timeline = analyze_timeline(&resume, chrono::Local::now().date_naive(), gap_months);
+    let Some(start) = timeline.entries.iter().map(|entry| entry.first).min() else {
+        return rsx! {
+            p { class: "mt-4 text-sm text-gray-500 italic", "Add start dates to your entries to see them on a timeline." }
+        };
+    };
+    let end = timeline.entries.iter().map(|entry| entry.last).max().unwrap_or(start).max(timeline.today);
+    // Position and width of a month range as percentages of the axis
+    let span = (end - start + 1) as f64;
+    let place = move |first: i32, last: i32| {
+        let left = (first - start) as f64 / span * 100.0;
+        let width = (last - first + 1) as f64 / span * 100.0;
+        format!("left: {:.2}%; width: {:.2}%;", left, width)
+    };
+    let first_year = start / 12;
+    let last_year = end / 12;
+    let year_step = ((last_year - first_year) / 6).max(1);
+    let years: Vec<(i32, String)> = (first_year..=last_year)
+        .step_by(year_step as usize)
+        .map(|year| (year, place(start.max(year * 12), start.max(year * 12))))
+        .collect();
+    let overlapping: Vec<usize> = timeline.overlaps.iter().flat_map(|overlap| [overlap.first, overlap.second]).collect();
+
+    rsx! {
+        details {
+            class: "mt-4 p-4 bg-white rounded-lg shadow-md",
+            open: true,
+            summary {
+                class: "cursor-pointer text-xl font-semibold text-gray-800",
+                "Career Timeline"
+            },
+
+            div {
+                class: "mt-4 space-y-4",
+                div {
+                    class: "flex flex-wrap items-center justify-between gap-2 text-sm",
+                    span {
+                        class: "text-gray-700",
+                        "Total experience: "
+                        strong { if timeline.total_months == 0 { "none yet" } else { "{duration_text(timeline.total_months)}" } }
+                    }
+                    label {
+                        class: "flex items-center gap-2 text-gray-600",
+                        "Report gaps longer than"
+                        input {
+                            class: "w-16 px-2 py-1 border rounded-md",
+                            r#type: "number",
+                            min: "0",
+                            max: "60",
+                            value: "{gap_months}",
+                            oninput: move |evt| {
+                                if let Ok(months) = evt.value().parse::<u32>() {
+                                    on_gap_change.call(months.min(60));
+                                }
+                            }
+                        }
+                        "months"
+                    }
+                }
+
+                // One row per entry, with gaps shaded behind them
+                div {
+                    class: "relative border-l border-r border-gray-200",
+                    for gap in timeline.gaps.iter() {
+                        div {
+                            key: "gap-{gap.first}",
+                            class: "absolute top-0 bottom-0 bg-amber-100",
+                            style: "{place(gap.first, gap.last)}",
+                            title: "No work or study: {month_text(gap.first)} to {month_text(gap.last)}",
+                        }
+                    }
+                    for entry in timeline.entries.iter().cloned() {
+                        div {
+                            key: "{entry.section:?}-{entry.index}",
+                            class: "relative h-7 my-1",
+                            button {
+                                class: "absolute h-full px-2 rounded text-xs text-left truncate text-white {bar_class(entry.section, overlapping.contains(&entry.index))}",
+                                style: "{place(entry.first, entry.last)}",
+                                title: "{entry.title}, {entry.organization}: {month_text(entry.first)} to {month_text(entry.last)} ({duration_text(entry.months())})",
+                                onclick: move |_| on_select.call(PreviewTarget::item(entry.section, entry.index)),
+                                if entry.organization.is_empty() { "{entry.title}" } else { "{entry.organization}" }
+                            }
+                        }
+                    }
+                }
+                div {
+                    class: "relative h-4 text-xs text-gray-500",
+                    for (year, style) in years {
+                        span { key: "{year}", class: "absolute", style: "{style}", "{year}" }
+                    }
+                }
+
+                div {
+                    class: "flex flex-wrap gap-4 text-xs text-gray-600",
+                    span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-blue-600" } "Work" }
+                    span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-emerald-500" } "Education" }
+                    span { span { class: "inline-block w-3 h-3 mr-1 rounded bg-amber-100 border" } "Gap" }
+                    span { span { class: "inline-block w-3 h-3 mr-1 rounded ring-2 ring-red-400" } "Overlap" }
+                }
+
+                if !timeline.tenures.is_empty() {
+                    div {
+                        h3 { class: "font-medium text-gray-800 mb-2", "Time per employer" }
+                        table {
+                            class: "w-full text-sm",
+                            tbody {
+                                for tenure in timeline.tenures.iter() {
+                                    tr {
+                                        key: "{tenure.company}",
+                                        class: "border-t",
+                                        td { class: "py-1", if tenure.company.is_empty() { "Unnamed employer" } else { "{tenure.company}" } }
+                                        td {
+                                            class: "py-1 text-gray-500",
+                                            "{month_text(tenure.first)} – "
+                                            if tenure.current { "present" } else { "{month_text(tenure.last)}" }
+                                        }
+                                        td { class: "py-1 text-right", "{duration_text(tenure.months)}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !timeline.undated.is_empty() {
+                    p {
+                        class: "text-sm text-amber-700",
+                        if timeline.undated.len() == 1 {
+                            "1 entry is left out because its dates cannot be read."
+                        } else {
+                            "{timeline.undated.len()} entries are left out because their dates cannot be read."
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Work and education in different colours, with overlapping positions outlined
+fn bar_class(section: Section, overlapping: bool) -> &'static str {
+    match (section, overlapping) {
+        (Section::Education, _) => "bg-emerald-500 hover:bg-emerald-600",
+        (_, true) => "bg-blue-600 hover:bg-blue-700 ring-2 ring-red-400",
+        (_, false) => "bg-blue-600 hover:bg-blue-700",
+    }
+}
@@ -2,6 +2,7 @@ use dioxus::prelude::*;
 
 #[component]
 pub fn DraggableSection(
+    anchor: String,
     index: usize,
     total_sections: usize,
     on_move_up: Option<EventHandler<()>>,
@@ -10,6 +11,7 @@ pub fn DraggableSection(
 ) -> Element {
     rsx! {
         div {
+            id: anchor,
             class: "border rounded bg-white shadow-sm mb-4 relative",
             
             // Drag handle
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use crate::domain::models::Education;
+use crate::application::dates::date_error;
+use crate::domain::models::{EntryOrder, Education};
+use super::entry_order_select::EntryOrderSelect;
 
 #[component]
 pub fn EducationForm(
@@ -7,9 +9,14 @@ pub fn EducationForm(
     on_add: EventHandler<Education>,
     on_update: EventHandler<(usize, Education)>,
     on_remove: EventHandler<usize>,
-    on_edit: EventHandler<usize>
+    on_edit: EventHandler<usize>,
+    // Entries can be moved by hand only when the section is ordered manually
+    order: EntryOrder,
+    on_order_change: EventHandler<EntryOrder>,
+    on_move: EventHandler<(usize, isize)>,
+    selected: Option<usize>
 ) -> Element {
-    let education_list_clone = education_list.clone();
+    let entry_count = education_list.len();
     let mut new_institution = use_signal(|| String::new());
     let mut new_degree = use_signal(|| String::new());
     let mut new_field = use_signal(|| String::new());
@@ -20,7 +27,11 @@ pub fn EducationForm(
     let mut new_gpa = use_signal(|| String::new());
     let mut editing_index = use_signal(|| None::<usize>);
 
+    let education_list_for_submit = education_list.clone();
     let handle_submit = move |_| {
+        // Visibility and page breaks are kept when an entry is edited
+        let editing = editing_index().and_then(|index| education_list_for_submit.get(index));
+
         let education = Education {
             institution: new_institution().clone(),
             degree: new_degree().clone(),
@@ -30,6 +41,8 @@ pub fn EducationForm(
             location: new_location().clone(),
             description: new_description().clone(),
             gpa: new_gpa().clone(),
+            hidden: editing.is_some_and(|edu| edu.hidden),
+            page_breaks: editing.map(|edu| edu.page_breaks).unwrap_or_default(),
         };
 
         if let Some(index) = editing_index() {
@@ -50,8 +63,7 @@ pub fn EducationForm(
         new_gpa.set(String::new());
     };
 
-    let _handle_edit = move |index: usize| {
-        let edu = &education_list_clone[index];
+    let mut start_edit = move |index: usize, edu: &Education| {
         new_institution.set(edu.institution.clone());
         new_degree.set(edu.degree.clone());
         new_field.set(edu.field_of_study.clone());
@@ -63,6 +75,13 @@ pub fn EducationForm(
         editing_index.set(Some(index));
     };
 
+    // Open the entry picked in the preview
+    use_effect(use_reactive!(|selected, education_list| {
+        if let Some((index, edu)) = selected.and_then(|index| education_list.get(index).map(|edu| (index, edu))) {
+            start_edit(index, edu);
+        }
+    }));
+
     rsx! {
         div {
             class: "grid grid-cols-1 md:grid-cols-2 gap-6",
@@ -123,6 +142,9 @@ pub fn EducationForm(
                                 value: "{new_start_date}",
                                 oninput: move |event| new_start_date.set(event.value())
                             }
+                            if let Some(error) = date_error(&new_start_date(), false) {
+                                p { class: "mt-1 text-xs text-red-600", "{error}" }
+                            }
                         },
                         div {
                             label {
@@ -134,6 +156,9 @@ pub fn EducationForm(
                                 value: "{new_end_date}",
                                 oninput: move |event| new_end_date.set(event.value())
                             }
+                            if let Some(error) = date_error(&new_end_date(), true) {
+                                p { class: "mt-1 text-xs text-red-600", "{error}" }
+                            }
                         }
                     },
 
@@ -183,16 +208,25 @@ pub fn EducationForm(
 
             // Education entries on the right
             div {
-                h4 {
-                    class: "text-md font-semibold mb-2",
-                    "Education Entries"
+                div {
+                    class: "flex items-center justify-between gap-2 mb-2",
+                    h4 {
+                        class: "text-md font-semibold",
+                        "Education Entries"
+                    },
+                    EntryOrderSelect {
+                        order: order,
+                        on_change: move |order| on_order_change.call(order)
+                    }
                 },
                 if !education_list.is_empty() {
                     div {
                         class: "space-y-4 max-h-[600px] overflow-y-auto pr-2",
                         for (index, edu) in education_list.iter().enumerate() {
                             div {
-                                class: "p-4 border rounded bg-gray-50 shadow-sm hover:shadow transition-shadow",
+                                class: format!("p-4 border rounded bg-gray-50 shadow-sm hover:shadow transition-shadow {}",
+                                    if edu.hidden { "opacity-50" } else { "" }
+                                ),
                                 div {
                                     class: "font-bold text-lg",
                                     "{edu.institution}"
@@ -223,12 +257,63 @@ pub fn EducationForm(
                                     }
                                 },
                                 div {
-                                    class: "mt-2 flex gap-2",
+                                    class: "mt-2 flex flex-wrap gap-2",
                                     button {
                                         class: "px-3 py-1 bg-yellow-500 text-white rounded hover:bg-yellow-600",
-                                        onclick: move |_| on_edit.call(index),
+                                        onclick: {
+                                            let edu = edu.clone();
+                                            move |_| {
+                                                start_edit(index, &edu);
+                                                on_edit.call(index);
+                                            }
+                                        },
                                         {"Edit"}
                                     },
+                                    button {
+                                        class: "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500",
+                                        onclick: {
+                                            let mut toggled = edu.clone();
+                                            toggled.hidden = !edu.hidden;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        if edu.hidden { "Show" } else { "Hide" }
+                                    },
+                                    button {
+                                        class: if edu.page_breaks.keep_together { "px-3 py-1 bg-indigo-500 text-white rounded hover:bg-indigo-600" } else { "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500" },
+                                        title: "Never split this entry across pages",
+                                        onclick: {
+                                            let mut toggled = edu.clone();
+                                            toggled.page_breaks.keep_together = !edu.page_breaks.keep_together;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        "Keep Together"
+                                    },
+                                    button {
+                                        class: if edu.page_breaks.new_page { "px-3 py-1 bg-indigo-500 text-white rounded hover:bg-indigo-600" } else { "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500" },
+                                        title: "Start this entry on a new page",
+                                        onclick: {
+                                            let mut toggled = edu.clone();
+                                            toggled.page_breaks.new_page = !edu.page_breaks.new_page;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        "New Page"
+                                    },
+                                    if order == EntryOrder::Manual {
+                                        button {
+                                            class: "px-3 py-1 bg-white border text-gray-700 rounded hover:bg-gray-100 disabled:opacity-30",
+                                            title: "Move up",
+                                            disabled: index == 0,
+                                            onclick: move |_| on_move.call((index, -1)),
+                                            "↑"
+                                        }
+                                        button {
+                                            class: "px-3 py-1 bg-white border text-gray-700 rounded hover:bg-gray-100 disabled:opacity-30",
+                                            title: "Move down",
+                                            disabled: index + 1 == entry_count,
+                                            onclick: move |_| on_move.call((index, 1)),
+                                            "↓"
+                                        }
+                                    }
                                     button {
                                         class: "px-3 py-1 bg-red-500 text-white rounded hover:bg-red-600",
                                         onclick: move |_| on_remove.call(index),
@@ -0,0 +1,30 @@
+use dioxus::prelude::*;
+use crate::domain::models::EntryOrder;
+
+/// Picks whether a section's entries are sorted newest first or kept in the order arranged by hand
+#[component]
+pub fn EntryOrderSelect(order: EntryOrder, on_change: EventHandler<EntryOrder>) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-2 text-sm text-gray-600",
+            "Order"
+            select {
+                class: "p-1 border rounded-md",
+                value: "{order.name()}",
+                title: "Newest first keeps current positions at the top; Manual lets you move entries yourself",
+                onchange: move |evt| {
+                    if let Some(order) = EntryOrder::from_name(&evt.value()) {
+                        on_change.call(order);
+                    }
+                },
+                for option_order in EntryOrder::all() {
+                    option {
+                        value: "{option_order.name()}",
+                        selected: option_order == order,
+                        "{option_order.name()}"
+                    }
+                }
+            }
+        }
+    }
+}
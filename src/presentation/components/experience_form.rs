@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use crate::domain::models::Experience;
+use crate::application::dates::date_error;
+use crate::domain::models::{EntryOrder, Experience};
+use super::entry_order_select::EntryOrderSelect;
 
 #[component]
 pub fn ExperienceForm(
@@ -7,9 +9,22 @@ pub fn ExperienceForm(
     on_add: EventHandler<Experience>,
     on_update: EventHandler<(usize, Experience)>,
     on_remove: EventHandler<usize>,
-    on_edit: EventHandler<usize>
+    on_edit: EventHandler<usize>,
+    // Entries can be moved by hand only when the section is ordered manually
+    order: EntryOrder,
+    on_order_change: EventHandler<EntryOrder>,
+    on_move: EventHandler<(usize, isize)>,
+    // (entry, entry of the employer to join) and entry to take out of its employer
+    on_merge: EventHandler<(usize, usize)>,
+    on_split: EventHandler<usize>,
+    selected: Option<usize>
 ) -> Element {
-    let experience_list_clone = experience_list.clone();
+    let entry_count = experience_list.len();
+    // Entries that lead an employer, offered as targets for merging
+    let employers: Vec<(usize, String)> = experience_list.iter().enumerate()
+        .filter(|(_, exp)| !exp.same_employer)
+        .map(|(index, exp)| (index, exp.company.clone()))
+        .collect();
     let mut new_company = use_signal(|| String::new());
     let mut new_position = use_signal(|| String::new());
     let mut new_start_date = use_signal(|| String::new());
@@ -20,6 +35,7 @@ pub fn ExperienceForm(
     let mut new_achievements = use_signal(|| String::new());
     let mut editing_index = use_signal(|| None::<usize>);
 
+    let experience_list_for_submit = experience_list.clone();
     let handle_submit = move |_| {
         let achievements: Vec<String> = new_achievements()
             .split('\n')
@@ -27,6 +43,9 @@ pub fn ExperienceForm(
             .map(|s| s.to_string())
             .collect();
 
+        // Visibility, page breaks and employer grouping are kept when an entry is edited
+        let editing = editing_index().and_then(|index| experience_list_for_submit.get(index));
+
         let experience = Experience {
             company: new_company().clone(),
             position: new_position().clone(),
@@ -36,6 +55,9 @@ pub fn ExperienceForm(
             location: new_location().clone(),
             description: new_description().clone(),
             achievements,
+            hidden: editing.is_some_and(|exp| exp.hidden),
+            page_breaks: editing.map(|exp| exp.page_breaks).unwrap_or_default(),
+            same_employer: editing.is_some_and(|exp| exp.same_employer),
         };
 
         if let Some(index) = editing_index() {
@@ -56,8 +78,7 @@ pub fn ExperienceForm(
         new_achievements.set(String::new());
     };
 
-    let _handle_edit = move |index: usize| {
-        let exp = &experience_list_clone[index];
+    let mut start_edit = move |index: usize, exp: &Experience| {
         new_company.set(exp.company.clone());
         new_position.set(exp.position.clone());
         new_start_date.set(exp.start_date.clone());
@@ -69,6 +90,19 @@ pub fn ExperienceForm(
         editing_index.set(Some(index));
     };
 
+    // A position sharing its employer with others renames all of them
+    let editing_shared_employer = editing_index().is_some_and(|index| {
+        experience_list.get(index).is_some_and(|exp| exp.same_employer)
+            || experience_list.get(index + 1).is_some_and(|next| next.same_employer)
+    });
+
+    // Open the entry picked in the preview
+    use_effect(use_reactive!(|selected, experience_list| {
+        if let Some((index, exp)) = selected.and_then(|index| experience_list.get(index).map(|exp| (index, exp))) {
+            start_edit(index, exp);
+        }
+    }));
+
     rsx! {
         div {
             class: "grid grid-cols-1 md:grid-cols-2 gap-6",
@@ -91,6 +125,9 @@ pub fn ExperienceForm(
                             value: "{new_company}",
                             oninput: move |event| new_company.set(event.value())
                         }
+                        if editing_shared_employer {
+                            p { class: "mt-1 text-xs text-gray-500", "Changing the company renames it for every position held there" }
+                        }
                     },
 
                     div {
@@ -117,6 +154,9 @@ pub fn ExperienceForm(
                                 value: "{new_start_date}",
                                 oninput: move |event| new_start_date.set(event.value())
                             }
+                            if let Some(error) = date_error(&new_start_date(), false) {
+                                p { class: "mt-1 text-xs text-red-600", "{error}" }
+                            }
                         },
                         div {
                             label {
@@ -129,6 +169,9 @@ pub fn ExperienceForm(
                                 oninput: move |event| new_end_date.set(event.value()),
                                 disabled: new_is_current()
                             }
+                            if let Some(error) = date_error(&new_end_date(), true).filter(|_| !new_is_current()) {
+                                p { class: "mt-1 text-xs text-red-600", "{error}" }
+                            }
                         }
                     },
 
@@ -191,19 +234,32 @@ pub fn ExperienceForm(
 
             // Experience entries on the right
             div {
-                h4 {
-                    class: "text-md font-semibold mb-2",
-                    "Experience Entries"
+                div {
+                    class: "flex items-center justify-between gap-2 mb-2",
+                    h4 {
+                        class: "text-md font-semibold",
+                        "Experience Entries"
+                    },
+                    EntryOrderSelect {
+                        order: order,
+                        on_change: move |order| on_order_change.call(order)
+                    }
                 },
                 if !experience_list.is_empty() {
                     div {
                         class: "space-y-4 max-h-[600px] overflow-y-auto pr-2",
                         for (index, exp) in experience_list.iter().enumerate() {
                             div {
-                                class: "p-4 border rounded bg-gray-50 shadow-sm hover:shadow transition-shadow",
-                                div {
-                                    class: "font-bold text-lg",
-                                    "{exp.company}"
+                                // Further positions at an employer are indented under its first one
+                                class: format!("p-4 border rounded bg-gray-50 shadow-sm hover:shadow transition-shadow {} {}",
+                                    if exp.hidden { "opacity-50" } else { "" },
+                                    if exp.same_employer { "ml-6 -mt-2 border-l-4 border-l-blue-200" } else { "" }
+                                ),
+                                if !exp.same_employer {
+                                    div {
+                                        class: "font-bold text-lg",
+                                        "{exp.company}"
+                                    }
                                 },
                                 div {
                                     "{exp.position}"
@@ -233,12 +289,86 @@ pub fn ExperienceForm(
                                     }
                                 },
                                 div {
-                                    class: "mt-2 flex gap-2",
+                                    class: "mt-2 flex flex-wrap gap-2",
                                     button {
                                         class: "px-3 py-1 bg-yellow-500 text-white rounded hover:bg-yellow-600",
-                                        onclick: move |_| on_edit.call(index),
+                                        onclick: {
+                                            let exp = exp.clone();
+                                            move |_| {
+                                                start_edit(index, &exp);
+                                                on_edit.call(index);
+                                            }
+                                        },
                                         {"Edit"}
                                     },
+                                    button {
+                                        class: "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500",
+                                        onclick: {
+                                            let mut toggled = exp.clone();
+                                            toggled.hidden = !exp.hidden;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        if exp.hidden { "Show" } else { "Hide" }
+                                    },
+                                    button {
+                                        class: if exp.page_breaks.keep_together { "px-3 py-1 bg-indigo-500 text-white rounded hover:bg-indigo-600" } else { "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500" },
+                                        title: "Never split this entry across pages",
+                                        onclick: {
+                                            let mut toggled = exp.clone();
+                                            toggled.page_breaks.keep_together = !exp.page_breaks.keep_together;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        "Keep Together"
+                                    },
+                                    button {
+                                        class: if exp.page_breaks.new_page { "px-3 py-1 bg-indigo-500 text-white rounded hover:bg-indigo-600" } else { "px-3 py-1 bg-gray-400 text-white rounded hover:bg-gray-500" },
+                                        title: "Start this entry on a new page",
+                                        onclick: {
+                                            let mut toggled = exp.clone();
+                                            toggled.page_breaks.new_page = !exp.page_breaks.new_page;
+                                            move |_| on_update.call((index, toggled.clone()))
+                                        },
+                                        "New Page"
+                                    },
+                                    if order == EntryOrder::Manual {
+                                        button {
+                                            class: "px-3 py-1 bg-white border text-gray-700 rounded hover:bg-gray-100 disabled:opacity-30",
+                                            title: "Move up",
+                                            disabled: index == 0,
+                                            onclick: move |_| on_move.call((index, -1)),
+                                            "↑"
+                                        }
+                                        button {
+                                            class: "px-3 py-1 bg-white border text-gray-700 rounded hover:bg-gray-100 disabled:opacity-30",
+                                            title: "Move down",
+                                            disabled: index + 1 == entry_count,
+                                            onclick: move |_| on_move.call((index, 1)),
+                                            "↓"
+                                        }
+                                    }
+                                    if exp.same_employer || experience_list.get(index + 1).is_some_and(|next| next.same_employer) {
+                                        button {
+                                            class: "px-3 py-1 bg-white border text-gray-700 rounded hover:bg-gray-100",
+                                            title: "List this position as an employer of its own",
+                                            onclick: move |_| on_split.call(index),
+                                            "Split"
+                                        }
+                                    } else if employers.len() > 1 {
+                                        select {
+                                            class: "px-2 py-1 border rounded bg-white text-gray-700",
+                                            title: "List this position under another employer, as a promotion or move",
+                                            value: "",
+                                            onchange: move |evt| {
+                                                if let Ok(target) = evt.value().parse::<usize>() {
+                                                    on_merge.call((index, target));
+                                                }
+                                            },
+                                            option { value: "", "Merge with…" }
+                                            for (target, company) in employers.iter().filter(|(target, _)| *target != index) {
+                                                option { value: "{target}", if company.is_empty() { "Unnamed employer" } else { "{company}" } }
+                                            }
+                                        }
+                                    }
                                     button {
                                         class: "px-3 py-1 bg-red-500 text-white rounded hover:bg-red-600",
                                         onclick: move |_| on_remove.call(index),
@@ -0,0 +1,142 @@
+use dioxus::prelude::*;
+use crate::domain::models::Resume;
+use crate::infrastructure::export::{LatexTemplate, DEFAULT_TEXT_WIDTH};
+use crate::presentation::export_format::ExportFormat;
+
+/// Export options for the open resume
+#[component]
+pub fn ExportPanel(
+    theme_name: String,
+    resume: Resume,
+    on_download: EventHandler<ExportFormat>,
+    on_copy: EventHandler<ExportFormat>
+) -> Element {
+    let mut selected_format = use_signal(|| ExportFormat::Pdf);
+    let mut text_width = use_signal(|| DEFAULT_TEXT_WIDTH);
+    let mut latex_template = use_signal(LatexTemplate::default);
+    let paper_size = resume.paper_size;
+
+    // Applies the chosen line width and template, and the resume's paper size, to the text exports
+    let current_format = move || match selected_format() {
+        ExportFormat::PlainText { .. } => ExportFormat::PlainText { width: text_width() },
+        ExportFormat::Latex { .. } => ExportFormat::Latex { template: latex_template() },
+        ExportFormat::Typst { .. } => ExportFormat::Typst { paper: paper_size },
+        format => format,
+    };
+
+    rsx! {
+        div {
+            class: "bg-white rounded-lg p-6 shadow",
+            h3 {
+                class: "text-xl font-bold mb-4",
+                "Export Resume"
+            },
+            p {
+                class: "mb-4",
+                "Your resume has been prepared for export with the '{theme_name}' theme."
+            },
+            div {
+                class: "mb-4",
+                label {
+                    class: "block text-sm font-medium text-gray-700 mb-1",
+                    "Format"
+                },
+                select {
+                    class: "w-full p-2 border rounded",
+                    value: "{selected_format().name()}",
+                    onchange: move |evt| {
+                        if let Some(format) = ExportFormat::all().into_iter().find(|format| format.name() == evt.value()) {
+                            selected_format.set(format);
+                        }
+                    },
+                    for format in ExportFormat::all() {
+                        option { value: "{format.name()}", "{format.name()}" }
+                    }
+                }
+            },
+            if matches!(selected_format(), ExportFormat::PlainText { .. }) {
+                div {
+                    class: "mb-4",
+                    label {
+                        class: "block text-sm font-medium text-gray-700 mb-1",
+                        "Line width"
+                    },
+                    input {
+                        r#type: "number",
+                        class: "w-full p-2 border rounded",
+                        min: "20",
+                        value: "{text_width}",
+                        oninput: move |evt| {
+                            if let Ok(width) = evt.value().parse::<usize>() {
+                                text_width.set(width);
+                            }
+                        }
+                    }
+                }
+            },
+            if matches!(selected_format(), ExportFormat::Latex { .. }) {
+                div {
+                    class: "mb-4",
+                    label {
+                        class: "block text-sm font-medium text-gray-700 mb-1",
+                        "Template"
+                    },
+                    select {
+                        class: "w-full p-2 border rounded",
+                        value: "{latex_template().name()}",
+                        onchange: move |evt| {
+                            if let Some(template) = LatexTemplate::from_name(&evt.value()) {
+                                latex_template.set(template);
+                            }
+                        },
+                        for template in LatexTemplate::all() {
+                            option { value: "{template.name()}", "{template.name()}" }
+                        }
+                    }
+                }
+            },
+            if matches!(selected_format(), ExportFormat::Pdf | ExportFormat::Typst { .. }) {
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    "Pages are laid out on {paper_size.name()} paper, as in the preview. Change the paper size above the pages."
+                }
+            },
+            if matches!(selected_format(), ExportFormat::Pdf) {
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    "The print dialog opens; choose \"Save as PDF\" as the printer."
+                }
+            },
+            if let Some(warning) = selected_format().photo_warning(&resume) {
+                p {
+                    class: "mb-4 p-2 text-sm text-amber-800 bg-amber-50 border border-amber-200 rounded",
+                    "{warning} Remove the photo or pick a theme that does not show photos to leave it out."
+                }
+            },
+            div {
+                class: "flex justify-end",
+                div {
+                    class: "flex gap-2",
+                    if selected_format().supports_copy() {
+                        button {
+                            class: "px-4 py-2 bg-green-500 text-white rounded hover:bg-green-600 transition-colors",
+                            onclick: move |_| on_copy.call(current_format()),
+                            "Copy to Clipboard"
+                        }
+                    },
+                    button {
+                        class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                        onclick: move |_| {
+                            on_download.call(current_format());
+                        },
+                        if matches!(selected_format(), ExportFormat::Pdf) {
+                            "Print to PDF"
+                        } else {
+                            "Download {selected_format().name()}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use dioxus::prelude::*;
+use crate::domain::import_draft::ImportDraft;
+use crate::domain::models::Resume;
+use crate::infrastructure::import::{import_linkedin_archive, import_text_resume};
+
+// Kinds of documents the dialog can import
+#[derive(Clone, Copy, PartialEq)]
+enum ImportSource {
+    LinkedIn,
+    Text,
+}
+
+/// Dialog for importing resume data with a preview before anything is changed
+#[component]
+pub fn ImportModal(
+    show: bool,
+    on_close: EventHandler<()>,
+    on_merge: EventHandler<Resume>,
+    on_replace: EventHandler<Resume>,
+    on_review: EventHandler<ImportDraft>
+) -> Element {
+    let mut source = use_signal(|| ImportSource::LinkedIn);
+    let mut imported = use_signal(|| Option::<Resume>::None);
+    let mut pasted_text = use_signal(String::new);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    if !show {
+        return rsx!{};
+    }
+
+    // Reads the selected archive and parses it into a draft resume
+    let handle_file = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else {
+            return;
+        };
+        let Some(file_name) = engine.files().into_iter().next() else {
+            return;
+        };
+        match engine.read_file(&file_name).await {
+            Some(bytes) => match import_linkedin_archive(&bytes) {
+                Ok(resume) => {
+                    imported.set(Some(resume));
+                    error.set(None);
+                }
+                Err(err) => {
+                    imported.set(None);
+                    error.set(Some(format!("Could not import {}: {}", file_name, err)));
+                }
+            },
+            None => error.set(Some(format!("Could not read {}", file_name))),
+        }
+    };
+
+    // Loads a Markdown or text file into the editable text area
+    let handle_text_file = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else {
+            return;
+        };
+        let Some(file_name) = engine.files().into_iter().next() else {
+            return;
+        };
+        match engine.read_file_to_string(&file_name).await {
+            Some(text) => {
+                pasted_text.set(text);
+                error.set(None);
+            }
+            None => error.set(Some(format!("Could not read {}", file_name))),
+        }
+    };
+
+    let mut close = move || {
+        imported.set(None);
+        pasted_text.set(String::new());
+        error.set(None);
+        on_close.call(());
+    };
+
+    let source_tab_class = move |tab: ImportSource| {
+        if source() == tab { "px-3 py-1 rounded bg-blue-500 text-white" } else { "px-3 py-1 rounded bg-gray-200 hover:bg-gray-300" }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            div {
+                class: "bg-white rounded-lg p-6 w-[32rem] max-h-[90vh] overflow-y-auto shadow-xl",
+                h3 {
+                    class: "text-xl font-bold mb-4",
+                    "Import Resume"
+                },
+                div {
+                    class: "flex gap-2 mb-4",
+                    button {
+                        class: source_tab_class(ImportSource::LinkedIn),
+                        onclick: move |_| source.set(ImportSource::LinkedIn),
+                        "LinkedIn Archive"
+                    },
+                    button {
+                        class: source_tab_class(ImportSource::Text),
+                        onclick: move |_| source.set(ImportSource::Text),
+                        "Markdown / Text"
+                    }
+                },
+
+                if source() == ImportSource::Text {
+                    p {
+                        class: "mb-4 text-sm text-gray-600",
+                        "Choose a Markdown or text file, or paste your old resume below. The draft opens in the editor for review before it replaces anything."
+                    },
+                    input {
+                        r#type: "file",
+                        accept: ".md,.markdown,.txt,text/plain,text/markdown",
+                        class: "w-full mb-4",
+                        onchange: handle_text_file
+                    },
+                    textarea {
+                        class: "w-full p-2 border rounded mb-4 h-48 font-mono text-sm",
+                        placeholder: "Paste resume text here",
+                        value: "{pasted_text}",
+                        oninput: move |evt| pasted_text.set(evt.value())
+                    }
+                } else {
+                    p {
+                        class: "mb-4 text-sm text-gray-600",
+                        "Choose the ZIP archive from LinkedIn's \"Get a copy of your data\" page. Nothing is changed until you confirm."
+                    },
+                    input {
+                        r#type: "file",
+                        accept: ".zip",
+                        class: "w-full mb-4",
+                        onchange: handle_file
+                    }
+                }
+
+                if let Some(message) = error() {
+                    p {
+                        class: "mb-4 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+
+                if let (ImportSource::LinkedIn, Some(draft)) = (source(), imported()) {
+                    div {
+                        class: "mb-4 p-3 border rounded bg-gray-50 text-sm space-y-2",
+                        p {
+                            class: "font-bold",
+                            if draft.personal_info.name.is_empty() { "Unnamed profile" } else { "{draft.personal_info.name}" }
+                        },
+                        if !draft.personal_info.email.is_empty() {
+                            p { "{draft.personal_info.email}" }
+                        },
+                        if !draft.personal_info.location.is_empty() {
+                            p { "{draft.personal_info.location}" }
+                        },
+                        p { "{draft.experience.len()} positions, {draft.education.len()} education entries, {draft.skills.skill_list.len()} skills, {draft.projects.len()} projects" },
+                        if !draft.experience.is_empty() {
+                            ul {
+                                class: "list-disc ml-5",
+                                for exp in draft.experience.iter() {
+                                    li { "{exp.position} at {exp.company}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex justify-between",
+                    button {
+                        class: "px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| close(),
+                        "Cancel"
+                    },
+                    if source() == ImportSource::Text {
+                        button {
+                            class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                            disabled: pasted_text().trim().is_empty(),
+                            onclick: move |_| {
+                                on_review.call(import_text_resume(&pasted_text()));
+                                close();
+                            },
+                            "Review in Editor"
+                        }
+                    } else if let Some(draft) = imported() {
+                        div {
+                            class: "flex gap-2",
+                            button {
+                                class: "px-4 py-2 bg-green-500 text-white rounded hover:bg-green-600 transition-colors",
+                                onclick: {
+                                    let draft = draft.clone();
+                                    move |_| {
+                                        on_merge.call(draft.clone());
+                                        close();
+                                    }
+                                },
+                                "Merge into Resume"
+                            },
+                            button {
+                                class: "px-4 py-2 bg-red-500 text-white rounded hover:bg-red-600 transition-colors",
+                                onclick: move |_| {
+                                    on_replace.call(draft.clone());
+                                    close();
+                                },
+                                "Replace Resume"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use dioxus::prelude::*;
+use crate::domain::import_draft::{Confidence, FieldConfidence};
+
+/// Banner shown while an imported draft is being reviewed in the editor forms
+#[component]
+pub fn ImportReview(
+    fields: Vec<FieldConfidence>,
+    on_accept: EventHandler<()>,
+    on_merge: EventHandler<()>,
+    on_discard: EventHandler<()>
+) -> Element {
+    let badge_class = |confidence: Confidence| match confidence {
+        Confidence::Low => "bg-red-100 text-red-800",
+        Confidence::Medium => "bg-amber-100 text-amber-800",
+        Confidence::High => "bg-green-100 text-green-800",
+    };
+
+    rsx! {
+        div {
+            class: "mb-6 p-4 border border-amber-300 rounded-lg bg-amber-50",
+            div {
+                class: "flex justify-between items-center mb-2",
+                h3 {
+                    class: "text-lg font-bold",
+                    "Reviewing Imported Resume"
+                },
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "px-3 py-1 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| on_discard.call(()),
+                        "Discard"
+                    },
+                    button {
+                        class: "px-3 py-1 bg-green-500 text-white rounded hover:bg-green-600 transition-colors",
+                        onclick: move |_| on_merge.call(()),
+                        "Merge into Previous"
+                    },
+                    button {
+                        class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                        onclick: move |_| on_accept.call(()),
+                        "Accept Draft"
+                    }
+                }
+            },
+            p {
+                class: "text-sm text-gray-700 mb-3",
+                "The forms below contain the imported draft. Correct anything that was guessed wrong, then accept it or merge it into your previous resume."
+            },
+            if fields.is_empty() {
+                p {
+                    class: "text-sm text-gray-600",
+                    "All fields were recognized with high confidence."
+                }
+            } else {
+                ul {
+                    class: "space-y-1 text-sm max-h-48 overflow-y-auto",
+                    for field in fields.iter() {
+                        li {
+                            class: "flex items-start gap-2",
+                            span {
+                                class: format!("px-2 rounded text-xs font-medium {}", badge_class(field.confidence)),
+                                "{field.confidence.name()}"
+                            },
+                            span {
+                                class: "font-medium",
+                                "{field.field}:"
+                            },
+                            span {
+                                class: "text-gray-700 truncate",
+                                "{field.value}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use dioxus::prelude::*;
+use crate::application::merge::{MergeChoice, MergePlan};
+
+/// Dialog listing merge conflicts with a keep mine / take theirs / keep both choice for each
+#[component]
+pub fn MergeDialog(
+    plan: MergePlan,
+    on_choice: EventHandler<(usize, MergeChoice)>,
+    on_cancel: EventHandler<()>,
+    on_apply: EventHandler<()>
+) -> Element {
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            div {
+                class: "bg-white rounded-lg p-6 w-[44rem] max-h-[90vh] overflow-y-auto shadow-xl",
+                h3 {
+                    class: "text-xl font-bold mb-2",
+                    "Resolve Merge Conflicts"
+                },
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    "{plan.additions} new entries will be added and {plan.duplicates} duplicates skipped. Choose what to keep where both resumes disagree."
+                },
+
+                for (index, conflict) in plan.conflicts.iter().enumerate() {
+                    div {
+                        key: "{index}",
+                        class: "mb-4 p-3 border rounded",
+                        div {
+                            class: "flex justify-between items-center mb-2",
+                            h4 {
+                                class: "font-bold",
+                                "{conflict.label}"
+                            },
+                            div {
+                                class: "flex gap-1",
+                                for choice in MergeChoice::all(conflict.target) {
+                                    button {
+                                        class: if conflict.choice == choice {
+                                            "px-2 py-1 text-sm rounded bg-blue-500 text-white"
+                                        } else {
+                                            "px-2 py-1 text-sm rounded bg-gray-200 hover:bg-gray-300"
+                                        },
+                                        onclick: move |_| on_choice.call((index, choice)),
+                                        "{choice.name()}"
+                                    }
+                                }
+                            }
+                        },
+                        table {
+                            class: "w-full text-sm",
+                            thead {
+                                tr {
+                                    th { class: "text-left text-gray-600 w-1/5", "Field" },
+                                    th { class: "text-left text-gray-600 w-2/5", "Mine" },
+                                    th { class: "text-left text-gray-600 w-2/5", "Theirs" }
+                                }
+                            },
+                            tbody {
+                                for difference in conflict.differences.iter() {
+                                    tr {
+                                        class: "align-top border-t",
+                                        td { class: "py-1 pr-2 font-medium", "{difference.field}" },
+                                        td { class: "py-1 pr-2 whitespace-pre-line", "{difference.mine}" },
+                                        td { class: "py-1 whitespace-pre-line", "{difference.theirs}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex justify-between",
+                    button {
+                        class: "px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| on_cancel.call(()),
+                        "Cancel"
+                    },
+                    button {
+                        class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                        onclick: move |_| on_apply.call(()),
+                        "Apply Merge"
+                    }
+                }
+            }
+        }
+    }
+}
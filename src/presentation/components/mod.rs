@@ -1,23 +1,54 @@
 // Components module - contains all UI components
 pub mod personal_info_form;
+pub mod photo_editor;
+pub mod profile_links_editor;
 pub mod resume_preview;
+pub mod paged_preview;
 pub mod education_form;
+pub mod entry_order_select;
 pub mod experience_form;
 pub mod theme_selector;
 pub mod draggable_section;
 pub mod toggle_button;
-pub mod export_modal;
+pub mod export_panel;
+pub mod import_modal;
+pub mod import_review;
+pub mod merge_dialog;
+pub mod passphrase_modal;
+pub mod unlock_screen;
+pub mod skill_insights;
+pub mod skill_suggestions;
 pub mod skills_form;
 pub mod projects_form;
+pub mod application_tracker;
+pub mod career_timeline;
+pub mod validation_panel;
+pub mod backup_modal;
+pub mod sync_modal;
 
 // Re-exports for convenience
 pub use education_form::EducationForm;
 pub use experience_form::ExperienceForm;
-pub use theme_selector::{Theme, ThemeSelector};
+pub use theme_selector::{available_themes, Theme, ThemeSelector};
 pub use draggable_section::DraggableSection;
 pub use toggle_button::ToggleButton;
-pub use export_modal::ExportModal;
+pub use export_panel::ExportPanel;
+pub use import_modal::ImportModal;
+pub use import_review::ImportReview;
+pub use merge_dialog::MergeDialog;
+pub use passphrase_modal::{PassphraseChange, PassphraseModal};
+pub use unlock_screen::UnlockScreen;
 pub use personal_info_form::PersonalInfoForm;
-pub use resume_preview::ResumePreview;
+pub use photo_editor::PhotoEditor;
+pub use profile_links_editor::ProfileLinksEditor;
+pub use resume_preview::{PreviewTarget, ResumePreview};
+pub use paged_preview::PagedPreview;
+pub use skill_insights::SkillInsights;
+pub use skill_suggestions::SkillSuggestions;
 pub use skills_form::SkillsForm;
 pub use projects_form::ProjectsForm;
+pub use application_tracker::ApplicationTracker;
+pub use career_timeline::CareerTimeline;
+pub use validation_panel::ValidationPanel;
+pub use backup_modal::BackupModal;
+pub use sync_modal::SyncModal;
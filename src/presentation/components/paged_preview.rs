@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+
+use crate::domain::models::{PaperSize, Resume};
+use crate::presentation::browser::{fit_to_width, measure_children};
+use crate::presentation::components::resume_preview::{render_block, PreviewLinks, PreviewTarget};
+use crate::presentation::pagination::{flow_blocks, paginate, PageArea, PAGE_MARGIN_MM, PX_PER_MM};
+
+const MEASURE_ID: &str = "page-measure";
+const VIEWPORT_ID: &str = "page-viewport";
+
+/// Resume laid out on printable pages, the way it is printed or saved as PDF
+#[component]
+pub fn PagedPreview(
+    resume: Resume,
+    theme_bg: &'static str,
+    highlight: Option<PreviewTarget>,
+    on_select: Option<EventHandler<PreviewTarget>>,
+    on_paper_change: EventHandler<PaperSize>
+) -> Element {
+    let mut heights = use_signal(Vec::<f64>::new);
+
+    // Blocks are measured off screen at page width whenever the resume changes
+    use_effect(use_reactive!(|resume| {
+        let _ = resume;
+        spawn(async move {
+            heights.set(measure_children(MEASURE_ID).await);
+        });
+    }));
+
+    let paper = resume.paper_size;
+    let (paper_width, paper_height) = paper.dimensions_mm();
+    use_effect(use_reactive!(|paper_width| fit_to_width(VIEWPORT_ID, paper_width * PX_PER_MM)));
+
+    let area = PageArea::for_paper(paper);
+    let flow = flow_blocks(&resume);
+    // Until the blocks are measured only forced page breaks are known
+    let measured = if heights().len() == flow.len() { heights() } else { vec![0.0; flow.len()] };
+    let pages = paginate(&flow, &measured, area.height_px());
+    let page_count = pages.len();
+    let cut_off = measured.iter().any(|height| *height > area.height_px());
+    let links = on_select.map(|on_select| PreviewLinks { highlight, on_select });
+
+    // Every sheet is printed as exactly one page
+    let print_css = format!(
+        "@page {{ size: {}mm {}mm; margin: 0; }}
+        .page-sheet {{ -webkit-print-color-adjust: exact; print-color-adjust: exact; }}
+        @media print {{ .page-stack {{ zoom: 1 !important; }} }}",
+        paper_width, paper_height
+    );
+    let sheet_style = format!(
+        "width: {}mm; height: {}mm; padding: {}mm;",
+        paper_width, paper_height, PAGE_MARGIN_MM
+    );
+
+    rsx! {
+        style { "{print_css}" }
+
+        div {
+            class: "flex items-center justify-between mb-2 text-sm print:hidden",
+            label {
+                class: "flex items-center gap-2",
+                "Paper",
+                select {
+                    class: "p-1 border rounded",
+                    value: "{paper.name()}",
+                    onchange: move |evt| {
+                        if let Some(paper) = PaperSize::from_name(&evt.value()) {
+                            on_paper_change.call(paper);
+                        }
+                    },
+                    for size in PaperSize::all() {
+                        option { value: "{size.name()}", "{size.name()}" }
+                    }
+                }
+            },
+            span {
+                class: "text-gray-600",
+                if page_count == 1 { "1 page" } else { "{page_count} pages" }
+            }
+        },
+        if cut_off {
+            p {
+                class: "mb-2 text-sm text-amber-700 print:hidden",
+                "An entry is taller than a page and is cut off. Shorten it or split it into several entries."
+            }
+        }
+
+        // Off-screen copy of the content, used to measure every block
+        div {
+            id: MEASURE_ID,
+            class: "fixed top-0 left-[-10000px] invisible flow-root print:hidden",
+            style: "width: {area.width_mm}mm;",
+            for flow in flow.iter() {
+                {render_block(&resume, *flow, None)}
+            }
+        }
+
+        div {
+            id: VIEWPORT_ID,
+            class: "overflow-hidden print:overflow-visible",
+            div {
+                class: "page-stack",
+                for (number, page) in pages.into_iter().enumerate() {
+                    div {
+                        class: if number + 1 < page_count { "mb-6 print:m-0 print:break-after-page" } else { "mb-6 print:m-0" },
+                        div {
+                            class: format!("page-sheet flow-root overflow-hidden bg-white shadow print:shadow-none {}", theme_bg),
+                            style: "{sheet_style}",
+                            for flow in flow[page].iter() {
+                                {render_block(&resume, *flow, links)}
+                            }
+                        },
+                        p {
+                            class: "mt-1 text-center text-xs text-gray-500 print:hidden",
+                            "Page {number + 1} of {page_count}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
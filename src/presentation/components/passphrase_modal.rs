@@ -0,0 +1,148 @@
+use dioxus::prelude::*;
+
+// Passphrase action requested from the dialog
+#[derive(Clone, PartialEq)]
+pub enum PassphraseChange {
+    Encrypt { passphrase: String },
+    Change { current: String, new: String },
+    Decrypt { current: String },
+}
+
+/// Dialog for turning encryption on or off and changing the passphrase
+#[component]
+pub fn PassphraseModal(
+    show: bool,
+    encrypted: bool,
+    busy: bool,
+    error: Option<String>,
+    on_close: EventHandler<()>,
+    on_submit: EventHandler<PassphraseChange>
+) -> Element {
+    let mut current = use_signal(String::new);
+    let mut new = use_signal(String::new);
+    let mut confirm = use_signal(String::new);
+    let mut mismatch = use_signal(|| false);
+
+    if !show {
+        return rsx!{};
+    }
+
+    let mut clear = move || {
+        current.set(String::new());
+        new.set(String::new());
+        confirm.set(String::new());
+        mismatch.set(false);
+    };
+
+    let mut submit_new = move || {
+        if new() != confirm() {
+            mismatch.set(true);
+            return;
+        }
+        on_submit.call(if encrypted {
+            PassphraseChange::Change { current: current(), new: new() }
+        } else {
+            PassphraseChange::Encrypt { passphrase: new() }
+        });
+        // Passphrases are not kept around once they have been handed over
+        clear();
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            div {
+                class: "bg-white rounded-lg p-6 w-96 shadow-xl",
+                h3 {
+                    class: "text-xl font-bold mb-2",
+                    if encrypted { "Change Passphrase" } else { "Encrypt Your Data" }
+                },
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    if encrypted {
+                        "Your resumes and applications are stored encrypted. There is no way to recover them if the passphrase is lost."
+                    } else {
+                        "Encrypt every resume and application stored on this device with a passphrase. There is no way to recover them if the passphrase is lost."
+                    }
+                },
+
+                if encrypted {
+                    label {
+                        class: "block text-sm font-medium mb-1",
+                        "Current passphrase"
+                    },
+                    input {
+                        r#type: "password",
+                        class: "w-full p-2 border rounded mb-3",
+                        value: "{current}",
+                        oninput: move |evt| current.set(evt.value())
+                    }
+                }
+                label {
+                    class: "block text-sm font-medium mb-1",
+                    "New passphrase"
+                },
+                input {
+                    r#type: "password",
+                    class: "w-full p-2 border rounded mb-3",
+                    value: "{new}",
+                    oninput: move |evt| new.set(evt.value())
+                },
+                label {
+                    class: "block text-sm font-medium mb-1",
+                    "Confirm new passphrase"
+                },
+                input {
+                    r#type: "password",
+                    class: "w-full p-2 border rounded mb-3",
+                    value: "{confirm}",
+                    oninput: move |evt| confirm.set(evt.value())
+                },
+
+                if mismatch() {
+                    p {
+                        class: "mb-3 text-sm text-red-600",
+                        "The new passphrases do not match."
+                    }
+                } else if let Some(message) = error {
+                    p {
+                        class: "mb-3 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+
+                div {
+                    class: "flex justify-between",
+                    button {
+                        class: "px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| {
+                            clear();
+                            on_close.call(());
+                        },
+                        "Cancel"
+                    },
+                    div {
+                        class: "flex gap-2",
+                        if encrypted {
+                            button {
+                                class: "px-4 py-2 bg-red-500 text-white rounded hover:bg-red-600 transition-colors",
+                                disabled: busy || current().is_empty(),
+                                onclick: move |_| {
+                                    on_submit.call(PassphraseChange::Decrypt { current: current() });
+                                    clear();
+                                },
+                                "Turn Off"
+                            }
+                        }
+                        button {
+                            class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                            disabled: busy || new().is_empty() || (encrypted && current().is_empty()),
+                            onclick: move |_| submit_new(),
+                            if busy { "Working..." } else if encrypted { "Change" } else { "Encrypt" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
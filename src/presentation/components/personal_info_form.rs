@@ -1,9 +1,12 @@
 use dioxus::prelude::*;
 use crate::domain::models::PersonalInfo;
+use crate::presentation::components::{PhotoEditor, ProfileLinksEditor};
 
 #[component]
 pub fn PersonalInfoForm(
     personal_info: PersonalInfo,
+    // Whether the selected theme shows the photo
+    shows_photo: bool,
     on_change: EventHandler<PersonalInfo>,
 ) -> Element {
     let mut name = use_signal(|| personal_info.name.clone());
@@ -12,10 +15,8 @@ pub fn PersonalInfoForm(
     let mut location = use_signal(|| personal_info.location.clone());
     let mut summary = use_signal(|| personal_info.summary.clone());
     
-    // Handle optional social media fields
-    let mut github = use_signal(|| personal_info.github.clone());
-    let mut linkedin = use_signal(|| personal_info.linkedin.clone());
-    let mut website = use_signal(|| personal_info.website.clone());
+    let mut links = use_signal(|| personal_info.links.clone());
+    let mut photo = use_signal(|| personal_info.photo.clone());
 
     // Update the parent component when any field changes
     let update_parent = move || {
@@ -25,9 +26,8 @@ pub fn PersonalInfoForm(
             phone: phone().clone(),
             location: location().clone(),
             summary: summary().clone(),
-            github: github().clone(),
-            linkedin: linkedin().clone(),
-            website: website().clone(),
+            links: links(),
+            photo: photo(),
         };
         on_change.call(updated_info);
     };
@@ -98,56 +98,14 @@ pub fn PersonalInfoForm(
                 }
             },
             
-            // Social media links
-            div {
-                class: "grid grid-cols-1 md:grid-cols-3 gap-4 mt-4",
-                div {
-                    label {
-                        class: "block text-sm font-medium text-gray-700 mb-1",
-                        "GitHub"
-                    },
-                    input {
-                        class: "w-full p-2 border rounded",
-                        value: "{github}",
-                        placeholder: "github.com/username",
-                        oninput: move |evt| {
-                            github.set(evt.value());
-                            update_parent();
-                        }
-                    }
-                },
-                div {
-                    label {
-                        class: "block text-sm font-medium text-gray-700 mb-1",
-                        "LinkedIn"
-                    },
-                    input {
-                        class: "w-full p-2 border rounded",
-                        value: "{linkedin}",
-                        placeholder: "linkedin.com/in/username",
-                        oninput: move |evt| {
-                            linkedin.set(evt.value());
-                            update_parent();
-                        }
-                    }
-                },
-                div {
-                    label {
-                        class: "block text-sm font-medium text-gray-700 mb-1",
-                        "Personal Website"
-                    },
-                    input {
-                        class: "w-full p-2 border rounded",
-                        value: "{website}",
-                        placeholder: "example.com",
-                        oninput: move |evt| {
-                            website.set(evt.value());
-                            update_parent();
-                        }
-                    }
+            ProfileLinksEditor {
+                links: links(),
+                on_change: move |updated| {
+                    links.set(updated);
+                    update_parent();
                 }
             },
-            
+
             div {
                 class: "mt-4",
                 label {
@@ -163,6 +121,15 @@ pub fn PersonalInfoForm(
                         update_parent();
                     }
                 }
+            },
+
+            PhotoEditor {
+                photo: photo(),
+                shown_by_theme: shows_photo,
+                on_change: move |updated| {
+                    photo.set(updated);
+                    update_parent();
+                }
             }
         }
     }
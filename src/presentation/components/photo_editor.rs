@@ -0,0 +1,231 @@
+use dioxus::prelude::*;
+use crate::domain::models::{PhotoShape, ProfilePhoto};
+use crate::presentation::browser::{close_image, crop_image, open_image};
+
+// Uploads larger than this are rejected before they are decoded
+const MAX_UPLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+/// Optional profile photo: upload, crop to a square or circle, or remove it
+#[component]
+pub fn PhotoEditor(
+    photo: Option<ProfilePhoto>,
+    // Whether the selected theme shows photos at all
+    shown_by_theme: bool,
+    on_change: EventHandler<Option<ProfilePhoto>>
+) -> Element {
+    // Object URL of the uploaded image while it is being cropped
+    let mut source = use_signal(|| Option::<String>::None);
+    let mut zoom = use_signal(|| 1.0);
+    let mut offset_x = use_signal(|| 0.0);
+    let mut offset_y = use_signal(|| 0.0);
+    let mut shape = use_signal(PhotoShape::default);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    // The crop is redone in the browser whenever the image or a control changes
+    let cropped = use_resource(move || async move {
+        let url = source()?;
+        crop_image(&url, zoom(), offset_x(), offset_y(), ProfilePhoto::SIZE_PX).await
+    });
+    let cropped_photo = cropped.read().clone().flatten().map(|jpeg| ProfilePhoto { shape: shape(), jpeg });
+
+    let current_shape = photo.as_ref().map(|photo| photo.shape).unwrap_or_default();
+    let handle_file = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else {
+            return;
+        };
+        let Some(file_name) = engine.files().into_iter().next() else {
+            return;
+        };
+        let Some(bytes) = engine.read_file(&file_name).await else {
+            error.set(Some(format!("Could not read {}", file_name)));
+            return;
+        };
+        if bytes.len() > MAX_UPLOAD_BYTES {
+            error.set(Some(format!("{} is larger than 20 MB", file_name)));
+            return;
+        }
+        match open_image(&bytes).await {
+            Some(url) => {
+                if let Some(previous) = source.replace(Some(url)) {
+                    close_image(&previous);
+                }
+                zoom.set(1.0);
+                offset_x.set(0.0);
+                offset_y.set(0.0);
+                shape.set(current_shape);
+                error.set(None);
+            }
+            None => error.set(Some(format!("{} is not an image this browser can read", file_name))),
+        }
+    };
+
+    let mut close_source = move || {
+        if let Some(url) = source.replace(None) {
+            close_image(&url);
+        }
+    };
+
+    let photo_class = |shape: PhotoShape| match shape {
+        PhotoShape::Square => "w-24 h-24 object-cover rounded border",
+        PhotoShape::Circle => "w-24 h-24 object-cover rounded-full border",
+    };
+    let slider = |label: &'static str, value: Signal<f64>, min: &'static str, max: &'static str| {
+        let mut value = value;
+        rsx! {
+            label {
+                class: "flex items-center gap-2 text-sm text-gray-700",
+                span { class: "w-20", "{label}" },
+                input {
+                    r#type: "range",
+                    class: "flex-1",
+                    min: min,
+                    max: max,
+                    step: "0.01",
+                    value: "{value}",
+                    oninput: move |evt| {
+                        if let Ok(number) = evt.value().parse::<f64>() {
+                            value.set(number);
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "mt-4",
+            label {
+                class: "block text-sm font-medium text-gray-700 mb-1",
+                "Photo (optional)"
+            },
+            p {
+                class: "mb-2 text-xs text-gray-500",
+                "Add a headshot only where employers expect one; many applicant tracking systems reject resumes with photos."
+            },
+
+            if source().is_some() {
+                div {
+                    class: "flex flex-wrap gap-4 items-start p-3 border rounded",
+                    div {
+                        class: "w-24 h-24",
+                        if let Some(preview) = cropped_photo.clone() {
+                            img {
+                                class: photo_class(preview.shape),
+                                src: preview.data_url(),
+                                alt: "Cropped photo"
+                            }
+                        }
+                    },
+                    div {
+                        class: "flex-1 min-w-[12rem] space-y-2",
+                        {slider("Zoom", zoom, "1", "4")},
+                        {slider("Left/right", offset_x, "-1", "1")},
+                        {slider("Up/down", offset_y, "-1", "1")},
+                        label {
+                            class: "flex items-center gap-2 text-sm text-gray-700",
+                            span { class: "w-20", "Shape" },
+                            select {
+                                class: "p-1 border rounded",
+                                value: "{shape().name()}",
+                                onchange: move |evt| {
+                                    if let Some(selected) = PhotoShape::from_name(&evt.value()) {
+                                        shape.set(selected);
+                                    }
+                                },
+                                for option_shape in PhotoShape::all() {
+                                    option { value: "{option_shape.name()}", "{option_shape.name()}" }
+                                }
+                            }
+                        },
+                        div {
+                            class: "flex gap-2",
+                            button {
+                                class: "px-3 py-1 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                                disabled: cropped_photo.is_none(),
+                                onclick: move |_| {
+                                    if let Some(photo) = cropped_photo.clone() {
+                                        on_change.call(Some(photo));
+                                    }
+                                    close_source();
+                                },
+                                "Use Photo"
+                            },
+                            button {
+                                class: "px-3 py-1 bg-gray-200 rounded hover:bg-gray-300 transition-colors",
+                                onclick: move |_| close_source(),
+                                "Cancel"
+                            }
+                        }
+                    }
+                }
+            } else if let Some(current) = photo.clone() {
+                div {
+                    class: "flex flex-wrap gap-4 items-center",
+                    img {
+                        class: photo_class(current.shape),
+                        src: current.data_url(),
+                        alt: "Profile photo"
+                    },
+                    div {
+                        class: "space-y-2",
+                        label {
+                            class: "flex items-center gap-2 text-sm text-gray-700",
+                            "Shape",
+                            select {
+                                class: "p-1 border rounded",
+                                value: "{current.shape.name()}",
+                                onchange: move |evt| {
+                                    if let Some(selected) = PhotoShape::from_name(&evt.value()) {
+                                        on_change.call(Some(ProfilePhoto { shape: selected, ..current.clone() }));
+                                    }
+                                },
+                                for option_shape in PhotoShape::all() {
+                                    option { value: "{option_shape.name()}", "{option_shape.name()}" }
+                                }
+                            }
+                        },
+                        div {
+                            class: "flex items-center gap-2",
+                            label {
+                                class: "px-3 py-1 bg-white border rounded cursor-pointer hover:bg-blue-50 text-sm",
+                                "Replace",
+                                input {
+                                    r#type: "file",
+                                    accept: "image/*",
+                                    class: "hidden",
+                                    onchange: handle_file
+                                }
+                            },
+                            button {
+                                class: "px-3 py-1 bg-red-500 text-white rounded hover:bg-red-600 transition-colors text-sm",
+                                onclick: move |_| on_change.call(None),
+                                "Remove"
+                            }
+                        }
+                    }
+                },
+                if !shown_by_theme {
+                    p {
+                        class: "mt-2 text-sm text-amber-700",
+                        "The selected theme does not show photos."
+                    }
+                }
+            } else {
+                input {
+                    r#type: "file",
+                    accept: "image/*",
+                    class: "text-sm",
+                    onchange: handle_file
+                }
+            },
+
+            if let Some(message) = error() {
+                p {
+                    class: "mt-2 text-sm text-red-600",
+                    "{message}"
+                }
+            }
+        }
+    }
+}
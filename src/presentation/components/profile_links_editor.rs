@@ -0,0 +1,141 @@
+use dioxus::prelude::*;
+use crate::domain::profile_link::{LinkKind, ProfileLink};
+
+/// Editable list of profile links, each with a kind, a URL or handle and an optional label
+#[component]
+pub fn ProfileLinksEditor(
+    links: Vec<ProfileLink>,
+    on_change: EventHandler<Vec<ProfileLink>>
+) -> Element {
+    let count = links.len();
+    let links_for_add = links.clone();
+
+    rsx! {
+        div {
+            class: "mt-4",
+            label {
+                class: "block text-sm font-medium text-gray-700 mb-1",
+                "Links"
+            },
+            div {
+                class: "space-y-2",
+                for (index, link) in links.iter().cloned().enumerate() {
+                    div {
+                        key: "{index}",
+                        class: "p-2 border rounded",
+                        div {
+                            class: "flex flex-wrap gap-2",
+                            select {
+                                class: "p-2 border rounded",
+                                value: "{link.kind.name()}",
+                                onchange: {
+                                    let links = links.clone();
+                                    move |evt: FormEvent| {
+                                        if let Some(kind) = LinkKind::from_name(&evt.value()) {
+                                            let mut updated = links.clone();
+                                            updated[index].kind = kind;
+                                            on_change.call(updated);
+                                        }
+                                    }
+                                },
+                                for kind in LinkKind::all() {
+                                    option { value: "{kind.name()}", "{kind.name()}" }
+                                }
+                            },
+                            input {
+                                class: "flex-1 min-w-[10rem] p-2 border rounded",
+                                value: "{link.value}",
+                                placeholder: link.kind.placeholder(),
+                                oninput: {
+                                    let links = links.clone();
+                                    move |evt: FormEvent| {
+                                        let mut updated = links.clone();
+                                        updated[index].value = evt.value();
+                                        on_change.call(updated);
+                                    }
+                                }
+                            },
+                            input {
+                                class: "w-36 p-2 border rounded",
+                                value: "{link.label}",
+                                placeholder: "Label (optional)",
+                                oninput: {
+                                    let links = links.clone();
+                                    move |evt: FormEvent| {
+                                        let mut updated = links.clone();
+                                        updated[index].label = evt.value();
+                                        on_change.call(updated);
+                                    }
+                                }
+                            },
+                            div {
+                                class: "flex gap-1",
+                                button {
+                                    class: "px-2 py-1 text-gray-600 hover:bg-gray-100 rounded disabled:opacity-30",
+                                    title: "Move up",
+                                    disabled: index == 0,
+                                    onclick: {
+                                        let links = links.clone();
+                                        move |_| {
+                                            let mut updated = links.clone();
+                                            updated.swap(index - 1, index);
+                                            on_change.call(updated);
+                                        }
+                                    },
+                                    "↑"
+                                },
+                                button {
+                                    class: "px-2 py-1 text-gray-600 hover:bg-gray-100 rounded disabled:opacity-30",
+                                    title: "Move down",
+                                    disabled: index + 1 == count,
+                                    onclick: {
+                                        let links = links.clone();
+                                        move |_| {
+                                            let mut updated = links.clone();
+                                            updated.swap(index, index + 1);
+                                            on_change.call(updated);
+                                        }
+                                    },
+                                    "↓"
+                                },
+                                button {
+                                    class: "px-2 py-1 text-red-500 hover:bg-red-50 rounded",
+                                    title: "Remove",
+                                    onclick: {
+                                        let links = links.clone();
+                                        move |_| {
+                                            let mut updated = links.clone();
+                                            updated.remove(index);
+                                            on_change.call(updated);
+                                        }
+                                    },
+                                    "✕"
+                                }
+                            }
+                        },
+                        // Shows what a handle turns into
+                        if !link.is_empty() {
+                            p {
+                                class: "mt-1 text-xs text-gray-500 truncate",
+                                "{link.display_label()} → {link.url()}"
+                            }
+                        }
+                    }
+                }
+            },
+            button {
+                class: "mt-2 px-3 py-1 bg-white text-blue-800 border rounded hover:bg-blue-50 transition-colors text-sm",
+                onclick: move |_| {
+                    let mut updated = links_for_add.clone();
+                    // Suggest the first kind of profile that is not listed yet
+                    let kind = LinkKind::all().into_iter()
+                        .find(|kind| !updated.iter().any(|link| link.kind == *kind))
+                        .unwrap_or_default();
+                    updated.push(ProfileLink::new(kind, ""));
+                    on_change.call(updated);
+                },
+                "Add Link"
+            }
+        }
+    }
+}
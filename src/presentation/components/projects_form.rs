@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
 use crate::domain::models::Project;
+use crate::presentation::components::SkillSuggestions;
 use chrono::NaiveDate;
 
 /// Component for adding, editing, and removing projects in the resume
@@ -9,6 +10,7 @@ pub fn ProjectsForm(
     on_add: EventHandler<Project>,
     on_remove: EventHandler<usize>,
     on_edit: EventHandler<(usize, Project)>,
+    selected: Option<usize>,
 ) -> Element {
     // State for form inputs
     let mut project_name = use_signal(|| String::new());
@@ -24,6 +26,8 @@ pub fn ProjectsForm(
     let projects = projects.clone();
     let projects_for_edit = projects.clone();
     
+    let projects_for_submit = projects.clone();
+
     // Function to handle form submission
     let handle_submit = move |_| {
         // Validation: name should not be empty
@@ -37,7 +41,10 @@ pub fn ProjectsForm(
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty())
             .collect::<Vec<String>>();
-        
+
+        // Visibility and page breaks are kept when an entry is edited
+        let editing = edit_index.read().and_then(|index| projects_for_submit.get(index));
+
         let new_project = Project {
             name: project_name.read().clone(),
             description: project_description.read().clone(),
@@ -46,6 +53,8 @@ pub fn ProjectsForm(
             start_date: start_date.read().parse::<NaiveDate>().ok(),
             end_date: end_date.read().parse::<NaiveDate>().ok(),
             technologies,
+            hidden: editing.is_some_and(|project| project.hidden),
+            page_breaks: editing.map(|project| project.page_breaks).unwrap_or_default(),
         };
         
         // Store index before clearing it
@@ -69,8 +78,7 @@ pub fn ProjectsForm(
     };
     
     // Function to handle editing an existing project
-    let handle_edit = EventHandler::new(move |index: usize| {
-        let project = &projects_for_edit[index];
+    let mut start_edit = move |index: usize, project: &Project| {
         project_name.set(project.name.clone());
         project_description.set(project.description.clone());
         project_role.set(project.role.clone());
@@ -87,7 +95,15 @@ pub fn ProjectsForm(
             .unwrap_or_default());
             
         edit_index.set(Some(index));
-    });
+    };
+    let handle_edit = EventHandler::new(move |index: usize| start_edit(index, &projects_for_edit[index]));
+
+    // Open the project picked in the preview
+    use_effect(use_reactive!(|selected, projects| {
+        if let Some((index, project)) = selected.and_then(|index| projects.get(index).map(|project| (index, project))) {
+            start_edit(index, project);
+        }
+    }));
     
     // Function to handle canceling an edit
     let handle_cancel = move |_| {
@@ -165,8 +181,10 @@ pub fn ProjectsForm(
                         class: "p-2 border rounded-md",
                         placeholder: "e.g. React, Node.js, Docker",
                         value: "{project_technologies}",
+                        list: "technology-suggestions",
                         oninput: move |evt| project_technologies.set(evt.value().clone())
                     }
+                    SkillSuggestions { id: "technology-suggestions", typed: project_technologies(), last_term: true }
                 }
                 
                 div { class: "flex flex-col space-y-1",
@@ -211,7 +229,9 @@ pub fn ProjectsForm(
                         for (i, project) in projects.iter().enumerate() {
                             div {
                                 key: "{i}",
-                                class: "p-4 bg-gray-50 rounded-md",
+                                class: format!("p-4 bg-gray-50 rounded-md {}",
+                                    if project.hidden { "opacity-50" } else { "" }
+                                ),
                                 div { class: "flex justify-between items-start",
                                     div { class: "space-y-1",
                                         h3 { class: "font-medium text-lg", "{project.name}" }
@@ -234,6 +254,35 @@ pub fn ProjectsForm(
                                             onclick: move |_| handle_edit.call(i),
                                             "Edit"
                                         }
+                                        button {
+                                            class: "p-1 text-gray-600 hover:text-gray-800",
+                                            onclick: {
+                                                let mut toggled = project.clone();
+                                                toggled.hidden = !project.hidden;
+                                                move |_| on_edit.call((i, toggled.clone()))
+                                            },
+                                            if project.hidden { "Show" } else { "Hide" }
+                                        }
+                                        button {
+                                            class: if project.page_breaks.keep_together { "p-1 text-indigo-700 font-semibold" } else { "p-1 text-gray-600 hover:text-gray-800" },
+                                            title: "Never split this project across pages",
+                                            onclick: {
+                                                let mut toggled = project.clone();
+                                                toggled.page_breaks.keep_together = !project.page_breaks.keep_together;
+                                                move |_| on_edit.call((i, toggled.clone()))
+                                            },
+                                            "Keep Together"
+                                        }
+                                        button {
+                                            class: if project.page_breaks.new_page { "p-1 text-indigo-700 font-semibold" } else { "p-1 text-gray-600 hover:text-gray-800" },
+                                            title: "Start this project on a new page",
+                                            onclick: {
+                                                let mut toggled = project.clone();
+                                                toggled.page_breaks.new_page = !project.page_breaks.new_page;
+                                                move |_| on_edit.call((i, toggled.clone()))
+                                            },
+                                            "New Page"
+                                        }
                                         button {
                                             class: "p-1 text-red-600 hover:text-red-800",
                                             onclick: move |_| on_remove.call(i),
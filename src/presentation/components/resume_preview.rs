@@ -1,141 +1,425 @@
 use dioxus::prelude::*;
-use crate::domain::models::Resume;
+use crate::application::dates::employer_dates;
+use crate::domain::models::{PhotoShape, Resume, Section};
+use crate::infrastructure::export::date_range;
+use crate::presentation::pagination::{flow_blocks, FlowBlock, PreviewBlock};
+use crate::presentation::components::theme_selector::theme_link_icon;
 
+// A section or one of its items as shown in the preview, used to link it with its form in the editor
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PreviewTarget {
+    pub section: Section,
+    // Position in the section's list; None for the section as a whole
+    pub index: Option<usize>,
+}
+
+impl PreviewTarget {
+    pub fn section(section: Section) -> Self {
+        Self { section, index: None }
+    }
+
+    pub fn item(section: Section, index: usize) -> Self {
+        Self { section, index: Some(index) }
+    }
+
+    // Element id of the target in the preview
+    pub fn preview_id(&self) -> String {
+        match self.index {
+            Some(index) => format!("preview-{}-{}", section_slug(self.section), index),
+            None => format!("preview-{}", section_slug(self.section)),
+        }
+    }
+
+    // Element id of the section's form in the editor
+    pub fn editor_id(&self) -> String {
+        format!("editor-{}", section_slug(self.section))
+    }
+}
+
+fn section_slug(section: Section) -> &'static str {
+    match section {
+        Section::Personal => "personal",
+        Section::Education => "education",
+        Section::Experience => "experience",
+        Section::Skills => "skills",
+        Section::Projects => "projects",
+    }
+}
+
+// Highlight and selection for a preview that is linked with the editor
+#[derive(Clone, Copy, PartialEq)]
+pub struct PreviewLinks {
+    pub highlight: Option<PreviewTarget>,
+    pub on_select: EventHandler<PreviewTarget>,
+}
+
+// Without `on_select` the preview is static, as in exported HTML
 #[component]
 pub fn ResumePreview(
     resume: Resume,
-    theme_bg: &'static str
+    theme_bg: &'static str,
+    highlight: Option<PreviewTarget>,
+    on_select: Option<EventHandler<PreviewTarget>>
 ) -> Element {
+    let links = on_select.map(|on_select| PreviewLinks { highlight, on_select });
+
     rsx! {
         div {
             class: format!("border p-6 bg-white shadow rounded {}", theme_bg),
-            
-            // Personal info
+            for flow in flow_blocks(&resume) {
+                {render_block(&resume, flow, links)}
+            }
+        }
+    }
+}
+
+// Renders one block of the pagination flow
+//
+// Page-break hints are added as print classes so exported HTML breaks pages
+// where the paginated preview does.
+pub fn render_block(resume: &Resume, flow: FlowBlock, links: Option<PreviewLinks>) -> Element {
+    let target_class = move |base: &str, target: PreviewTarget| {
+        let mut class = base.to_string();
+        if flow.new_page {
+            class.push_str(" break-before-page");
+        }
+        if flow.keep_with_next {
+            class.push_str(" break-after-avoid");
+        }
+        if let Some(links) = links {
+            class.push_str(" cursor-pointer rounded hover:bg-blue-50");
+            if links.highlight == Some(target) {
+                class.push_str(" ring-2 ring-blue-400 bg-blue-50 print:ring-0 print:bg-transparent");
+            }
+        }
+        class
+    };
+    let target_id = move |target: PreviewTarget| links.map(|_| target.preview_id());
+    let select = move |target: PreviewTarget| {
+        if let Some(links) = links {
+            links.on_select.call(target);
+        }
+    };
+    let personal = PreviewTarget::section(Section::Personal);
+
+    match flow.block {
+        // Personal info
+        PreviewBlock::Header => {
+            let photo = resume.displayed_photo();
+            let link_icon = theme_link_icon(resume.theme);
+            // The photo sits to the right of the name and contact details
+            let layout = if photo.is_some() { "mb-6 border-b pb-4 flex justify-between items-start gap-4" } else { "mb-6 border-b pb-4" };
+            rsx! {
+                div {
+                    id: target_id(personal),
+                    class: target_class(layout, personal),
+                    onclick: move |_| select(personal),
+                    div {
+                        h1 {
+                            class: "text-2xl font-bold",
+                            "{resume.personal_info.name}"
+                        },
+                        div {
+                            class: "flex flex-wrap gap-2 text-sm text-gray-600",
+                            if !resume.personal_info.email.is_empty() {
+                                span {
+                                    "{resume.personal_info.email}"
+                                }
+                            },
+                            if !resume.personal_info.phone.is_empty() {
+                                span {
+                                    " | {resume.personal_info.phone}"
+                                }
+                            },
+                            if !resume.personal_info.location.is_empty() {
+                                span {
+                                    " | {resume.personal_info.location}"
+                                }
+                            }
+                        },
+                        if resume.personal_info.visible_links().next().is_some() {
+                            div {
+                                class: "mt-1 flex flex-wrap gap-x-4 text-sm text-gray-600",
+                                for link in resume.personal_info.visible_links() {
+                                    a {
+                                        class: "inline-flex items-center gap-1",
+                                        href: link.url(),
+                                        if let Some(icon_class) = link_icon {
+                                            span {
+                                                class: "px-1 rounded border text-xs font-bold {icon_class}",
+                                                title: link.kind.name(),
+                                                "{link.kind.icon()}"
+                                            }
+                                        },
+                                        "{link.display_label()}"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    if let Some(photo) = photo {
+                        img {
+                            class: match photo.shape {
+                                PhotoShape::Square => "w-24 h-24 shrink-0 object-cover rounded",
+                                PhotoShape::Circle => "w-24 h-24 shrink-0 object-cover rounded-full",
+                            },
+                            src: photo.data_url(),
+                            alt: "Photo of {resume.personal_info.name}"
+                        }
+                    }
+                }
+            }
+        },
+
+        PreviewBlock::Summary => rsx! {
             div {
-                class: "mb-6 border-b pb-4",
-                h1 {
-                    class: "text-2xl font-bold",
-                    "{resume.personal_info.name}"
+                class: target_class("mt-4", personal),
+                onclick: move |_| select(personal),
+                h2 {
+                    class: "text-lg font-bold border-b",
+                    "Summary"
                 },
+                p {
+                    class: "mt-2",
+                    "{resume.personal_info.summary}"
+                }
+            }
+        },
+
+        PreviewBlock::Heading(section) => {
+            let target = PreviewTarget::section(section);
+            rsx! {
                 div {
-                    class: "flex flex-wrap gap-2 text-sm text-gray-600",
-                    if !resume.personal_info.email.is_empty() {
-                        span {
-                            "{resume.personal_info.email}"
-                        }
+                    id: target_id(target),
+                    class: target_class("mt-6", target),
+                    onclick: move |_| select(target),
+                    h2 {
+                        class: "text-lg font-bold border-b mb-2",
+                        "{section.title()}"
+                    }
+                }
+            }
+        },
+
+        PreviewBlock::Entry(Section::Education, index) => {
+            let target = PreviewTarget::item(Section::Education, index);
+            let edu = &resume.education[index];
+            rsx! {
+                div {
+                    id: target_id(target),
+                    class: target_class("mt-3", target),
+                    onclick: move |_| select(target),
+                    div {
+                        class: "font-bold",
+                        "{edu.institution}"
                     },
-                    if !resume.personal_info.phone.is_empty() {
-                        span {
-                            " | {resume.personal_info.phone}"
+                    div {
+                        "{edu.degree} in {edu.field_of_study}"
+                    },
+                    div {
+                        class: "text-sm text-gray-600",
+                        "{edu.start_date} - {edu.end_date}"
+                    },
+                    if !edu.location.is_empty() {
+                        div {
+                            class: "text-sm text-gray-600",
+                            "{edu.location}"
                         }
                     },
-                    if !resume.personal_info.location.is_empty() {
-                        span {
-                            " | {resume.personal_info.location}"
+                    if !edu.description.is_empty() {
+                        p {
+                            class: "text-sm mt-1",
+                            "{edu.description}"
                         }
                     }
                 }
-            },
-            
-            // Summary
-            if !resume.personal_info.summary.is_empty() {
+            }
+        },
+
+        PreviewBlock::Entry(Section::Experience, index) => {
+            let target = PreviewTarget::item(Section::Experience, index);
+            let exp = &resume.experience[index];
+            rsx! {
                 div {
-                    class: "mt-4",
-                    h2 {
-                        class: "text-lg font-bold border-b",
-                        "Summary"
+                    id: target_id(target),
+                    class: target_class("mt-3", target),
+                    onclick: move |_| select(target),
+                    div {
+                        class: "font-bold",
+                        "{exp.company}"
                     },
-                    p {
-                        class: "mt-2",
-                        "{resume.personal_info.summary}"
+                    div {
+                        "{exp.position}"
+                    },
+                    div {
+                        class: "text-sm text-gray-600",
+                        if exp.is_current {
+                            "{exp.start_date} - Present"
+                        } else {
+                            "{exp.start_date} - {exp.end_date}"
+                        }
+                    },
+                    if !exp.location.is_empty() {
+                        div {
+                            class: "text-sm text-gray-600",
+                            "{exp.location}"
+                        }
+                    },
+                    if !exp.description.is_empty() {
+                        p {
+                            class: "text-sm mt-1",
+                            "{exp.description}"
+                        }
                     }
                 }
-            },
-            
-            // Education
-            if !resume.education.is_empty() {
+            }
+        },
+
+        // Company block of an employer with several positions, the positions stacked below it
+        PreviewBlock::Employer(index) => {
+            let target = PreviewTarget::item(Section::Experience, index);
+            let employers = resume.employers();
+            let Some(employer) = employers.iter().find(|employer| employer.roles[0].0 == index) else {
+                return rsx! {};
+            };
+            let (start, end, is_current) = employer_dates(employer);
+            let dates = date_range(start, end, is_current);
+            rsx! {
                 div {
-                    class: "mt-6",
-                    h2 {
-                        class: "text-lg font-bold border-b mb-2",
-                        "Education"
+                    class: target_class("mt-3", target),
+                    onclick: move |_| select(target),
+                    div {
+                        class: "flex justify-between gap-2",
+                        span { class: "font-bold", "{employer.company}" }
+                        if !dates.is_empty() {
+                            span { class: "text-sm text-gray-600", "{dates}" }
+                        }
                     },
-                    for edu in resume.education.iter() {
+                    if !employer.location.is_empty() {
                         div {
-                            class: "mt-3",
-                            div {
-                                class: "font-bold",
-                                "{edu.institution}"
-                            },
-                            div {
-                                "{edu.degree} in {edu.field_of_study}"
-                            },
-                            div {
-                                class: "text-sm text-gray-600",
-                                "{edu.start_date} - {edu.end_date}"
-                            },
-                            if !edu.location.is_empty() {
-                                div {
-                                    class: "text-sm text-gray-600",
-                                    "{edu.location}"
-                                }
-                            },
-                            if !edu.description.is_empty() {
-                                p {
-                                    class: "text-sm mt-1",
-                                    "{edu.description}"
-                                }
-                            }
+                            class: "text-sm text-gray-600",
+                            "{employer.location}"
                         }
                     }
                 }
-            },
-            
-            // Experience
-            if !resume.experience.is_empty() {
+            }
+        },
+
+        PreviewBlock::Role(index) => {
+            let target = PreviewTarget::item(Section::Experience, index);
+            let exp = &resume.experience[index];
+            rsx! {
                 div {
-                    class: "mt-6",
-                    h2 {
-                        class: "text-lg font-bold border-b mb-2",
-                        "Experience"
+                    id: target_id(target),
+                    class: target_class("mt-1 ml-3 pl-3 border-l-2 border-gray-300", target),
+                    onclick: move |_| select(target),
+                    div {
+                        class: "font-medium",
+                        "{exp.position}"
+                    },
+                    div {
+                        class: "text-sm text-gray-600",
+                        if exp.is_current {
+                            "{exp.start_date} - Present"
+                        } else {
+                            "{exp.start_date} - {exp.end_date}"
+                        }
                     },
-                    for exp in resume.experience.iter() {
+                    if !exp.description.is_empty() {
+                        p {
+                            class: "text-sm mt-1",
+                            "{exp.description}"
+                        }
+                    }
+                }
+            }
+        },
+
+        // Each bullet is its own list so a long entry can continue on the next page
+        PreviewBlock::Achievement(index, item) => {
+            let target = PreviewTarget::item(Section::Experience, index);
+            // Bullets of a stacked position line up with its title
+            let indent = if is_stacked_role(resume, index) { "ml-11" } else { "ml-5" };
+            let base = if item == 0 { format!("list-disc {} text-sm mt-1", indent) } else { format!("list-disc {} text-sm", indent) };
+            rsx! {
+                ul {
+                    class: target_class(&base, target),
+                    onclick: move |_| select(target),
+                    li {
+                        "{resume.experience[index].achievements[item]}"
+                    }
+                }
+            }
+        },
+
+        PreviewBlock::Entry(Section::Projects, index) => {
+            let target = PreviewTarget::item(Section::Projects, index);
+            let project = &resume.projects[index];
+            rsx! {
+                div {
+                    id: target_id(target),
+                    class: target_class("mt-3", target),
+                    onclick: move |_| select(target),
+                    div {
+                        class: "font-bold",
+                        "{project.name}"
+                    },
+                    if !project.role.is_empty() {
                         div {
-                            class: "mt-3",
-                            div {
-                                class: "font-bold",
-                                "{exp.company}"
-                            },
-                            div {
-                                "{exp.position}"
-                            },
-                            div {
-                                class: "text-sm text-gray-600",
-                                if exp.is_current {
-                                    "{exp.start_date} - Present"
-                                } else {
-                                    "{exp.start_date} - {exp.end_date}"
-                                }
-                            },
-                            if !exp.location.is_empty() {
-                                div {
-                                    class: "text-sm text-gray-600",
-                                    "{exp.location}"
-                                }
-                            },
-                            if !exp.description.is_empty() {
-                                p {
-                                    class: "text-sm mt-1",
-                                    "{exp.description}"
-                                }
-                            },
-                            
-                            if !exp.achievements.is_empty() {
-                                ul {
-                                    class: "list-disc ml-5 text-sm mt-1",
-                                    for achievement in exp.achievements.iter() {
-                                        li {
-                                            "{achievement}"
+                            "{project.role}"
+                        }
+                    },
+                    if !project.url.is_empty() {
+                        div {
+                            class: "text-sm text-gray-600",
+                            "{project.url}"
+                        }
+                    },
+                    if !project.description.is_empty() {
+                        p {
+                            class: "text-sm mt-1",
+                            "{project.description}"
+                        }
+                    },
+                    if !project.technologies.is_empty() {
+                        div {
+                            class: "text-sm text-gray-600 mt-1",
+                            "{project.technologies.join(\", \")}"
+                        }
+                    }
+                }
+            }
+        },
+
+        PreviewBlock::Entry(_, _) => rsx! {},
+
+        // Skills are picked one by one, so the list itself is not a target
+        PreviewBlock::Skills => {
+            let groups = resume.skills.groups();
+            let categorized = groups.iter().any(|group| !group.category.is_empty());
+            let shows_levels = resume.theme.shows_skill_levels();
+            rsx! {
+                for group in groups {
+                    div {
+                        class: "text-sm",
+                        if categorized {
+                            h3 {
+                                class: "mt-2 font-bold",
+                                if group.category.is_empty() { "Other" } else { "{group.category}" }
+                            }
+                        },
+                        div {
+                            class: "mt-1 flex flex-wrap gap-2",
+                            for (index, skill) in group.skills {
+                                span {
+                                    id: target_id(PreviewTarget::item(Section::Skills, index)),
+                                    class: target_class(if skill.featured { "px-2 border rounded font-bold" } else { "px-2 border rounded" }, PreviewTarget::item(Section::Skills, index)),
+                                    onclick: move |_| select(PreviewTarget::item(Section::Skills, index)),
+                                    "{skill.name}",
+                                    if shows_levels && !resume.skills.proficiency(skill).is_empty() {
+                                        span {
+                                            class: "text-xs text-gray-600",
+                                            " · {resume.skills.proficiency(skill)}"
                                         }
                                     }
                                 }
@@ -144,6 +428,12 @@ pub fn ResumePreview(
                     }
                 }
             }
-        }
+        },
     }
 }
+
+// Whether an experience entry is shown as one of several positions under its employer
+fn is_stacked_role(resume: &Resume, index: usize) -> bool {
+    resume.employers().iter()
+        .any(|employer| employer.has_several_roles() && employer.roles.iter().any(|(role, _)| *role == index))
+}
@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+use crate::application::analyze_skills;
+use crate::domain::models::{Resume, Skill};
+
+/// Skills found in the experience, project and education entries: suggestions
+/// for missing skills, listed skills nothing backs up, and when each was last used
+#[component]
+pub fn SkillInsights(
+    resume: Resume,
+    on_add: EventHandler<Skill>,
+    on_edit: EventHandler<(usize, Skill)>,
+) -> Element {
+    let analysis = analyze_skills(&resume, chrono::Local::now().date_naive());
+    let unevidenced: Vec<String> = analysis.unevidenced()
+        .map(|(index, _)| resume.skills.skill_list[*index].name.clone())
+        .collect();
+    let evidenced: Vec<_> = analysis.listed.iter().filter(|(_, evidence)| evidence.is_evidenced()).cloned().collect();
+
+    rsx! {
+        details {
+            class: "mt-4 p-4 bg-white rounded-lg shadow-md",
+            summary {
+                class: "cursor-pointer text-xl font-semibold text-gray-800",
+                "Skill Insights"
+            },
+
+            div {
+                class: "mt-4 space-y-6",
+                div {
+                    h3 { class: "font-medium text-gray-800 mb-2", "Mentioned but not listed" }
+                    if analysis.missing.is_empty() {
+                        p { class: "text-sm text-gray-500 italic", "Every skill your entries mention is listed." }
+                    }
+                    ul {
+                        class: "space-y-2",
+                        for evidence in analysis.missing.iter().cloned() {
+                            li {
+                                key: "{evidence.name}",
+                                class: "flex items-start justify-between gap-2 p-2 bg-gray-50 rounded-md",
+                                div {
+                                    div { class: "font-medium",
+                                        "{evidence.name}"
+                                        if !evidence.category.is_empty() {
+                                            span { class: "ml-2 text-xs text-gray-500", "{evidence.category}" }
+                                        }
+                                    }
+                                    div { class: "text-sm text-gray-500", "{evidence.sources.join(\"; \")}" }
+                                }
+                                button {
+                                    class: "px-3 py-1 bg-blue-600 text-white rounded-md hover:bg-blue-700 text-sm",
+                                    onclick: move |_| on_add.call(Skill {
+                                        name: evidence.name.clone(),
+                                        category: evidence.category.clone(),
+                                        years: evidence.years_used(),
+                                        ..Skill::default()
+                                    }),
+                                    "Add"
+                                }
+                            }
+                        }
+                    }
+                },
+
+                div {
+                    h3 { class: "font-medium text-gray-800 mb-2", "Listed but not backed up" }
+                    if unevidenced.is_empty() {
+                        p { class: "text-sm text-gray-500 italic", "Every listed skill appears in an entry." }
+                    } else {
+                        p {
+                            class: "text-sm text-amber-700",
+                            "No experience, project or education entry mentions: {unevidenced.join(\", \")}"
+                        }
+                    }
+                },
+
+                if !evidenced.is_empty() {
+                    div {
+                        h3 { class: "font-medium text-gray-800 mb-2", "Usage from entry dates" }
+                        table {
+                            class: "w-full text-sm",
+                            thead {
+                                tr {
+                                    class: "text-left text-gray-500",
+                                    th { class: "py-1", "Skill" }
+                                    th { class: "py-1", "Last used" }
+                                    th { class: "py-1", "Used for" }
+                                    th {}
+                                }
+                            }
+                            tbody {
+                                for (index, evidence) in evidenced {
+                                    tr {
+                                        key: "{index}",
+                                        class: "border-t",
+                                        td { class: "py-1", "{evidence.name}" }
+                                        td { class: "py-1", "{evidence.last_used()}" }
+                                        td { class: "py-1", "{evidence.duration()}" }
+                                        td {
+                                            class: "py-1 text-right",
+                                            // Offered when the computed years differ from the ones entered
+                                            if let Some(years) = evidence.years_used().filter(|years| resume.skills.skill_list[index].years != Some(*years)) {
+                                                button {
+                                                    class: "text-blue-600 hover:text-blue-800",
+                                                    onclick: {
+                                                        let mut skill = resume.skills.skill_list[index].clone();
+                                                        skill.years = Some(years);
+                                                        move |_| on_edit.call((index, skill.clone()))
+                                                    },
+                                                    if years == 1 { "Set 1 year" } else { "Set {years} years" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
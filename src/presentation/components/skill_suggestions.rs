@@ -0,0 +1,26 @@
+use dioxus::prelude::*;
+use crate::domain::skill_dictionary::suggest_skills;
+
+// Suggestions offered at a time
+const SUGGESTION_LIMIT: usize = 8;
+
+/// Autocomplete list of dictionary skills for the input whose `list` attribute is `id`;
+/// with `last_term` only the text after the last comma is completed
+#[component]
+pub fn SkillSuggestions(id: String, typed: String, #[props(default)] last_term: bool) -> Element {
+    // Earlier terms of a comma separated list are kept in front of each suggestion
+    let (before, term) = match typed.rfind(',') {
+        Some(comma) if last_term => (format!("{}, ", typed[..comma].trim_end()), &typed[comma + 1..]),
+        _ => (String::new(), typed.as_str()),
+    };
+    let suggestions = suggest_skills(term, SUGGESTION_LIMIT);
+
+    rsx! {
+        datalist {
+            id: "{id}",
+            for skill in suggestions {
+                option { value: "{before}{skill.name}", "{skill.category}" }
+            }
+        }
+    }
+}
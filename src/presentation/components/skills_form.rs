@@ -1,23 +1,70 @@
 use dioxus::prelude::*;
-use crate::domain::models::Skill;
+use crate::domain::models::{Skill, Skills};
+use crate::domain::skill_dictionary::find_known_skill;
+use crate::presentation::components::SkillSuggestions;
+
+// Changes to the skills section as a whole
+enum SectionChange {
+    AddCategory(String),
+    RenameCategory(String, String),
+    RemoveCategory(String),
+    MoveCategory(usize, isize),
+    LevelLabel(usize, String),
+    ResetLevelLabels,
+}
 
 /// Component for adding, editing, and removing skills in the resume
 #[component]
 pub fn SkillsForm(
-    skills: Vec<Skill>,
+    skills: Skills,
     on_add: EventHandler<Skill>,
     on_remove: EventHandler<usize>,
     on_edit: EventHandler<(usize, Skill)>,
+    // Category and proficiency label changes, which touch the whole section
+    on_update: EventHandler<Skills>,
+    selected: Option<usize>,
 ) -> Element {
     // State for form inputs
     let mut skill_name = use_signal(|| String::new());
     let mut skill_level = use_signal(|| 0);
+    let mut skill_category = use_signal(String::new);
+    let mut skill_years = use_signal(String::new);
+    let mut skill_featured = use_signal(|| false);
     let mut edit_index = use_signal(|| Option::<usize>::None);
+    let mut new_category = use_signal(String::new);
     
     // Clone skills for use in closures
-    let skills = skills.clone();
+    let section = skills.clone();
+    let skills = section.skill_list.clone();
     let skills_for_edit = skills.clone();
     
+    let skills_for_submit = skills.clone();
+    let level_name = |level: i32| section.level_label(level).map(str::to_string).unwrap_or_else(|| "Not rated".to_string());
+
+    let section_for_change = section.clone();
+    let change = EventHandler::new(move |change: SectionChange| {
+        let mut updated = section_for_change.clone();
+        match change {
+            SectionChange::AddCategory(name) => updated.add_category(&name),
+            SectionChange::RenameCategory(old, new) => updated.rename_category(&old, &new),
+            SectionChange::RemoveCategory(name) => updated.remove_category(&name),
+            SectionChange::MoveCategory(index, offset) => updated.move_category(index, offset),
+            SectionChange::LevelLabel(index, label) => {
+                if let Some(existing) = updated.level_labels.get_mut(index) {
+                    *existing = label;
+                }
+            }
+            SectionChange::ResetLevelLabels => updated.level_labels = Skills::default_level_labels(),
+        }
+        on_update.call(updated);
+    });
+    let category_count = section.categories.len();
+
+    // Category the dictionary knows the typed skill by, when it is not already picked
+    let suggested_category = find_known_skill(&skill_name())
+        .map(|skill| skill.category)
+        .filter(|category| !category.is_empty() && *category != skill_category());
+
     // Function to handle form submission
     let handle_submit = move |_| {
         // Validation: name should not be empty
@@ -28,6 +75,12 @@ pub fn SkillsForm(
         let new_skill = Skill {
             name: skill_name.read().clone(),
             level: *skill_level.read(),
+            category: skill_category.read().clone(),
+            years: skill_years.read().trim().parse().ok(),
+            featured: *skill_featured.read(),
+            hidden: edit_index.read()
+                .and_then(|index| skills_for_submit.get(index))
+                .is_some_and(|skill| skill.hidden),
         };
         
         // Store index before clearing it
@@ -40,23 +93,37 @@ pub fn SkillsForm(
             on_add.call(new_skill);
         }
         
-        // Reset form
+        // Reset form, keeping the category for the next skill
         skill_name.set(String::new());
         skill_level.set(0);
+        skill_years.set(String::new());
+        skill_featured.set(false);
     };
     
     // Function to handle editing an existing skill
-    let handle_edit = EventHandler::new(move |index: usize| {
-        let skill = &skills_for_edit[index];
+    let mut start_edit = move |index: usize, skill: &Skill| {
         skill_name.set(skill.name.clone());
         skill_level.set(skill.level);
+        skill_category.set(skill.category.clone());
+        skill_years.set(skill.years.map(|years| years.to_string()).unwrap_or_default());
+        skill_featured.set(skill.featured);
         edit_index.set(Some(index));
-    });
+    };
+    let handle_edit = EventHandler::new(move |index: usize| start_edit(index, &skills_for_edit[index]));
+
+    // Open the skill picked in the preview
+    use_effect(use_reactive!(|selected, skills| {
+        if let Some((index, skill)) = selected.and_then(|index| skills.get(index).map(|skill| (index, skill))) {
+            start_edit(index, skill);
+        }
+    }));
     
     // Function to handle canceling an edit
     let handle_cancel = move |_| {
         skill_name.set(String::new());
         skill_level.set(0);
+        skill_years.set(String::new());
+        skill_featured.set(false);
         edit_index.set(None);
     };
     
@@ -72,17 +139,43 @@ pub fn SkillsForm(
                         class: "p-2 border rounded-md",
                         placeholder: "e.g. JavaScript, Project Management, etc.",
                         value: "{skill_name}",
+                        list: "skill-name-suggestions",
                         oninput: move |evt| skill_name.set(evt.value().clone())
                     }
+                    SkillSuggestions { id: "skill-name-suggestions", typed: skill_name() }
                 }
                 
+                div { class: "flex flex-col space-y-1",
+                    label { class: "text-sm font-medium text-gray-700", "Category" }
+                    select {
+                        class: "p-2 border rounded-md",
+                        value: "{skill_category}",
+                        onchange: move |evt| skill_category.set(evt.value()),
+                        option { value: "", "Uncategorized" }
+                        for category in section.categories.iter() {
+                            option { value: "{category}", "{category}" }
+                        }
+                        // A suggested category is added to the section when the skill is saved
+                        if !skill_category().is_empty() && !section.categories.contains(&skill_category()) {
+                            option { value: "{skill_category}", "{skill_category} (new)" }
+                        }
+                    }
+                    if let Some(category) = suggested_category {
+                        button {
+                            class: "self-start text-sm text-blue-600 hover:text-blue-800",
+                            onclick: move |_| skill_category.set(category.to_string()),
+                            "Use suggested category: {category}"
+                        }
+                    }
+                }
+
                 div { class: "flex flex-col space-y-1",
                     label { class: "text-sm font-medium text-gray-700", "Proficiency Level (0-5)" }
                     input {
                         r#type: "range",
                         class: "w-full",
                         min: "0",
-                        max: "5",
+                        max: "{Skills::MAX_LEVEL}",
                         value: "{skill_level}",
                         oninput: move |evt| {
                             if let Ok(level) = evt.value().parse::<i32>() {
@@ -90,7 +183,28 @@ pub fn SkillsForm(
                             }
                         }
                     }
-                    div { class: "text-center font-medium", "{skill_level} / 5" }
+                    div { class: "text-center font-medium", "{skill_level} / 5 · {level_name(skill_level())}" }
+                }
+
+                div { class: "flex items-end gap-4",
+                    div { class: "flex flex-col space-y-1",
+                        label { class: "text-sm font-medium text-gray-700", "Years of Experience" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            class: "w-28 p-2 border rounded-md",
+                            value: "{skill_years}",
+                            oninput: move |evt| skill_years.set(evt.value())
+                        }
+                    }
+                    label { class: "flex items-center gap-2 pb-2 text-sm text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            checked: skill_featured(),
+                            onchange: move |evt| skill_featured.set(evt.checked())
+                        }
+                        "Featured"
+                    }
                 }
                 
                 div { class: "flex space-x-2 mt-4",
@@ -125,9 +239,16 @@ pub fn SkillsForm(
                         for (i, skill) in skills.iter().enumerate() {
                             div {
                                 key: "{i}",
-                                class: "flex items-center justify-between p-3 bg-gray-50 rounded-md",
+                                class: format!("flex items-center justify-between p-3 bg-gray-50 rounded-md {}",
+                                    if skill.hidden { "opacity-50" } else { "" }
+                                ),
                                 div { class: "flex-1",
-                                    div { class: "font-medium", "{skill.name}" }
+                                    div { class: "font-medium",
+                                        "{skill.name}"
+                                        if skill.featured {
+                                            span { class: "ml-2 text-xs text-blue-600", "Featured" }
+                                        }
+                                    }
                                     div { class: "text-sm text-gray-500", 
                                         "Proficiency: ", 
                                         for j in 0..5 {
@@ -137,6 +258,10 @@ pub fn SkillsForm(
                                                 "★"
                                             }
                                         }
+                                        " {section.proficiency(skill)}"
+                                    }
+                                    if !skill.category.is_empty() {
+                                        div { class: "text-xs text-gray-500", "{skill.category}" }
                                     }
                                 }
                                 div { class: "flex space-x-2",
@@ -145,6 +270,15 @@ pub fn SkillsForm(
                                         onclick: move |_| handle_edit.call(i),
                                         "Edit"
                                     }
+                                    button {
+                                        class: "p-1 text-gray-600 hover:text-gray-800",
+                                        onclick: {
+                                            let mut toggled = skill.clone();
+                                            toggled.hidden = !skill.hidden;
+                                            move |_| on_edit.call((i, toggled.clone()))
+                                        },
+                                        if skill.hidden { "Show" } else { "Hide" }
+                                    }
                                     button {
                                         class: "p-1 text-red-600 hover:text-red-800",
                                         onclick: move |_| on_remove.call(i),
@@ -156,6 +290,87 @@ pub fn SkillsForm(
                     }
                 }
             }
+
+            // Categories, in the order themes show them
+            div { class: "space-y-3 md:border-t md:pt-4",
+                h2 { class: "text-xl font-semibold text-gray-800", "Categories" }
+                if section.categories.is_empty() {
+                    div { class: "text-sm text-gray-500 italic", "No categories yet; skills are shown as one list." }
+                }
+                for (index, category) in section.categories.iter().cloned().enumerate() {
+                    div {
+                        key: "{category}",
+                        class: "flex items-center gap-2",
+                        input {
+                            class: "flex-1 p-2 border rounded-md",
+                            value: "{category}",
+                            // Renamed once the field loses focus rather than on every keystroke
+                            onchange: {
+                                let category = category.clone();
+                                move |evt: FormEvent| change.call(SectionChange::RenameCategory(category.clone(), evt.value()))
+                            }
+                        }
+                        button {
+                            class: "px-2 py-1 text-gray-600 hover:bg-gray-100 rounded disabled:opacity-30",
+                            title: "Move up",
+                            disabled: index == 0,
+                            onclick: move |_| change.call(SectionChange::MoveCategory(index, -1)),
+                            "↑"
+                        }
+                        button {
+                            class: "px-2 py-1 text-gray-600 hover:bg-gray-100 rounded disabled:opacity-30",
+                            title: "Move down",
+                            disabled: index + 1 == category_count,
+                            onclick: move |_| change.call(SectionChange::MoveCategory(index, 1)),
+                            "↓"
+                        }
+                        button {
+                            class: "p-1 text-red-600 hover:text-red-800",
+                            title: "Its skills become uncategorized",
+                            onclick: move |_| change.call(SectionChange::RemoveCategory(category.clone())),
+                            "Remove"
+                        }
+                    }
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "flex-1 p-2 border rounded-md",
+                        placeholder: "e.g. Languages, Frameworks, Tools",
+                        value: "{new_category}",
+                        oninput: move |evt| new_category.set(evt.value())
+                    }
+                    button {
+                        class: "px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700",
+                        onclick: move |_| {
+                            change.call(SectionChange::AddCategory(new_category()));
+                            new_category.set(String::new());
+                        },
+                        "Add Category"
+                    }
+                }
+            }
+
+            // Names of the proficiency levels
+            div { class: "space-y-3 md:border-t md:pt-4",
+                h2 { class: "text-xl font-semibold text-gray-800", "Proficiency Labels" }
+                for (index, label) in section.level_labels.iter().cloned().enumerate() {
+                    label {
+                        key: "{index}",
+                        class: "flex items-center gap-2 text-sm text-gray-700",
+                        span { class: "w-16", "Level {index + 1}" }
+                        input {
+                            class: "flex-1 p-2 border rounded-md",
+                            value: "{label}",
+                            oninput: move |evt| change.call(SectionChange::LevelLabel(index, evt.value()))
+                        }
+                    }
+                }
+                button {
+                    class: "text-sm text-blue-600 hover:text-blue-800",
+                    onclick: move |_| change.call(SectionChange::ResetLevelLabels),
+                    "Reset to defaults"
+                }
+            }
         }
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
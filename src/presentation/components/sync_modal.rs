@@ -0,0 +1,127 @@
+use dioxus::prelude::*;
+
+use crate::infrastructure::sync::SyncSettings;
+
+/// Dialog for connecting to a sync server and syncing on demand
+#[component]
+pub fn SyncModal(
+    settings: Option<SyncSettings>,
+    status: String,
+    busy: bool,
+    error: Option<String>,
+    on_close: EventHandler<()>,
+    on_connect: EventHandler<SyncSettings>,
+    on_sync: EventHandler<()>,
+    on_disconnect: EventHandler<()>
+) -> Element {
+    let connected = settings.is_some();
+    let initial = settings.unwrap_or_else(|| SyncSettings {
+        server_url: String::new(),
+        token: String::new(),
+        key: "main".to_string(),
+    });
+    let mut server_url = use_signal(|| initial.server_url.clone());
+    let mut token = use_signal(|| initial.token.clone());
+    let mut key = use_signal(|| initial.key.clone());
+
+    let changed = server_url() != initial.server_url || token() != initial.token || key() != initial.key;
+    let complete = !server_url().trim().is_empty() && !key().trim().is_empty();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            div {
+                class: "bg-white rounded-lg p-6 w-[28rem] shadow-xl",
+                h3 {
+                    class: "text-xl font-bold mb-2",
+                    "Sync"
+                },
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    "Keep this resume in sync with your own server. Changes made offline are pushed once the server is reachable again."
+                },
+
+                label {
+                    class: "block text-sm font-medium mb-1",
+                    "Server URL"
+                },
+                input {
+                    r#type: "url",
+                    class: "w-full p-2 border rounded mb-3",
+                    placeholder: "http://localhost:8787",
+                    value: "{server_url}",
+                    oninput: move |evt| server_url.set(evt.value())
+                },
+                label {
+                    class: "block text-sm font-medium mb-1",
+                    "Access token"
+                },
+                input {
+                    r#type: "password",
+                    class: "w-full p-2 border rounded mb-3",
+                    value: "{token}",
+                    oninput: move |evt| token.set(evt.value())
+                },
+                label {
+                    class: "block text-sm font-medium mb-1",
+                    "Resume key"
+                },
+                input {
+                    class: "w-full p-2 border rounded mb-3",
+                    value: "{key}",
+                    oninput: move |evt| key.set(evt.value())
+                },
+
+                p {
+                    class: "mb-3 text-sm text-gray-700",
+                    "Status: {status}"
+                },
+                if let Some(message) = error {
+                    p {
+                        class: "mb-3 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+
+                div {
+                    class: "flex justify-between",
+                    button {
+                        class: "px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    },
+                    div {
+                        class: "flex gap-2",
+                        if connected {
+                            button {
+                                class: "px-4 py-2 bg-red-500 text-white rounded hover:bg-red-600 transition-colors",
+                                disabled: busy,
+                                onclick: move |_| on_disconnect.call(()),
+                                "Disconnect"
+                            }
+                        }
+                        if connected && !changed {
+                            button {
+                                class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                                disabled: busy,
+                                onclick: move |_| on_sync.call(()),
+                                if busy { "Syncing..." } else { "Sync Now" }
+                            }
+                        } else {
+                            button {
+                                class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                                disabled: busy || !complete,
+                                onclick: move |_| on_connect.call(SyncSettings {
+                                    server_url: server_url().trim().to_string(),
+                                    token: token().trim().to_string(),
+                                    key: key().trim().to_string(),
+                                }),
+                                if busy { "Syncing..." } else { "Save & Sync" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,40 @@
 use dioxus::prelude::*;
+use crate::domain::models::ResumeTheme;
 
 pub type Theme = (&'static str, &'static str);
 
+// Background class used to render a resume theme
+pub fn theme_background(theme: ResumeTheme) -> &'static str {
+    match theme {
+        ResumeTheme::Professional => "bg-blue-50",
+        ResumeTheme::Minimal => "bg-gray-50",
+        ResumeTheme::Creative => "bg-purple-50",
+        ResumeTheme::Modern => "bg-teal-50",
+        ResumeTheme::Executive => "bg-amber-50",
+        ResumeTheme::Technical => "bg-cyan-50",
+    }
+}
+
+// Colour of the link icons in the header; the minimal theme shows links without icons
+pub fn theme_link_icon(theme: ResumeTheme) -> Option<&'static str> {
+    match theme {
+        ResumeTheme::Professional => Some("text-blue-700"),
+        ResumeTheme::Minimal => None,
+        ResumeTheme::Creative => Some("text-purple-700"),
+        ResumeTheme::Modern => Some("text-teal-700"),
+        ResumeTheme::Executive => Some("text-amber-700"),
+        ResumeTheme::Technical => Some("text-cyan-700"),
+    }
+}
+
+// All resume themes paired with their background class
+pub fn available_themes() -> Vec<Theme> {
+    ResumeTheme::all()
+        .into_iter()
+        .map(|theme| (theme.name(), theme_background(theme)))
+        .collect()
+}
+
 #[component]
 pub fn ThemeSelector(
     themes: Vec<Theme>,
@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+/// Full-screen prompt shown while stored data is encrypted and locked
+#[component]
+pub fn UnlockScreen(
+    error: Option<String>,
+    busy: bool,
+    on_unlock: EventHandler<String>,
+    on_cancel: Option<EventHandler<()>>
+) -> Element {
+    let mut passphrase = use_signal(String::new);
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-gray-100 flex items-center justify-center z-50",
+            form {
+                class: "bg-white rounded-lg p-6 w-96 shadow-xl",
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    on_unlock.call(passphrase());
+                },
+                h3 {
+                    class: "text-xl font-bold mb-2",
+                    "Locked"
+                },
+                p {
+                    class: "mb-4 text-sm text-gray-600",
+                    "Your data is encrypted on this device. Enter your passphrase to open it."
+                },
+                input {
+                    r#type: "password",
+                    class: "w-full p-2 border rounded mb-2",
+                    placeholder: "Passphrase",
+                    autofocus: true,
+                    value: "{passphrase}",
+                    oninput: move |evt| passphrase.set(evt.value())
+                },
+                if let Some(message) = error {
+                    p {
+                        class: "mb-2 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: "w-full mt-2 px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors",
+                    disabled: busy || passphrase().is_empty(),
+                    if busy { "Unlocking..." } else { "Unlock" }
+                }
+                if let Some(on_cancel) = on_cancel {
+                    button {
+                        r#type: "button",
+                        class: "w-full mt-2 px-4 py-2 bg-gray-300 rounded hover:bg-gray-400 transition-colors",
+                        onclick: move |_| on_cancel.call(()),
+                        "Back to Resumes"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use dioxus::prelude::*;
+use crate::application::validation::ValidationIssue;
+use super::resume_preview::PreviewTarget;
+
+/// Warnings about the resume; clicking one opens the entry it is about
+#[component]
+pub fn ValidationPanel(issues: Vec<ValidationIssue>, on_select: EventHandler<PreviewTarget>) -> Element {
+    if issues.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        details {
+            class: "p-4 bg-amber-50 border border-amber-200 rounded-lg",
+            summary {
+                class: "cursor-pointer font-semibold text-amber-800",
+                if issues.len() == 1 { "1 thing to check" } else { "{issues.len()} things to check" }
+            },
+            ul {
+                class: "mt-2 space-y-1 text-sm",
+                for (position, issue) in issues.into_iter().enumerate() {
+                    li {
+                        key: "{position}",
+                        button {
+                            class: "text-left text-amber-900 hover:underline",
+                            onclick: move |_| on_select.call(PreviewTarget { section: issue.section, index: issue.index }),
+                            "{issue.message}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
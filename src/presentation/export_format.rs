@@ -0,0 +1,112 @@
+use std::error::Error;
+use crate::domain::models::{PaperSize, Resume};
+use crate::infrastructure::export::{
+    export_docx, export_latex, export_markdown, export_plain_text, export_typst, LatexTemplate,
+    DEFAULT_TEXT_WIDTH,
+};
+use crate::presentation::html_export::export_html;
+
+const PHOTO_WARNING: &str = "This export includes your profile photo. Many applicant tracking systems \
+    reject or misread resumes with photos, so leave it out unless the employer expects one.";
+
+// Formats offered in the export dialog
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ExportFormat {
+    Pdf,
+    Html,
+    Docx,
+    Markdown,
+    PlainText { width: usize },
+    Latex { template: LatexTemplate },
+    Typst { paper: PaperSize },
+}
+
+impl ExportFormat {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Pdf,
+            Self::Html,
+            Self::Docx,
+            Self::Markdown,
+            Self::PlainText { width: DEFAULT_TEXT_WIDTH },
+            Self::Latex { template: LatexTemplate::default() },
+            Self::Typst { paper: PaperSize::default() },
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pdf => "PDF",
+            Self::Html => "HTML",
+            Self::Docx => "Word (DOCX)",
+            Self::Markdown => "Markdown",
+            Self::PlainText { .. } => "Plain text",
+            Self::Latex { .. } => "LaTeX",
+            Self::Typst { .. } => "Typst",
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Pdf => "resume.pdf",
+            Self::Html => "resume.html",
+            Self::Docx => "resume.docx",
+            Self::Markdown => "resume.md",
+            Self::PlainText { .. } => "resume.txt",
+            Self::Latex { .. } => "resume.tex",
+            Self::Typst { .. } => "resume.typ",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Html => "text/html",
+            Self::Docx => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            Self::Markdown => "text/markdown",
+            Self::PlainText { .. } => "text/plain",
+            Self::Latex { .. } => "application/x-tex",
+            Self::Typst { .. } => "text/x-typst",
+        }
+    }
+
+    // Formats that carry the profile photo when the resume's theme shows it
+    pub fn includes_photo(&self) -> bool {
+        matches!(self, Self::Pdf | Self::Html | Self::Docx)
+    }
+
+    // Warning for exports that would contain the profile photo
+    pub fn photo_warning(&self, resume: &Resume) -> Option<&'static str> {
+        (self.includes_photo() && resume.displayed_photo().is_some()).then_some(PHOTO_WARNING)
+    }
+
+    // Text formats that make sense to paste into a job portal
+    pub fn supports_copy(&self) -> bool {
+        matches!(self, Self::Markdown | Self::PlainText { .. })
+    }
+
+    // Renders the resume in this format as file contents
+    pub fn render(&self, resume: &Resume) -> Result<Vec<u8>, Box<dyn Error>> {
+        match self {
+            Self::Pdf => Err("PDF export is produced by the browser print dialog".into()),
+            Self::Html => Ok(export_html(resume).into_bytes()),
+            Self::Docx => export_docx(resume),
+            Self::Markdown => Ok(export_markdown(resume).into_bytes()),
+            Self::PlainText { width } => Ok(export_plain_text(resume, *width).into_bytes()),
+            Self::Latex { template } => Ok(export_latex(resume, *template).into_bytes()),
+            Self::Typst { paper } => Ok(export_typst(resume, *paper).into_bytes()),
+        }
+    }
+
+    // Renders text formats for pasting, `None` for binary or external formats
+    pub fn render_text(&self, resume: &Resume) -> Option<String> {
+        match self {
+            Self::Html => Some(export_html(resume)),
+            Self::Markdown => Some(export_markdown(resume)),
+            Self::PlainText { width } => Some(export_plain_text(resume, *width)),
+            Self::Latex { template } => Some(export_latex(resume, *template)),
+            Self::Typst { paper } => Some(export_typst(resume, *paper)),
+            Self::Pdf | Self::Docx => None,
+        }
+    }
+}
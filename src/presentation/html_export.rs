@@ -0,0 +1,223 @@
+use dioxus::prelude::*;
+use crate::domain::models::Resume;
+use crate::presentation::pagination::PAGE_MARGIN_MM;
+use crate::presentation::components::resume_preview::ResumePreviewProps;
+use crate::presentation::components::ResumePreview;
+use crate::presentation::components::theme_selector::theme_background;
+
+// Standalone HTML export
+//
+// The resume is rendered with the same `ResumePreview` component used in the
+// app, then only the CSS for the utility classes that actually appear in the
+// markup is inlined, so the file works without Tailwind or the Dioxus runtime.
+
+// Base rules that replace the parts of Tailwind's preflight the preview relies on
+const BASE_CSS: &str = "\
+*,*::before,*::after{box-sizing:border-box;border:0 solid #e5e7eb}
+html{-webkit-text-size-adjust:100%}
+body{margin:0;font-family:ui-sans-serif,system-ui,-apple-system,\"Segoe UI\",Roboto,\"Helvetica Neue\",Arial,sans-serif;line-height:1.5;color:#111827;background:#f3f4f6}
+h1,h2,h3,p,ul{margin:0}
+h1,h2,h3{font-size:inherit;font-weight:inherit}
+ul{padding:0}
+a{color:inherit;text-decoration:inherit}
+img{display:block;max-width:100%}
+.page{max-width:800px;margin:2rem auto}";
+
+// Print rules so the exported page prints as a clean document; the page size
+// and margins are added by `print_css`
+const PRINT_CSS: &str = "\
+@media print{
+body{background:#fff}
+.page{max-width:none;margin:0}
+.shadow{box-shadow:none}
+.border{border-width:0}
+h2{break-after:avoid}
+.page>div>*{break-inside:avoid}
+}";
+
+// Utility classes understood by the exporter, in Tailwind's cascade order
+const UTILITY_CSS: &[(&str, &str)] = &[
+    ("flex", "display:flex"),
+    ("inline-flex", "display:inline-flex"),
+    ("flex-wrap", "flex-wrap:wrap"),
+    ("shrink-0", "flex-shrink:0"),
+    ("items-start", "align-items:flex-start"),
+    ("items-center", "align-items:center"),
+    ("justify-between", "justify-content:space-between"),
+    ("gap-1", "gap:0.25rem"),
+    ("gap-2", "gap:0.5rem"),
+    ("gap-4", "gap:1rem"),
+    ("gap-x-4", "column-gap:1rem"),
+    ("object-cover", "object-fit:cover"),
+    ("w-24", "width:6rem"),
+    ("h-24", "height:6rem"),
+    ("break-before-page", "break-before:page"),
+    ("break-after-avoid", "break-after:avoid"),
+    ("list-disc", "list-style-type:disc"),
+    ("rounded", "border-radius:0.25rem"),
+    ("rounded-full", "border-radius:9999px"),
+    ("border", "border-width:1px"),
+    ("border-b", "border-bottom-width:1px"),
+    ("border-l-2", "border-left-width:2px"),
+    ("border-gray-300", "border-color:#d1d5db"),
+    ("bg-white", "background-color:#fff"),
+    ("bg-blue-50", "background-color:#eff6ff"),
+    ("bg-gray-50", "background-color:#f9fafb"),
+    ("bg-purple-50", "background-color:#faf5ff"),
+    ("bg-teal-50", "background-color:#f0fdfa"),
+    ("bg-amber-50", "background-color:#fffbeb"),
+    ("bg-cyan-50", "background-color:#ecfeff"),
+    ("p-6", "padding:1.5rem"),
+    ("px-1", "padding-left:0.25rem;padding-right:0.25rem"),
+    ("px-2", "padding-left:0.5rem;padding-right:0.5rem"),
+    ("pl-3", "padding-left:0.75rem"),
+    ("pb-4", "padding-bottom:1rem"),
+    ("mb-2", "margin-bottom:0.5rem"),
+    ("mb-6", "margin-bottom:1.5rem"),
+    ("ml-3", "margin-left:0.75rem"),
+    ("ml-5", "margin-left:1.25rem"),
+    ("ml-11", "margin-left:2.75rem"),
+    ("mt-1", "margin-top:0.25rem"),
+    ("mt-2", "margin-top:0.5rem"),
+    ("mt-3", "margin-top:0.75rem"),
+    ("mt-4", "margin-top:1rem"),
+    ("mt-6", "margin-top:1.5rem"),
+    ("text-2xl", "font-size:1.5rem;line-height:2rem"),
+    ("text-lg", "font-size:1.125rem;line-height:1.75rem"),
+    ("text-sm", "font-size:0.875rem;line-height:1.25rem"),
+    ("text-xs", "font-size:0.75rem;line-height:1rem"),
+    ("font-medium", "font-weight:500"),
+    ("font-bold", "font-weight:700"),
+    ("text-gray-600", "color:#4b5563"),
+    ("text-blue-700", "color:#1d4ed8"),
+    ("text-purple-700", "color:#7e22ce"),
+    ("text-teal-700", "color:#0f766e"),
+    ("text-amber-700", "color:#b45309"),
+    ("text-cyan-700", "color:#0e7490"),
+    ("shadow", "box-shadow:0 1px 3px 0 rgb(0 0 0 / 0.1),0 1px 2px -1px rgb(0 0 0 / 0.1)"),
+];
+
+// Renders a resume as a self-contained HTML document using its selected theme
+pub fn export_html(resume: &Resume) -> String {
+    let body = render_preview(resume);
+    let css = inline_css(&body, resume);
+    let title = escape_html(&resume.personal_info.name);
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\n\
+         <title>{title} - Resume</title>\n<style>\n{css}\n</style>\n</head>\n\
+         <body>\n<main class=\"page\">{body}</main>\n</body>\n</html>\n"
+    )
+}
+
+// Renders the preview component to static markup
+fn render_preview(resume: &Resume) -> String {
+    let props = ResumePreviewProps {
+        resume: resume.clone(),
+        theme_bg: theme_background(resume.theme),
+        highlight: None,
+        on_select: None,
+    };
+    let mut dom = VirtualDom::new_with_props(ResumePreview, props);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+// Builds a stylesheet containing only the utility classes used in the markup
+fn inline_css(markup: &str, resume: &Resume) -> String {
+    let used = used_classes(markup);
+    let mut css = String::from(BASE_CSS);
+    css.push('\n');
+    for (class, declarations) in UTILITY_CSS {
+        if used.iter().any(|name| name == class) {
+            css.push_str(&format!(".{}{{{}}}\n", class, declarations));
+        }
+    }
+    css.push_str(&print_css(resume));
+    css
+}
+
+// Prints on the resume's paper size with the margins of the paginated preview
+fn print_css(resume: &Resume) -> String {
+    let (width, height) = resume.paper_size.dimensions_mm();
+    format!("@page{{size:{}mm {}mm;margin:{}mm}}\n{}", width, height, PAGE_MARGIN_MM, PRINT_CSS)
+}
+
+// Collects every class name that appears in a `class` attribute
+fn used_classes(markup: &str) -> Vec<String> {
+    let mut classes: Vec<String> = Vec::new();
+    for attribute in markup.split("class=\"").skip(1) {
+        let value = attribute.split('"').next().unwrap_or_default();
+        for class in value.split_whitespace() {
+            if !classes.iter().any(|existing| existing == class) {
+                classes.push(class.to_string());
+            }
+        }
+    }
+    classes
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Education, Experience, PersonalInfo, Section};
+
+    fn resume() -> Resume {
+        let job = |company: &str, hidden: bool| Experience {
+            company: company.to_string(),
+            position: "Engineer".to_string(),
+            start_date: "2020-01".to_string(),
+            end_date: "2021-12".to_string(),
+            hidden,
+            ..Experience::default()
+        };
+        Resume {
+            personal_info: PersonalInfo {
+                name: "Ada <Lovelace> & Co".to_string(),
+                summary: "Writes <script>alert(\"hi\")</script> safely".to_string(),
+                ..PersonalInfo::default()
+            },
+            education: vec![Education { institution: "University of London".to_string(), degree: "B.Sc.".to_string(), ..Education::default() }],
+            experience: vec![job("Analytical Engines", false), job("Secret Project", true)],
+            section_order: vec![Section::Experience, Section::Education],
+            ..Resume::default()
+        }
+    }
+
+    #[test]
+    fn user_text_is_escaped() {
+        let html = export_html(&resume());
+        assert!(html.contains("<title>Ada &lt;Lovelace&gt; &amp; Co - Resume</title>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<Lovelace>"));
+    }
+
+    #[test]
+    fn hidden_entries_are_left_out_and_sections_follow_the_resume_order() {
+        let html = export_html(&resume());
+        assert!(!html.contains("Secret Project"));
+        let experience = html.find("Analytical Engines").unwrap();
+        let education = html.find("University of London").unwrap();
+        assert!(experience < education);
+    }
+
+    #[test]
+    fn only_used_utility_classes_are_inlined() {
+        let resume = resume();
+        let used = used_classes(&render_preview(&resume));
+        let css = inline_css(&render_preview(&resume), &resume);
+        assert!(css.contains("@page{size:"));
+        for (class, _) in UTILITY_CSS {
+            assert_eq!(css.contains(&format!("\n.{}{{", class)), used.iter().any(|name| name == class), "{class}");
+        }
+    }
+}
@@ -1,7 +1,8 @@
 // Presentation layer - contains UI components and view models
 pub mod components;
+pub mod browser;
+pub mod html_export;
+pub mod export_format;
+pub mod pagination;
+pub mod routes;
 
-// We'll keep the module with just the components
-pub mod prelude {
-    pub use super::components::*;
-} 
\ No newline at end of file
@@ -0,0 +1,239 @@
+use std::ops::Range;
+
+use crate::domain::models::{PageBreaks, PaperSize, Resume, Section};
+
+// Pagination model shared by the paginated preview, printing and exports
+//
+// The resume is laid out as a flow of blocks: the header, a section heading,
+// an entry, one achievement bullet, and so on. A block is never split between
+// pages. Two flags describe where breaks may go: `keep_with_next` glues a
+// block to the one after it (headings to their first entry, and every block
+// of an entry marked "keep together"), and `new_page` forces a break before
+// the block. The preview measures each block in the browser and fills pages
+// with `paginate`; exports translate the same flags into CSS or Typst rules.
+
+// Margin on every side of a printed page
+pub const PAGE_MARGIN_MM: f64 = 15.0;
+
+// CSS pixels per millimetre
+pub const PX_PER_MM: f64 = 96.0 / 25.4;
+
+// One piece of the resume that is placed on a page as a whole
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PreviewBlock {
+    // Name and contact details
+    Header,
+    Summary,
+    Heading(Section),
+    // Title, dates and description of an entry in a section list
+    Entry(Section, usize),
+    // Company of an employer with several positions, by the experience index of its first shown position
+    Employer(usize),
+    // One position under such an employer: title, dates and description without the company
+    Role(usize),
+    // One bullet of an experience entry: (experience index, achievement index)
+    Achievement(usize, usize),
+    Skills,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FlowBlock {
+    pub block: PreviewBlock,
+    pub keep_with_next: bool,
+    pub new_page: bool,
+}
+
+// Size of the printable area inside the margins
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PageArea {
+    pub width_mm: f64,
+    pub height_mm: f64,
+}
+
+impl PageArea {
+    pub fn for_paper(paper: PaperSize) -> Self {
+        let (width, height) = paper.dimensions_mm();
+        Self {
+            width_mm: width - 2.0 * PAGE_MARGIN_MM,
+            height_mm: height - 2.0 * PAGE_MARGIN_MM,
+        }
+    }
+
+    pub fn height_px(&self) -> f64 {
+        self.height_mm * PX_PER_MM
+    }
+}
+
+// Blocks of a resume in reading order; hidden entries and empty sections are left out
+pub fn flow_blocks(resume: &Resume) -> Vec<FlowBlock> {
+    let mut flow = vec![plain(PreviewBlock::Header)];
+
+    for section in resume.sections() {
+        match section {
+            Section::Personal => {
+                if !resume.personal_info.summary.is_empty() {
+                    flow.push(plain(PreviewBlock::Summary));
+                }
+            }
+            Section::Education => {
+                let entries = visible(resume.education.iter().map(|edu| (edu.hidden, edu.page_breaks, 0)));
+                push_section(&mut flow, section, &entries);
+            }
+            Section::Experience => push_experience(&mut flow, resume),
+            Section::Skills => {
+                if resume.skills.skill_list.iter().any(|skill| !skill.hidden) {
+                    flow.push(heading(section));
+                    flow.push(plain(PreviewBlock::Skills));
+                }
+            }
+            Section::Projects => {
+                let entries = visible(resume.projects.iter().map(|project| (project.hidden, project.page_breaks, 0)));
+                push_section(&mut flow, section, &entries);
+            }
+        }
+    }
+    flow
+}
+
+// Splits the flow into pages, given the measured height of every block
+pub fn paginate(flow: &[FlowBlock], heights: &[f64], page_height: f64) -> Vec<Range<usize>> {
+    let mut pages = Vec::new();
+    let mut page = 0..0;
+    let mut used = 0.0;
+
+    for unit in units(flow) {
+        let new_page = flow[unit.clone()].iter().any(|block| block.new_page);
+        // A group taller than a page has to be split after all
+        let pieces: Vec<Range<usize>> = if total_height(heights, &unit) > page_height {
+            unit.map(|index| index..index + 1).collect()
+        } else {
+            vec![unit]
+        };
+
+        for (offset, piece) in pieces.into_iter().enumerate() {
+            let height = total_height(heights, &piece);
+            if !page.is_empty() && ((new_page && offset == 0) || used + height > page_height) {
+                pages.push(page.clone());
+                page = piece.start..piece.start;
+                used = 0.0;
+            }
+            page.end = piece.end;
+            used += height;
+        }
+    }
+    if !page.is_empty() || pages.is_empty() {
+        pages.push(page);
+    }
+    pages
+}
+
+fn total_height(heights: &[f64], blocks: &Range<usize>) -> f64 {
+    heights[blocks.clone()].iter().sum()
+}
+
+// Runs of blocks that are glued together by `keep_with_next`
+fn units(flow: &[FlowBlock]) -> Vec<Range<usize>> {
+    let mut units = Vec::new();
+    let mut start = 0;
+    for (index, block) in flow.iter().enumerate() {
+        if !block.keep_with_next || index + 1 == flow.len() {
+            units.push(start..index + 1);
+            start = index + 1;
+        }
+    }
+    units
+}
+
+// (list index, page breaks, achievement count) of the entries that are shown
+fn visible(entries: impl Iterator<Item = (bool, PageBreaks, usize)>) -> Vec<(usize, PageBreaks, usize)> {
+    entries.enumerate()
+        .filter(|(_, (hidden, _, _))| !hidden)
+        .map(|(index, (_, page_breaks, achievements))| (index, page_breaks, achievements))
+        .collect()
+}
+
+fn push_section(flow: &mut Vec<FlowBlock>, section: Section, entries: &[(usize, PageBreaks, usize)]) {
+    if entries.is_empty() {
+        return;
+    }
+    flow.push(heading(section));
+
+    for (position, (index, page_breaks, achievements)) in entries.iter().enumerate() {
+        // The heading moves along with a first entry that starts a new page
+        if page_breaks.new_page && position == 0 {
+            move_break_to_last(flow);
+        }
+        push_entry(flow, PreviewBlock::Entry(section, *index), *index, *page_breaks, *achievements, position > 0);
+    }
+}
+
+// Employers with several positions get a company block, glued to their first position
+fn push_experience(flow: &mut Vec<FlowBlock>, resume: &Resume) {
+    let employers = resume.employers();
+    if employers.is_empty() {
+        return;
+    }
+    flow.push(heading(Section::Experience));
+
+    for (position, employer) in employers.iter().enumerate() {
+        let (first, first_role) = employer.roles[0];
+        if first_role.page_breaks.new_page && position == 0 {
+            move_break_to_last(flow);
+        }
+        if !employer.has_several_roles() {
+            push_entry(flow, PreviewBlock::Entry(Section::Experience, first), first, first_role.page_breaks, first_role.achievements.len(), position > 0);
+            continue;
+        }
+        flow.push(FlowBlock {
+            block: PreviewBlock::Employer(first),
+            keep_with_next: true,
+            new_page: first_role.page_breaks.new_page && position > 0,
+        });
+        for (role, (index, exp)) in employer.roles.iter().enumerate() {
+            push_entry(flow, PreviewBlock::Role(*index), *index, exp.page_breaks, exp.achievements.len(), role > 0);
+        }
+    }
+}
+
+// An entry's block and its bullets; `may_break` is false when the block
+// before it already carries the entry's page break
+fn push_entry(flow: &mut Vec<FlowBlock>, block: PreviewBlock, index: usize, page_breaks: PageBreaks, achievements: usize, may_break: bool) {
+    let first = flow.len();
+    flow.push(FlowBlock {
+        block,
+        // Never leave an entry's title alone at the bottom of a page
+        keep_with_next: achievements > 0,
+        new_page: page_breaks.new_page && may_break,
+    });
+    for item in 0..achievements {
+        flow.push(plain(PreviewBlock::Achievement(index, item)));
+    }
+    if page_breaks.keep_together {
+        let last = flow.len() - 1;
+        for block in &mut flow[first..last] {
+            block.keep_with_next = true;
+        }
+    }
+}
+
+fn move_break_to_last(flow: &mut [FlowBlock]) {
+    if let Some(block) = flow.last_mut() {
+        block.new_page = true;
+    }
+}
+
+fn heading(section: Section) -> FlowBlock {
+    FlowBlock {
+        block: PreviewBlock::Heading(section),
+        keep_with_next: true,
+        new_page: false,
+    }
+}
+
+fn plain(block: PreviewBlock) -> FlowBlock {
+    FlowBlock {
+        block,
+        keep_with_next: false,
+        new_page: false,
+    }
+}
@@ -0,0 +1,134 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::{ApplicationTrackerUseCase, ResumeLibraryUseCase};
+#[cfg(all(feature = "sqlite", not(feature = "web")))]
+use crate::application::repository::{ApplicationRepository, LibraryRepository};
+#[cfg(not(feature = "web"))]
+use crate::domain::{JobApplication, LibraryEntry};
+use crate::infrastructure::*;
+use crate::presentation::components::UnlockScreen;
+#[cfg(feature = "web")]
+use crate::presentation::routes::context::{APPLICATIONS_KEY, LIBRARY_KEY};
+use crate::presentation::routes::context::AppContext;
+use crate::presentation::routes::Route;
+
+/// Layout around every page: sets up shared state and shows the top navigation
+#[component]
+pub fn AppShell() -> Element {
+    let keyring = use_hook(|| Rc::new(Keyring::new()));
+
+    // The browser can encrypt the library and the applications with the resumes
+    #[cfg(feature = "web")]
+    let encrypted_lists = use_hook(|| {
+        [LIBRARY_KEY, APPLICATIONS_KEY]
+            .map(|key| Rc::new(EncryptedListRepository::new(Rc::new(LocalStorageResumeRepository::new(key)), keyring.clone())))
+            .to_vec()
+    });
+    #[cfg(feature = "web")]
+    let (library_repository, application_repository) = (encrypted_lists[0].clone(), encrypted_lists[1].clone());
+    #[cfg(not(feature = "web"))]
+    let encrypted_lists = Vec::new();
+
+    #[cfg(all(feature = "sqlite", not(feature = "web")))]
+    let (library_repository, application_repository) = use_hook(open_database);
+
+    #[cfg(not(any(feature = "web", feature = "sqlite")))]
+    let (library_repository, application_repository) = (
+        Rc::new(InMemoryLibraryRepository::new(use_signal(Vec::<LibraryEntry>::new))),
+        Rc::new(InMemoryApplicationRepository::new(use_signal(Vec::<JobApplication>::new))),
+    );
+
+    let app = use_hook(move || {
+        let mut app = AppContext::new(
+            Rc::new(ResumeLibraryUseCase::new(library_repository)),
+            Rc::new(ApplicationTrackerUseCase::new(application_repository)),
+            keyring,
+            encrypted_lists,
+        );
+        if !app.is_locked() {
+            app.reload();
+        }
+        app
+    });
+    use_context_provider(|| app.clone());
+    let mut locked = use_signal(|| app.is_locked());
+    let mut unlock_error = use_signal(|| None::<String>);
+
+    let route = use_route::<Route>();
+    let nav_class = |active: bool| {
+        format!("px-4 py-2 rounded transition-colors duration-300 {}",
+            if active { "bg-blue-800 text-white" } else { "bg-white text-blue-800 hover:bg-blue-50" }
+        )
+    };
+
+    rsx! {
+        div {
+            class: "min-h-screen bg-gray-100 print:bg-white",
+            div {
+                class: "container mx-auto p-4 max-w-5xl lg:max-w-7xl print:p-0 print:max-w-none",
+                div {
+                    class: "flex flex-wrap justify-between items-center gap-2 mb-6 print:hidden",
+                    Link {
+                        class: "text-3xl font-bold text-blue-800",
+                        to: Route::Library {},
+                        "Resume Builder"
+                    },
+                    nav {
+                        class: "flex items-center gap-2",
+                        Link {
+                            class: nav_class(!matches!(route, Route::Applications {})),
+                            to: Route::Library {},
+                            "Resumes"
+                        },
+                        Link {
+                            class: nav_class(matches!(route, Route::Applications {})),
+                            to: Route::Applications {},
+                            "Applications"
+                        }
+                    }
+                },
+                if locked() {
+                    UnlockScreen {
+                        error: unlock_error(),
+                        busy: false,
+                        on_unlock: move |passphrase: String| {
+                            let mut app = app.clone();
+                            match app.unlock(&passphrase) {
+                                Ok(()) => {
+                                    unlock_error.set(None);
+                                    locked.set(false);
+                                }
+                                Err(err) => unlock_error.set(Some(err.to_string())),
+                            }
+                        },
+                        on_cancel: None
+                    }
+                } else {
+                    Outlet::<Route> {}
+                }
+            }
+        }
+    }
+}
+
+// The desktop app keeps its library and applications in the SQLite database
+// next to the resumes, falling back to memory when it cannot be opened
+#[cfg(all(feature = "sqlite", not(feature = "web")))]
+fn open_database() -> (Rc<dyn LibraryRepository>, Rc<dyn ApplicationRepository>) {
+    use crate::infrastructure::sqlite::{default_database_path, SqliteApplicationRepository, SqliteLibraryRepository};
+    let path = default_database_path();
+    let repositories = SqliteLibraryRepository::open(&path)
+        .and_then(|library| Ok((library, SqliteApplicationRepository::open(&path)?)));
+    match repositories {
+        Ok((library, applications)) => (Rc::new(library), Rc::new(applications)),
+        Err(err) => {
+            tracing::error!("Error opening {}, changes will not be kept: {}", path.display(), err);
+            (
+                Rc::new(InMemoryLibraryRepository::new(Signal::new_in_scope(Vec::<LibraryEntry>::new(), ScopeId::ROOT))),
+                Rc::new(InMemoryApplicationRepository::new(Signal::new_in_scope(Vec::<JobApplication>::new(), ScopeId::ROOT))),
+            )
+        }
+    }
+}
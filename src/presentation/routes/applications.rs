@@ -0,0 +1,62 @@
+use dioxus::prelude::*;
+
+use crate::presentation::browser::download_text;
+use crate::presentation::components::ApplicationTracker;
+use crate::presentation::routes::context::AppContext;
+
+/// Job applications across all resumes
+#[component]
+pub fn Applications() -> Element {
+    let app = use_context::<AppContext>();
+    let applications = app.applications;
+    let last_opened = app.last_opened;
+
+    rsx! {
+        ApplicationTracker {
+            applications: applications(),
+            current_resume: last_opened(),
+            on_add: {
+                let mut app = app.clone();
+                move |application| {
+                    let mut updated = applications();
+                    app.tracker_use_case.add_application(&mut updated, application);
+                    app.save_applications(updated);
+                }
+            },
+            on_update: {
+                let mut app = app.clone();
+                move |application| {
+                    let mut updated = applications();
+                    if app.tracker_use_case.update_application(&mut updated, application).is_ok() {
+                        app.save_applications(updated);
+                    }
+                }
+            },
+            on_status_change: {
+                let mut app = app.clone();
+                move |(id, status)| {
+                    let mut updated = applications();
+                    if app.tracker_use_case.change_status(&mut updated, id, status).is_ok() {
+                        app.save_applications(updated);
+                    }
+                }
+            },
+            on_remove: {
+                let mut app = app.clone();
+                move |id| {
+                    let mut updated = applications();
+                    if app.tracker_use_case.remove_application(&mut updated, id).is_ok() {
+                        app.save_applications(updated);
+                    }
+                }
+            },
+            on_export_csv: {
+                let app = app.clone();
+                move |_| {
+                    let csv = app.tracker_use_case.export_csv(&applications());
+                    download_text("applications.csv", "text/csv", &csv);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::repository::DocumentStore;
+use crate::application::{ApplicationTrackerUseCase, ResumeLibraryUseCase, ResumeUseCase};
+use crate::domain::{JobApplication, LibraryEntry, Resume, Workspace};
+use crate::infrastructure::*;
+use crate::presentation::components::PassphraseChange;
+
+// localStorage keys of the library and of the tracked applications
+#[cfg(feature = "web")]
+pub const LIBRARY_KEY: &str = "resume-library";
+#[cfg(feature = "web")]
+pub const APPLICATIONS_KEY: &str = "resume-applications";
+
+// State shared by every page: the resume library, the tracked applications
+// and the storage of every resume opened so far
+#[derive(Clone)]
+pub struct AppContext {
+    pub library: Signal<Vec<LibraryEntry>>,
+    pub library_use_case: Rc<ResumeLibraryUseCase>,
+    pub applications: Signal<Vec<JobApplication>>,
+    pub tracker_use_case: Rc<ApplicationTrackerUseCase>,
+    // Resume last opened in the editor; new applications attach a snapshot of it
+    pub last_opened: Signal<Option<Resume>>,
+    // Passphrase and keys shared by every encrypted store
+    pub keyring: Rc<Keyring>,
+    // Library and application stores that can be encrypted, empty where encryption is unavailable
+    encrypted_lists: Vec<Rc<EncryptedListRepository>>,
+    storages: Rc<RefCell<HashMap<u64, Rc<ResumeStorage>>>>,
+}
+
+impl AppContext {
+    // The library and applications are loaded by `reload` once they can be read
+    pub fn new(
+        library_use_case: Rc<ResumeLibraryUseCase>,
+        tracker_use_case: Rc<ApplicationTrackerUseCase>,
+        keyring: Rc<Keyring>,
+        encrypted_lists: Vec<Rc<EncryptedListRepository>>,
+    ) -> Self {
+        Self {
+            library: Signal::new(Vec::new()),
+            library_use_case,
+            applications: Signal::new(Vec::new()),
+            tracker_use_case,
+            last_opened: Signal::new(None),
+            keyring,
+            encrypted_lists,
+            storages: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    pub fn reload(&mut self) {
+        let library = self.library_use_case.load_entries().unwrap_or_else(|err| {
+            tracing::error!("Error loading resume library: {}", err);
+            Vec::new()
+        });
+        let applications = self.tracker_use_case.load_applications().unwrap_or_else(|err| {
+            tracing::error!("Error loading applications: {}", err);
+            Vec::new()
+        });
+        self.library.set(library);
+        self.applications.set(applications);
+    }
+
+    // Whether the library or the applications are encrypted and wait for the passphrase
+    pub fn is_locked(&self) -> bool {
+        self.encrypted_lists.iter().any(|list| matches!(list.state(), Ok(EncryptionState::Locked)))
+    }
+
+    // Unlocks the library and the applications with the passphrase and loads them
+    pub fn unlock(&mut self, passphrase: &str) -> Result<(), Box<dyn Error>> {
+        for list in &self.encrypted_lists {
+            if list.state()? == EncryptionState::Locked {
+                list.unlock(passphrase)?;
+            }
+        }
+        self.reload();
+        Ok(())
+    }
+
+    // Turns encryption of everything stored in the browser on or off, or changes its passphrase
+    pub async fn change_encryption(&self, change: &PassphraseChange) -> Result<(), Box<dyn Error>> {
+        let stores = self.encrypted_stores()?;
+        match change {
+            PassphraseChange::Encrypt { passphrase } => self.keyring.encrypt(&stores, passphrase).await,
+            PassphraseChange::Change { current, new } => self.keyring.change_passphrase(&stores, current, new).await,
+            PassphraseChange::Decrypt { current } => self.keyring.decrypt(&stores, current).await,
+        }
+    }
+
+    // Raw documents of the library, the applications and every resume in it
+    #[cfg(feature = "web")]
+    fn encrypted_stores(&self) -> Result<Vec<Rc<dyn DocumentStore>>, Box<dyn Error>> {
+        let mut stores: Vec<Rc<dyn DocumentStore>> = vec![
+            Rc::new(LocalStorageResumeRepository::new(LIBRARY_KEY)),
+            Rc::new(LocalStorageResumeRepository::new(APPLICATIONS_KEY)),
+        ];
+        for entry in self.library.read().iter() {
+            let keys = ResumeStorage::keys(entry.id);
+            stores.push(Rc::new(IndexedDbResumeRepository::new("resume-builder", &keys.0)));
+        }
+        Ok(stores)
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn encrypted_stores(&self) -> Result<Vec<Rc<dyn DocumentStore>>, Box<dyn Error>> {
+        Err("Encryption is only available in the browser".into())
+    }
+
+    // Storage of one resume; it is opened once and then kept, so its sync
+    // state and unsaved draft last while the user moves between pages
+    pub fn storage(&self, id: u64) -> Rc<ResumeStorage> {
+        self.storages
+            .borrow_mut()
+            .entry(id)
+            .or_insert_with(|| Rc::new(ResumeStorage::open(id, &self.keyring)))
+            .clone()
+    }
+
+    pub fn entry(&self, id: u64) -> Option<LibraryEntry> {
+        self.library.read().iter().find(|entry| entry.id == id).cloned()
+    }
+
+    // Persists the library after every change
+    pub fn save_library(&mut self, updated: Vec<LibraryEntry>) {
+        if let Err(err) = self.library_use_case.save_entries(&updated) {
+            tracing::error!("Error saving resume library: {}", err);
+        }
+        self.library.set(updated);
+    }
+
+    // Persists tracked applications after every change
+    pub fn save_applications(&mut self, updated: Vec<JobApplication>) {
+        if let Err(err) = self.tracker_use_case.save_applications(&updated) {
+            tracing::error!("Error saving applications: {}", err);
+        }
+        self.applications.set(updated);
+    }
+
+    // Removes a resume from the library together with its stored data
+    pub fn remove_resume(&mut self, id: u64) -> Result<(), &'static str> {
+        let mut updated = self.library.read().clone();
+        self.library_use_case.remove_entry(&mut updated, id)?;
+        self.save_library(updated);
+        self.delete_storage(id);
+        Ok(())
+    }
+
+    // Every resume in the library as last saved, with the tracked applications
+    pub async fn load_workspace(&self) -> Result<Workspace, Box<dyn Error>> {
+        let library = self.library.read().clone();
+        let mut resumes = BTreeMap::new();
+        for entry in &library {
+            let resume = self.storage(entry.id).use_case.load_resume().await
+                .map_err(|err| format!("Could not read \"{}\": {}", entry.title, err))?;
+            resumes.insert(entry.id, resume);
+        }
+        Ok(Workspace { library, resumes, applications: self.applications.read().clone() })
+    }
+
+    // Adds the restored resumes that are not in the library yet as new
+    // entries and merges the tracked applications; returns how many resumes were added
+    pub async fn merge_workspace(&mut self, restored: Workspace) -> Result<usize, Box<dyn Error>> {
+        let current = self.load_workspace().await?;
+        let mut library = current.library;
+        let mut added = 0;
+        for entry in &restored.library {
+            let Some(resume) = restored.resumes.get(&entry.id) else {
+                continue;
+            };
+            if current.resumes.values().any(|existing| existing == resume) {
+                continue;
+            }
+            let id = self.library_use_case.add_entry(&mut library, &entry.title);
+            if let Err(err) = self.storage(id).use_case.save_resume(resume).await {
+                library.pop();
+                self.save_library(library);
+                return Err(format!("Could not restore \"{}\": {}", entry.title, err).into());
+            }
+            added += 1;
+        }
+        self.save_library(library);
+
+        let mut applications = self.applications.read().clone();
+        self.tracker_use_case.merge_applications(&mut applications, restored.applications);
+        self.save_applications(applications);
+        Ok(added)
+    }
+
+    // Replaces the library, every resume and the tracked applications with a restored backup
+    pub async fn replace_workspace(&mut self, restored: Workspace) -> Result<(), Box<dyn Error>> {
+        for entry in &restored.library {
+            let Some(resume) = restored.resumes.get(&entry.id) else {
+                continue;
+            };
+            let storage = self.storage(entry.id);
+            storage.use_case.save_resume(resume).await
+                .map_err(|err| format!("Could not restore \"{}\": {}", entry.title, err))?;
+            storage.draft.replace(None);
+        }
+        let removed: Vec<u64> = self.library.read().iter()
+            .map(|entry| entry.id)
+            .filter(|id| !restored.library.iter().any(|entry| entry.id == *id))
+            .collect();
+        for id in removed {
+            self.delete_storage(id);
+        }
+        self.save_library(restored.library);
+        self.save_applications(restored.applications);
+        Ok(())
+    }
+
+    // Forgets the storage of a resume and deletes its stored data
+    fn delete_storage(&self, id: u64) {
+        let storage = self.storage(id);
+        self.storages.borrow_mut().remove(&id);
+        spawn(async move {
+            if let Err(err) = storage.remove().await {
+                tracing::error!("Error removing resume data: {}", err);
+            }
+        });
+    }
+}
+
+// Repositories of one resume in the library
+//
+// The synced repository holds its conflict, so it lives as long as the app. The first resume keeps the
+// storage keys used before the library existed.
+pub struct ResumeStorage {
+    pub use_case: Rc<ResumeUseCase>,
+    pub encryption: Option<Rc<EncryptedResumeRepository>>,
+    pub sync: Option<Rc<SyncedResumeRepository>>,
+    // Unsaved edits, kept while other pages are open
+    pub draft: RefCell<Option<Resume>>,
+    #[cfg(any(feature = "web", feature = "sqlite"))]
+    keys: (String, String),
+}
+
+impl ResumeStorage {
+    // Keys of the stored resume and of its sync state
+    #[cfg(any(feature = "web", feature = "sqlite"))]
+    fn keys(id: u64) -> (String, String) {
+        match id {
+            crate::domain::DEFAULT_RESUME_ID => ("resume-data".to_string(), "resume-sync-state".to_string()),
+            id => (format!("resume-data-{}", id), format!("resume-sync-state-{}", id)),
+        }
+    }
+
+    #[cfg(feature = "web")]
+    fn open(id: u64, keyring: &Rc<Keyring>) -> Self {
+        let keys = Self::keys(id);
+        let encrypted = Rc::new(EncryptedResumeRepository::new(
+            Rc::new(IndexedDbResumeRepository::new("resume-builder", &keys.0)),
+            keyring.clone(),
+        ));
+        let synced = Rc::new(SyncedResumeRepository::new(
+            encrypted.clone(),
+            Rc::new(LocalStorageResumeRepository::new(&keys.1)),
+            HttpSyncApi::connect,
+        ));
+        Self {
+            use_case: Rc::new(ResumeUseCase::new(synced.clone())),
+            encryption: Some(encrypted),
+            sync: Some(synced),
+            draft: RefCell::new(None),
+            keys,
+        }
+    }
+
+    // Desktop builds keep every resume in the SQLite library, falling back to
+    // memory when the database cannot be opened
+    #[cfg(all(feature = "sqlite", not(feature = "web")))]
+    fn open(id: u64, _keyring: &Rc<Keyring>) -> Self {
+        let keys = Self::keys(id);
+        let repository: Rc<dyn crate::application::repository::AsyncResumeRepository> =
+            match sqlite::SqliteResumeRepository::open(sqlite::default_database_path(), &keys.0) {
+                Ok(repository) => Rc::new(repository),
+                Err(err) => {
+                    tracing::error!("Error opening resume database, changes will not be kept: {}", err);
+                    Rc::new(InMemoryResumeRepository::new(Signal::new_in_scope(None, ScopeId::ROOT)))
+                }
+            };
+        Self {
+            use_case: Rc::new(ResumeUseCase::new(repository)),
+            encryption: None,
+            sync: None,
+            draft: RefCell::new(None),
+            keys,
+        }
+    }
+
+    #[cfg(not(any(feature = "web", feature = "sqlite")))]
+    fn open(_id: u64, _keyring: &Rc<Keyring>) -> Self {
+        let repository = InMemoryResumeRepository::new(Signal::new_in_scope(None, ScopeId::ROOT));
+        Self {
+            use_case: Rc::new(ResumeUseCase::new(Rc::new(repository))),
+            encryption: None,
+            sync: None,
+            draft: RefCell::new(None),
+        }
+    }
+
+    // Deletes the stored resume and its sync bookkeeping
+    #[cfg(feature = "web")]
+    async fn remove(&self) -> Result<(), Box<dyn Error>> {
+        IndexedDbResumeRepository::new("resume-builder", &self.keys.0).remove().await?;
+        LocalStorageResumeRepository::new(&self.keys.1).remove()
+    }
+
+    // Deletes the resume with its snapshots from the SQLite library
+    #[cfg(all(feature = "sqlite", not(feature = "web")))]
+    async fn remove(&self) -> Result<(), Box<dyn Error>> {
+        sqlite::SqliteResumeRepository::open(sqlite::default_database_path(), &self.keys.0)?.delete()
+    }
+
+    #[cfg(not(any(feature = "web", feature = "sqlite")))]
+    async fn remove(&self) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+use dioxus::prelude::*;
+
+use crate::application::entry_order::{sort_section, sorted_position};
+use crate::application::timeline::DEFAULT_GAP_MONTHS;
+use crate::application::validation::validate_resume;
+use crate::domain::{normalize_skills, EntryOrder, ResumeTheme, Section, Skill};
+use crate::presentation::components::*;
+use crate::presentation::routes::workspace::use_session;
+use crate::presentation::routes::Route;
+
+/// Section forms with the live preview beside them on wide screens
+#[component]
+pub fn Editor(id: u64) -> Element {
+    let session = use_session(id);
+    let mut resume = session.resume;
+    let mut import_review = session.import_review;
+    let start_merge = session.start_merge;
+    let mark_edited = session.mark_edited;
+    let select_item = session.select_item;
+    let selected_item = session.selected_item;
+    let preview_highlight = session.preview_highlight;
+    let navigator = use_navigator();
+    // Outcome of the last "Normalize Skills" run
+    let mut normalize_note = use_signal(|| Option::<String>::None);
+    // Employment gaps up to this many months are not reported
+    let mut gap_months = use_signal(|| DEFAULT_GAP_MONTHS);
+
+    // The selected theme is stored on the resume itself so exports can use it
+    let themes: Vec<Theme> = available_themes();
+    let selected_theme = move || {
+        ResumeTheme::all()
+            .iter()
+            .position(|theme| *theme == resume().theme)
+            .unwrap_or_default()
+    };
+
+    // Skills come from the skills form and from the insights below it; a new
+    // category is added to the section along with its first skill
+    let add_skill = move |skill: Skill| {
+        let mut updated_resume = resume();
+        updated_resume.skills.add_category(&skill.category);
+        updated_resume.skills.skill_list.push(skill);
+        mark_edited.call(PreviewTarget::item(Section::Skills, updated_resume.skills.skill_list.len() - 1));
+        resume.set(updated_resume);
+    };
+    let edit_skill = move |(index, skill): (usize, Skill)| {
+        let mut updated_resume = resume();
+        updated_resume.skills.add_category(&skill.category);
+        if let Some(existing) = updated_resume.skills.skill_list.get_mut(index) {
+            *existing = skill;
+        }
+        resume.set(updated_resume);
+        mark_edited.call(PreviewTarget::item(Section::Skills, index));
+    };
+
+    // Education and experience are sorted newest first unless ordered by hand
+    let mut change_entry_order = move |section: Section, order: EntryOrder| {
+        let mut updated_resume = resume();
+        updated_resume.set_entry_order(section, order);
+        sort_section(&mut updated_resume, section);
+        resume.set(updated_resume);
+        mark_edited.call(PreviewTarget::section(section));
+    };
+    let mut move_entry = move |section: Section, (index, offset): (usize, isize)| {
+        let mut updated_resume = resume();
+        let moved_to = updated_resume.move_entry(section, index, offset);
+        resume.set(updated_resume);
+        mark_edited.call(PreviewTarget::item(section, moved_to));
+    };
+
+    // Function to handle section drag
+    let mut handle_section_drag = move |from: usize, to: usize| {
+        let mut updated_resume = resume();
+        let mut new_order = updated_resume.sections();
+        let section = new_order.remove(from);
+        new_order.insert(to, section);
+        updated_resume.section_order = new_order;
+        resume.set(updated_resume);
+    };
+
+    rsx! {
+        // Switches to the preview page when the screen is too narrow for both
+        div {
+            class: "lg:hidden mb-4 flex justify-end print:hidden",
+            ToggleButton {
+                is_preview_mode: false,
+                on_toggle: move |preview: bool| {
+                    if preview {
+                        navigator.push(Route::Preview { id });
+                    }
+                }
+            }
+        }
+
+        // Editor and live preview side by side
+        div {
+            class: "lg:grid lg:grid-cols-2 lg:gap-6 lg:items-start print:block",
+            div {
+                class: "space-y-4 print:hidden",
+                if let Some((previous, fields)) = import_review() {
+                    ImportReview {
+                        fields: fields,
+                        on_accept: move |_| import_review.set(None),
+                        on_merge: move |_| start_merge.call((previous.clone(), resume())),
+                        on_discard: move |_| {
+                            if let Some((previous, _)) = import_review() {
+                                resume.set(previous);
+                            }
+                            import_review.set(None);
+                        }
+                    }
+                }
+
+                ValidationPanel {
+                    issues: validate_resume(&resume(), chrono::Local::now().date_naive(), gap_months()),
+                    on_select: move |target| select_item.call(target)
+                }
+
+                p {
+                    class: "text-gray-700 italic mb-4",
+                    "Tip: Drag and drop sections to reorder them in your resume"
+                },
+
+                // Render each section in the user-defined order
+                for (index, section) in resume().sections().into_iter().enumerate() {
+                    DraggableSection {
+                        anchor: PreviewTarget::section(section).editor_id(),
+                        index: index,
+                        total_sections: resume().sections().len(),
+                        on_move_up: if index > 0 {
+                            Some(EventHandler::new(move |_| handle_section_drag(index, index - 1)))
+                        } else {
+                            None
+                        },
+                        on_move_down: if index < resume().sections().len() - 1 {
+                            Some(EventHandler::new(move |_| handle_section_drag(index, index + 1)))
+                        } else {
+                            None
+                        },
+                        match section {
+                            Section::Personal => rsx! {
+                                PersonalInfoForm {
+                                    personal_info: resume().personal_info,
+                                    shows_photo: resume().theme.shows_photo(),
+                                    on_change: move |info| {
+                                        let mut updated_resume = resume();
+                                        updated_resume.personal_info = info;
+                                        resume.set(updated_resume);
+                                        mark_edited.call(PreviewTarget::section(Section::Personal));
+                                    }
+                                }
+                            },
+                            Section::Education => rsx! {
+                                div {
+                                    h2 {
+                                        class: "text-xl font-bold mb-4",
+                                        "Education"
+                                    },
+
+                                    EducationForm {
+                                        selected: selected_item().filter(|target| target.section == Section::Education).and_then(|target| target.index),
+                                        education_list: resume().education.clone(),
+                                        order: resume().education_order,
+                                        on_order_change: move |order| change_entry_order(Section::Education, order),
+                                        on_move: move |change| move_entry(Section::Education, change),
+                                        on_add: move |edu| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.education.push(edu);
+                                            let order = sort_section(&mut updated_resume, Section::Education);
+                                            mark_edited.call(PreviewTarget::item(Section::Education, sorted_position(&order, updated_resume.education.len() - 1)));
+                                            resume.set(updated_resume);
+                                        },
+                                        on_update: move |(index, edu)| {
+                                            let mut updated_resume = resume();
+                                            if let Some(existing_edu) = updated_resume.education.get_mut(index) {
+                                                *existing_edu = edu;
+                                            }
+                                            let order = sort_section(&mut updated_resume, Section::Education);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::item(Section::Education, sorted_position(&order, index)));
+                                        },
+                                        on_remove: move |index| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.education.remove(index);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::section(Section::Education));
+                                        },
+                                        on_edit: move |_index| {
+                                            // Handled within EducationForm
+                                        }
+                                    }
+                                }
+                            },
+                            Section::Experience => rsx! {
+                                div {
+                                    h2 {
+                                        class: "text-xl font-bold mb-4",
+                                        "Work Experience"
+                                    },
+
+                                    ExperienceForm {
+                                        selected: selected_item().filter(|target| target.section == Section::Experience).and_then(|target| target.index),
+                                        experience_list: resume().experience.clone(),
+                                        order: resume().experience_order,
+                                        on_order_change: move |order| change_entry_order(Section::Experience, order),
+                                        on_move: move |change| move_entry(Section::Experience, change),
+                                        on_merge: move |(index, target)| {
+                                            let mut updated_resume = resume();
+                                            let merged = updated_resume.merge_experience(index, target);
+                                            let order = sort_section(&mut updated_resume, Section::Experience);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::item(Section::Experience, sorted_position(&order, merged)));
+                                        },
+                                        on_split: move |index| {
+                                            let mut updated_resume = resume();
+                                            let split = updated_resume.split_experience(index);
+                                            let order = sort_section(&mut updated_resume, Section::Experience);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::item(Section::Experience, sorted_position(&order, split)));
+                                        },
+                                        on_add: move |exp| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.experience.push(exp);
+                                            let order = sort_section(&mut updated_resume, Section::Experience);
+                                            mark_edited.call(PreviewTarget::item(Section::Experience, sorted_position(&order, updated_resume.experience.len() - 1)));
+                                            resume.set(updated_resume);
+                                        },
+                                        on_update: move |(index, exp)| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.update_experience(index, exp);
+                                            let order = sort_section(&mut updated_resume, Section::Experience);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::item(Section::Experience, sorted_position(&order, index)));
+                                        },
+                                        on_remove: move |index| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.remove_experience(index);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::section(Section::Experience));
+                                        },
+                                        on_edit: move |_index| {
+                                            // Handled within ExperienceForm
+                                        }
+                                    },
+
+                                    CareerTimeline {
+                                        resume: resume(),
+                                        gap_months: gap_months(),
+                                        on_gap_change: move |months| gap_months.set(months),
+                                        on_select: move |target| select_item.call(target)
+                                    }
+                                }
+                            },
+                            Section::Skills => rsx! {
+                                div {
+                                    div {
+                                        class: "flex flex-wrap items-center justify-between gap-2 mb-4",
+                                        h2 {
+                                            class: "text-xl font-bold",
+                                            "Skills"
+                                        },
+                                        div {
+                                            class: "flex items-center gap-2",
+                                            if let Some(note) = normalize_note() {
+                                                span { class: "text-sm text-gray-600", "{note}" }
+                                            }
+                                            button {
+                                                class: "px-3 py-1 bg-white text-blue-800 border rounded hover:bg-blue-50 transition-colors text-sm",
+                                                title: "Use the usual spelling of known skills and technologies and merge duplicates",
+                                                onclick: move |_| {
+                                                    let mut updated_resume = resume();
+                                                    let changes = normalize_skills(&mut updated_resume);
+                                                    if !changes.is_empty() {
+                                                        resume.set(updated_resume);
+                                                        mark_edited.call(PreviewTarget::section(Section::Skills));
+                                                    }
+                                                    normalize_note.set(Some(changes.summary()));
+                                                },
+                                                "Normalize Skills"
+                                            }
+                                        }
+                                    },
+
+                                    SkillsForm {
+                                        selected: selected_item().filter(|target| target.section == Section::Skills).and_then(|target| target.index),
+                                        skills: resume().skills.clone(),
+                                        on_add: add_skill,
+                                        on_edit: edit_skill,
+                                        on_remove: move |index| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.skills.skill_list.remove(index);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::section(Section::Skills));
+                                        },
+                                        on_update: move |skills| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.skills = skills;
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::section(Section::Skills));
+                                        }
+                                    },
+
+                                    SkillInsights {
+                                        resume: resume(),
+                                        on_add: add_skill,
+                                        on_edit: edit_skill
+                                    }
+                                }
+                            },
+                            Section::Projects => rsx! {
+                                div {
+                                    h2 {
+                                        class: "text-xl font-bold mb-4",
+                                        "Projects"
+                                    },
+
+                                    ProjectsForm {
+                                        selected: selected_item().filter(|target| target.section == Section::Projects).and_then(|target| target.index),
+                                        projects: resume().projects.clone(),
+                                        on_add: move |project| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.projects.push(project);
+                                            mark_edited.call(PreviewTarget::item(Section::Projects, updated_resume.projects.len() - 1));
+                                            resume.set(updated_resume);
+                                        },
+                                        on_edit: move |(index, project)| {
+                                            let mut updated_resume = resume();
+                                            if let Some(existing) = updated_resume.projects.get_mut(index) {
+                                                *existing = project;
+                                            }
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::item(Section::Projects, index));
+                                        },
+                                        on_remove: move |index| {
+                                            let mut updated_resume = resume();
+                                            updated_resume.projects.remove(index);
+                                            resume.set(updated_resume);
+                                            mark_edited.call(PreviewTarget::section(Section::Projects));
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "hidden lg:block lg:sticky lg:top-4 print:block print:static",
+                div {
+                    class: "print:hidden",
+                    ThemeSelector {
+                        themes: themes.clone(),
+                        selected_theme: selected_theme(),
+                        on_theme_select: move |index: usize| {
+                            let mut updated_resume = resume();
+                            updated_resume.theme = ResumeTheme::all()[index];
+                            resume.set(updated_resume);
+                        }
+                    }
+                }
+
+                div {
+                    "data-scroll-container": "true",
+                    class: "lg:max-h-[calc(100vh-8rem)] lg:overflow-y-auto print:max-h-none print:overflow-visible",
+                    PagedPreview {
+                        resume: resume(),
+                        theme_bg: themes[selected_theme()].1,
+                        highlight: preview_highlight(),
+                        on_select: move |target| select_item.call(target),
+                        on_paper_change: move |paper| {
+                            let mut updated_resume = resume();
+                            updated_resume.paper_size = paper;
+                            resume.set(updated_resume);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
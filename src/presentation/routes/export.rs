@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::presentation::browser::{copy_to_clipboard, download_bytes, print_document};
+use crate::presentation::components::theme_selector::theme_background;
+use crate::presentation::components::{ExportPanel, PagedPreview};
+use crate::presentation::export_format::ExportFormat;
+use crate::presentation::routes::workspace::use_session;
+
+/// Export options next to the pages that are printed as PDF
+#[component]
+pub fn Export(id: u64) -> Element {
+    let mut resume = use_session(id).resume;
+
+    let theme = resume().theme;
+    let theme_bg = theme_background(theme);
+
+    // Function for handling export downloads
+    let download_export = move |format: ExportFormat| {
+        match format {
+            // The pages beside the options are what gets printed
+            ExportFormat::Pdf => print_document(),
+            _ => match format.render(&resume()) {
+                Ok(contents) => download_bytes(format.file_name(), format.mime_type(), &contents),
+                Err(err) => tracing::error!("Error exporting resume: {}", err),
+            },
+        }
+    };
+
+    // Function for copying text exports to the clipboard
+    let copy_export = move |format: ExportFormat| {
+        if let Some(contents) = format.render_text(&resume()) {
+            copy_to_clipboard(&contents);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "lg:grid lg:grid-cols-[24rem_1fr] lg:gap-6 lg:items-start print:block",
+            div {
+                class: "mb-6 print:hidden",
+                ExportPanel {
+                    theme_name: theme.name().to_string(),
+                    resume: resume(),
+                    on_download: download_export,
+                    on_copy: copy_export
+                }
+            }
+            PagedPreview {
+                resume: resume(),
+                theme_bg: theme_bg,
+                highlight: None,
+                on_select: None,
+                on_paper_change: move |paper| {
+                    let mut updated_resume = resume();
+                    updated_resume.paper_size = paper;
+                    resume.set(updated_resume);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use dioxus::prelude::*;
+
+use crate::domain::sample_data::sample_resume;
+use crate::domain::Workspace;
+use crate::infrastructure::backup::{encrypt_backup, export_backup};
+use crate::presentation::browser::download_bytes;
+use crate::presentation::components::{BackupModal, PassphraseChange, PassphraseModal};
+use crate::presentation::routes::context::AppContext;
+use crate::presentation::routes::Route;
+
+/// All resumes on this device, with a form for starting a new one, a backup of all of them and their encryption
+#[component]
+pub fn Library() -> Element {
+    let app = use_context::<AppContext>();
+    let navigator = use_navigator();
+    let mut new_title = use_signal(String::new);
+    let mut from_example = use_signal(|| false);
+    let mut show_backup_modal = use_signal(|| false);
+    // Outcome of the last backup or restore, and whether it failed
+    let mut backup_message = use_signal(|| Option::<(String, bool)>::None);
+
+    // Encryption covers the library, the applications and every resume at once
+    let mut is_encrypted = use_signal(|| app.keyring.is_unlocked());
+    let mut show_passphrase_modal = use_signal(|| false);
+    let mut passphrase_error = use_signal(|| Option::<String>::None);
+    let mut encryption_busy = use_signal(|| false);
+
+    let mut app_create = app.clone();
+    let create_resume = move |evt: FormEvent| {
+        evt.prevent_default();
+        let mut updated = app_create.library.read().clone();
+        let id = app_create.library_use_case.add_entry(&mut updated, new_title().trim());
+        app_create.save_library(updated);
+        // The example is an unsaved draft until the user saves it
+        if from_example() {
+            app_create.storage(id).draft.replace(Some(sample_resume()));
+        }
+        new_title.set(String::new());
+        navigator.push(Route::Editor { id });
+    };
+
+    // Downloads every resume with all tracked applications as a backup
+    // archive, sealed when a passphrase was given
+    let app_backup = app.clone();
+    let download_backup = move |passphrase: Option<String>| {
+        let app = app_backup.clone();
+        spawn(async move {
+            let backup = app.load_workspace().await
+                .and_then(|workspace| export_backup(&workspace))
+                .and_then(|archive| match &passphrase {
+                    Some(passphrase) => encrypt_backup(&archive, passphrase),
+                    None => Ok(archive),
+                });
+            match backup {
+                Ok(bytes) => {
+                    let date = chrono::Local::now().format("%Y-%m-%d");
+                    match passphrase {
+                        Some(_) => download_bytes(&format!("resume-backup-{}.enc", date), "application/octet-stream", &bytes),
+                        None => download_bytes(&format!("resume-backup-{}.zip", date), "application/zip", &bytes),
+                    }
+                    backup_message.set(None);
+                }
+                Err(err) => backup_message.set(Some((format!("Could not create the backup: {}", err), true))),
+            }
+        });
+    };
+
+    let app_merge = app.clone();
+    let merge_backup = move |restored: Workspace| {
+        let mut app = app_merge.clone();
+        spawn(async move {
+            match app.merge_workspace(restored).await {
+                Ok(added) => backup_message.set(Some((format!("Restored {} resumes and merged the tracked applications.", added), false))),
+                Err(err) => backup_message.set(Some((err.to_string(), true))),
+            }
+        });
+    };
+
+    let app_replace = app.clone();
+    let replace_backup = move |restored: Workspace| {
+        let mut app = app_replace.clone();
+        spawn(async move {
+            match app.replace_workspace(restored).await {
+                Ok(()) => backup_message.set(Some(("Replaced your resumes and applications with the backup.".to_string(), false))),
+                Err(err) => backup_message.set(Some((err.to_string(), true))),
+            }
+        });
+    };
+
+    let app_encrypt = app.clone();
+    let change_encryption = move |change: PassphraseChange| {
+        let app = app_encrypt.clone();
+        encryption_busy.set(true);
+        spawn(async move {
+            match app.change_encryption(&change).await {
+                Ok(()) => {
+                    is_encrypted.set(app.keyring.is_unlocked());
+                    passphrase_error.set(None);
+                    show_passphrase_modal.set(false);
+                }
+                Err(err) => passphrase_error.set(Some(err.to_string())),
+            }
+            encryption_busy.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "grid gap-6 md:grid-cols-[1fr_20rem] md:items-start",
+            div {
+                class: "space-y-3",
+                h2 {
+                    class: "text-2xl font-bold text-gray-800 mb-2",
+                    "Your Resumes"
+                },
+                for entry in app.library.read().iter().cloned() {
+                    div {
+                        key: "{entry.id}",
+                        class: "p-4 bg-white rounded shadow-sm flex flex-wrap justify-between items-center gap-2",
+                        div {
+                            Link {
+                                class: "text-lg font-semibold text-blue-800 hover:underline",
+                                to: Route::Editor { id: entry.id },
+                                "{entry.title}"
+                            },
+                            div {
+                                class: "text-sm text-gray-600",
+                                match entry.updated_on {
+                                    Some(date) => rsx! { "Saved {date.format(\"%b %-d, %Y\")}" },
+                                    None => rsx! { "Not saved yet" },
+                                }
+                            }
+                        },
+                        div {
+                            class: "flex gap-3 text-sm",
+                            Link { class: "text-blue-700 hover:underline", to: Route::Editor { id: entry.id }, "Edit" },
+                            Link { class: "text-blue-700 hover:underline", to: Route::Preview { id: entry.id }, "Preview" },
+                            Link { class: "text-blue-700 hover:underline", to: Route::Export { id: entry.id }, "Export" },
+                            Link { class: "text-blue-700 hover:underline", to: Route::Settings { id: entry.id }, "Settings" }
+                        }
+                    }
+                }
+            },
+
+            div {
+                class: "space-y-4",
+                form {
+                    class: "p-4 bg-white rounded shadow-sm",
+                    onsubmit: create_resume,
+                    h3 {
+                        class: "text-lg font-semibold mb-2",
+                        "New Resume"
+                    },
+                    input {
+                        class: "w-full p-2 border rounded mb-2",
+                        placeholder: "e.g. Backend roles",
+                        value: "{new_title}",
+                        oninput: move |evt| new_title.set(evt.value())
+                    },
+                    label {
+                        class: "flex items-center gap-2 mb-3 text-sm text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            checked: from_example(),
+                            onchange: move |evt| from_example.set(evt.checked())
+                        },
+                        "Start from the example resume"
+                    },
+                    button {
+                        r#type: "submit",
+                        class: "w-full px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors duration-300",
+                        disabled: new_title().trim().is_empty(),
+                        "Create"
+                    }
+                },
+
+                div {
+                    class: "p-4 bg-white rounded shadow-sm",
+                    h3 {
+                        class: "text-lg font-semibold mb-2",
+                        "Backup"
+                    },
+                    p {
+                        class: "mb-3 text-sm text-gray-600",
+                        "Back up every resume together with all tracked applications, or restore a backup."
+                    },
+                    if let Some((message, failed)) = backup_message() {
+                        p {
+                            class: if failed { "mb-3 text-sm text-red-600" } else { "mb-3 text-sm text-green-700" },
+                            "{message}"
+                        }
+                    }
+                    button {
+                        class: "w-full px-4 py-2 bg-white text-blue-800 border rounded hover:bg-blue-50 transition-colors duration-300",
+                        onclick: move |_| show_backup_modal.set(true),
+                        "Backup & Restore"
+                    }
+                },
+
+                if cfg!(feature = "web") {
+                    div {
+                        class: "p-4 bg-white rounded shadow-sm",
+                        h3 {
+                            class: "text-lg font-semibold mb-2",
+                            "Encryption"
+                        },
+                        p {
+                            class: "mb-3 text-sm text-gray-600",
+                            if is_encrypted() {
+                                "Your resumes and applications are encrypted with a passphrase on this device."
+                            } else {
+                                "Your resumes and applications are stored unencrypted on this device."
+                            }
+                        },
+                        p {
+                            class: "mb-3 text-sm text-amber-700",
+                            "Encryption covers this device only: resumes synced to a server are sent and stored there unencrypted."
+                        },
+                        button {
+                            class: "w-full px-4 py-2 bg-white text-blue-800 border rounded hover:bg-blue-50 transition-colors duration-300",
+                            onclick: move |_| {
+                                passphrase_error.set(None);
+                                show_passphrase_modal.set(true);
+                            },
+                            if is_encrypted() { "Change Encryption" } else { "Encrypt" }
+                        }
+                    }
+                }
+            }
+        }
+
+        BackupModal {
+            show: show_backup_modal(),
+            on_close: move |_| show_backup_modal.set(false),
+            on_download: download_backup,
+            on_merge: merge_backup,
+            on_replace: replace_backup
+        }
+
+        PassphraseModal {
+            show: show_passphrase_modal(),
+            encrypted: is_encrypted(),
+            busy: encryption_busy(),
+            error: passphrase_error(),
+            on_close: move |_| show_passphrase_modal.set(false),
+            on_submit: change_encryption
+        }
+    }
+}
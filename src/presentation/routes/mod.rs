@@ -0,0 +1,50 @@
+// Pages of the app and the routes that lead to them
+pub mod context;
+pub mod app_shell;
+pub mod library;
+pub mod applications;
+pub mod workspace;
+pub mod editor;
+pub mod preview;
+pub mod export;
+pub mod settings;
+pub mod not_found;
+
+use dioxus::prelude::*;
+
+pub use app_shell::AppShell;
+pub use applications::Applications;
+pub use editor::Editor;
+pub use export::Export;
+pub use library::Library;
+pub use not_found::NotFound;
+pub use preview::Preview;
+pub use settings::Settings;
+pub use workspace::ResumeWorkspace;
+
+// Every page has its own address, so deep links and back/forward work.
+// The pages of one resume share the workspace layout, which loads the
+// resume once and keeps it while the user switches between them.
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+pub enum Route {
+    #[layout(AppShell)]
+        #[route("/")]
+        Library {},
+        #[route("/applications")]
+        Applications {},
+        #[nest("/resumes/:id")]
+            #[layout(ResumeWorkspace)]
+                #[route("/")]
+                Editor { id: u64 },
+                #[route("/preview")]
+                Preview { id: u64 },
+                #[route("/export")]
+                Export { id: u64 },
+                #[route("/settings")]
+                Settings { id: u64 },
+            #[end_layout]
+        #[end_nest]
+        #[route("/:..segments")]
+        NotFound { segments: Vec<String> },
+}
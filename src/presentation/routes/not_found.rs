@@ -0,0 +1,28 @@
+use dioxus::prelude::*;
+
+use crate::presentation::routes::Route;
+
+/// Shown for any address that matches no page
+#[component]
+pub fn NotFound(segments: Vec<String>) -> Element {
+    let path = segments.join("/");
+
+    rsx! {
+        div {
+            class: "p-6 bg-white rounded shadow-sm text-center",
+            h2 {
+                class: "text-2xl font-bold text-gray-800 mb-2",
+                "Page not found"
+            },
+            p {
+                class: "mb-4 text-gray-600",
+                "There is nothing at /{path}."
+            },
+            Link {
+                class: "text-blue-700 hover:underline",
+                to: Route::Library {},
+                "Back to your resumes"
+            }
+        }
+    }
+}
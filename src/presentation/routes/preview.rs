@@ -0,0 +1,63 @@
+use dioxus::prelude::*;
+
+use crate::domain::ResumeTheme;
+use crate::presentation::components::*;
+use crate::presentation::routes::workspace::use_session;
+use crate::presentation::routes::Route;
+
+/// Full-width page preview; clicking an item opens it in the editor
+#[component]
+pub fn Preview(id: u64) -> Element {
+    let session = use_session(id);
+    let mut resume = session.resume;
+    let select_item = session.select_item;
+    let navigator = use_navigator();
+
+    let themes: Vec<Theme> = available_themes();
+    let selected_theme = ResumeTheme::all()
+        .iter()
+        .position(|theme| *theme == resume().theme)
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "lg:hidden mb-4 flex justify-end print:hidden",
+            ToggleButton {
+                is_preview_mode: true,
+                on_toggle: move |preview: bool| {
+                    if !preview {
+                        navigator.push(Route::Editor { id });
+                    }
+                }
+            }
+        }
+
+        div {
+            class: "max-w-4xl mx-auto print:max-w-none",
+            div {
+                class: "print:hidden",
+                ThemeSelector {
+                    themes: themes.clone(),
+                    selected_theme: selected_theme,
+                    on_theme_select: move |index: usize| {
+                        let mut updated_resume = resume();
+                        updated_resume.theme = ResumeTheme::all()[index];
+                        resume.set(updated_resume);
+                    }
+                }
+            }
+
+            PagedPreview {
+                resume: resume(),
+                theme_bg: themes[selected_theme].1,
+                highlight: None,
+                on_select: move |target| select_item.call(target),
+                on_paper_change: move |paper| {
+                    let mut updated_resume = resume();
+                    updated_resume.paper_size = paper;
+                    resume.set(updated_resume);
+                }
+            }
+        }
+    }
+}
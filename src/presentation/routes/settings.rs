@@ -0,0 +1,197 @@
+use dioxus::prelude::*;
+
+use crate::infrastructure::{SyncSettings, SyncStatus};
+use crate::presentation::components::SyncModal;
+use crate::presentation::routes::context::AppContext;
+use crate::presentation::routes::workspace::use_session;
+use crate::presentation::routes::Route;
+
+/// Title, sync and removal of one resume
+#[component]
+pub fn Settings(id: u64) -> Element {
+    let app = use_context::<AppContext>();
+    let session = use_session(id);
+    let storage = session.storage.clone();
+    let mut resume = session.resume;
+    let mut sync_settings = session.sync_settings;
+    let mut sync_status = session.sync_status;
+    let refresh_sync = session.refresh_sync;
+    let navigator = use_navigator();
+
+    let mut title = use_signal(|| app.entry(id).map(|entry| entry.title).unwrap_or_default());
+    let mut remove_error = use_signal(|| Option::<String>::None);
+
+    let mut show_sync_modal = use_signal(|| false);
+    let mut sync_error = use_signal(|| Option::<String>::None);
+    let mut sync_busy = use_signal(|| false);
+
+    let mut app_rename = app.clone();
+    let rename_resume = move |evt: FormEvent| {
+        evt.prevent_default();
+        let mut updated = app_rename.library.read().clone();
+        if app_rename.library_use_case.rename_entry(&mut updated, id, title().trim()).is_ok() {
+            app_rename.save_library(updated);
+        }
+    };
+
+    let mut app_remove = app.clone();
+    let remove_resume = move |_| match app_remove.remove_resume(id) {
+        Ok(()) => {
+            navigator.push(Route::Library {});
+        }
+        Err(err) => remove_error.set(Some(err.to_string())),
+    };
+
+    let section_class = "p-4 bg-white rounded shadow-sm";
+    let button_class = "px-4 py-2 bg-white text-blue-800 border rounded hover:bg-blue-50 transition-colors duration-300";
+
+    rsx! {
+        div {
+            class: "max-w-2xl space-y-4",
+            div {
+                class: section_class,
+                h3 {
+                    class: "text-lg font-semibold mb-2",
+                    "Title"
+                },
+                form {
+                    class: "flex gap-2",
+                    onsubmit: rename_resume,
+                    input {
+                        class: "flex-1 p-2 border rounded",
+                        value: "{title}",
+                        oninput: move |evt| title.set(evt.value())
+                    },
+                    button {
+                        r#type: "submit",
+                        class: button_class,
+                        disabled: title().trim().is_empty(),
+                        "Rename"
+                    }
+                }
+            },
+
+            if storage.sync.is_some() {
+                div {
+                    class: section_class,
+                    h3 {
+                        class: "text-lg font-semibold mb-2",
+                        "Sync"
+                    },
+                    p {
+                        class: "mb-3 text-sm text-gray-600",
+                        "{sync_status().description()}"
+                    },
+                    button {
+                        class: button_class,
+                        onclick: move |_| {
+                            sync_error.set(None);
+                            show_sync_modal.set(true);
+                        },
+                        if sync_settings().is_some() { "Sync Settings" } else { "Set Up Sync" }
+                    }
+                }
+            }
+
+            div {
+                class: section_class,
+                h3 {
+                    class: "text-lg font-semibold mb-2",
+                    "Remove"
+                },
+                p {
+                    class: "mb-3 text-sm text-gray-600",
+                    "Deletes this resume and its saved data from this device. A synced copy stays on the server."
+                },
+                if let Some(message) = remove_error() {
+                    p {
+                        class: "mb-3 text-sm text-red-600",
+                        "{message}"
+                    }
+                }
+                button {
+                    class: "px-4 py-2 bg-red-500 text-white rounded hover:bg-red-600 transition-colors duration-300",
+                    onclick: remove_resume,
+                    "Remove Resume"
+                }
+            }
+        }
+
+        // Sync settings
+        if show_sync_modal() {
+            SyncModal {
+                settings: sync_settings(),
+                status: sync_status().description(),
+                busy: sync_busy(),
+                error: sync_error(),
+                on_close: move |_| show_sync_modal.set(false),
+                on_connect: {
+                    let storage = storage.clone();
+                    move |settings: SyncSettings| {
+                        let storage = storage.clone();
+                        let Some(sync) = storage.sync.clone() else {
+                            return;
+                        };
+                        sync_busy.set(true);
+                        spawn(async move {
+                            match sync.configure(settings.clone()).await {
+                                Ok(_) => {
+                                    sync_settings.set(Some(settings));
+                                    sync_error.set(None);
+                                    // A first sync may have pulled the server copy
+                                    if let (Ok(loaded), None) = (storage.use_case.load_resume().await, sync.conflict()) {
+                                        resume.set(loaded);
+                                    }
+                                }
+                                Err(err) => sync_error.set(Some(err.to_string())),
+                            }
+                            refresh_sync.call(());
+                            sync_busy.set(false);
+                        });
+                    }
+                },
+                on_sync: {
+                    let storage = storage.clone();
+                    move |_| {
+                        let storage = storage.clone();
+                        let Some(sync) = storage.sync.clone() else {
+                            return;
+                        };
+                        sync_busy.set(true);
+                        spawn(async move {
+                            match storage.use_case.load_resume().await {
+                                Ok(loaded) => {
+                                    if sync.conflict().is_none() {
+                                        resume.set(loaded);
+                                    }
+                                    sync_error.set(None);
+                                }
+                                Err(err) => sync_error.set(Some(err.to_string())),
+                            }
+                            refresh_sync.call(());
+                            sync_busy.set(false);
+                        });
+                    }
+                },
+                on_disconnect: {
+                    let sync = storage.sync.clone();
+                    move |_| {
+                        let Some(sync) = sync.clone() else {
+                            return;
+                        };
+                        spawn(async move {
+                            match sync.disconnect().await {
+                                Ok(()) => {
+                                    sync_settings.set(None);
+                                    sync_status.set(SyncStatus::Disabled);
+                                    show_sync_modal.set(false);
+                                }
+                                Err(err) => sync_error.set(Some(err.to_string())),
+                            }
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::entry_order::sort_entries;
+use crate::application::MergePlan;
+use crate::domain::{FieldConfidence, ImportDraft, Resume};
+use crate::infrastructure::{EncryptionState, SyncSettings, SyncStatus};
+use crate::presentation::browser::{focus_first_field, scroll_within_container, wait};
+use crate::presentation::components::{ImportModal, MergeDialog, PreviewTarget, UnlockScreen};
+use crate::presentation::routes::context::{AppContext, ResumeStorage};
+use crate::presentation::routes::Route;
+
+// Saves are pushed to the sync server once no other save followed for this long
+const SYNC_DELAY_MS: u32 = 3000;
+
+// State of the resume open in the editor, preview, export and settings pages
+#[derive(Clone)]
+pub struct ResumeSession {
+    pub id: u64,
+    pub storage: Rc<ResumeStorage>,
+    pub resume: Signal<Resume>,
+    // Link between the editor and the preview: the item last edited and the item picked in the preview
+    pub preview_highlight: Signal<Option<PreviewTarget>>,
+    pub selected_item: Signal<Option<PreviewTarget>>,
+    // Imported draft under review: the resume it replaced and the fields to check
+    pub import_review: Signal<Option<(Resume, Vec<FieldConfidence>)>>,
+    pub sync_settings: Signal<Option<SyncSettings>>,
+    pub sync_status: Signal<SyncStatus>,
+    // Merges imported data into the resume; conflicts are left for the merge dialog to resolve
+    pub start_merge: Callback<(Resume, Resume)>,
+    // Picks up the status of the last sync; a conflict is merged like an import
+    pub refresh_sync: Callback<()>,
+    pub save: Callback<()>,
+    pub mark_edited: Callback<PreviewTarget>,
+    pub select_item: Callback<PreviewTarget>,
+}
+
+// Session of the resume shown by a page nested in the workspace layout
+pub fn use_session(id: u64) -> ResumeSession {
+    let session = use_context::<ResumeSession>();
+    debug_assert_eq!(session.id, id, "page and workspace show different resumes");
+    session
+}
+
+/// Layout of the pages of one resume: tabs, saving, importing and the dialogs they share
+#[component]
+pub fn ResumeWorkspace(id: u64) -> Element {
+    // Keyed so that every resume starts with fresh state
+    rsx! {
+        ResumeSessionView { key: "{id}", id }
+    }
+}
+
+#[component]
+fn ResumeSessionView(id: u64) -> Element {
+    let app = use_context::<AppContext>();
+    let storage = use_hook(|| app.storage(id));
+    let navigator = use_navigator();
+
+    let mut resume = use_signal(Resume::default);
+    let mut loaded = use_signal(|| false);
+    let mut preview_highlight = use_signal(|| Option::<PreviewTarget>::None);
+    let mut selected_item = use_signal(|| Option::<PreviewTarget>::None);
+    let mut import_review = use_signal(|| Option::<(Resume, Vec<FieldConfidence>)>::None);
+    let mut pending_merge = use_signal(|| Option::<MergePlan>::None);
+    let mut show_import_modal = use_signal(|| false);
+
+    // A resume encrypted under another passphrase stays locked until that passphrase is entered
+    let mut is_locked = use_signal(|| false);
+    let mut unlock_error = use_signal(|| Option::<String>::None);
+    let mut unlock_busy = use_signal(|| false);
+
+    // Sync state: a server conflict is resolved in the merge dialog before pushing again
+    let mut sync_settings = use_signal(|| Option::<SyncSettings>::None);
+    let mut sync_status = use_signal(|| SyncStatus::Disabled);
+    let mut resolving_sync = use_signal(|| false);
+    let mut sync_generation = use_signal(|| 0u64);
+
+    let use_case = storage.use_case.clone();
+
+    let use_case_import = use_case.clone();
+    let start_merge = use_callback(move |(mine, theirs): (Resume, Resume)| {
+        let plan = use_case_import.plan_merge(&mine, &theirs);
+        if plan.conflicts.is_empty() {
+            resume.set(use_case_import.apply_merge(&plan));
+            import_review.set(None);
+        } else {
+            pending_merge.set(Some(plan));
+        }
+    });
+
+    // Pushes the resolved resume on top of the conflicting server revision
+    let sync_resolve = storage.sync.clone();
+    let resolve_sync = use_callback(move |merged: Resume| {
+        let Some(sync) = sync_resolve.clone() else {
+            return;
+        };
+        resolving_sync.set(false);
+        spawn(async move {
+            match sync.resolve(&merged).await {
+                Ok(status) => sync_status.set(status),
+                Err(err) => tracing::error!("Error resolving sync conflict: {}", err),
+            }
+        });
+    });
+
+    let sync_refresh = storage.sync.clone();
+    let use_case_sync = use_case.clone();
+    let refresh_sync = use_callback(move |_: ()| {
+        let Some(sync) = sync_refresh.clone() else {
+            return;
+        };
+        sync_status.set(sync.status());
+        let Some(remote) = sync.conflict() else {
+            return;
+        };
+        if resolving_sync() {
+            return;
+        }
+        let plan = use_case_sync.plan_merge(&resume(), &remote.resume);
+        if plan.conflicts.is_empty() {
+            let merged = use_case_sync.apply_merge(&plan);
+            resume.set(merged.clone());
+            resolve_sync.call(merged);
+        } else {
+            resolving_sync.set(true);
+            pending_merge.set(Some(plan));
+        }
+    });
+
+    // Pushes queued saves after a pause, so a burst of saves makes one request
+    let sync_push = storage.sync.clone();
+    let push_later = use_callback(move |_: ()| {
+        let Some(sync) = sync_push.clone() else {
+            return;
+        };
+        let generation = sync_generation() + 1;
+        sync_generation.set(generation);
+        spawn(async move {
+            wait(SYNC_DELAY_MS).await;
+            if sync_generation() != generation {
+                return;
+            }
+            if let Err(err) = sync.sync().await {
+                tracing::error!("Error syncing resume: {}", err);
+            }
+            refresh_sync.call(());
+        });
+    });
+
+    let use_case_save = use_case.clone();
+    let app_save = app.clone();
+    let save = use_callback(move |_: ()| {
+        let use_case_save = use_case_save.clone();
+        let mut app = app_save.clone();
+        spawn(async move {
+            if let Err(err) = use_case_save.save_resume(&resume()).await {
+                tracing::error!("Error saving resume: {}", err);
+                return;
+            }
+            let mut updated = app.library.read().clone();
+            if app.library_use_case.touch_entry(&mut updated, id).is_ok() {
+                app.save_library(updated);
+            }
+            push_later.call(());
+        });
+    });
+
+    // Highlights an edited item in the preview; the edit also ends any selection made there
+    let mark_edited = use_callback(move |target: PreviewTarget| {
+        preview_highlight.set(Some(target));
+        selected_item.set(None);
+        scroll_within_container(&target.preview_id());
+    });
+
+    // Opens the form of an item picked in the preview
+    let select_item = use_callback(move |target: PreviewTarget| {
+        preview_highlight.set(Some(target));
+        selected_item.set(Some(target));
+        navigator.push(Route::Editor { id });
+        focus_first_field(&target.editor_id());
+    });
+
+    // Load the resume, or pick up unsaved edits made before leaving its pages
+    let storage_load = storage.clone();
+    use_future(move || {
+        let storage = storage_load.clone();
+        async move {
+            if let Some(encryption) = &storage.encryption {
+                match encryption.state().await {
+                    Ok(state) => is_locked.set(state == EncryptionState::Locked),
+                    Err(err) => tracing::error!("Error reading encryption state: {}", err),
+                }
+            }
+            if is_locked() {
+                return;
+            }
+
+            let draft = storage.draft.borrow().clone();
+            match draft {
+                Some(draft) => resume.set(draft),
+                None => match storage.use_case.load_resume().await {
+                    Ok(loaded_resume) => resume.set(loaded_resume),
+                    Err(err) => tracing::error!("Error loading resume: {}", err),
+                },
+            }
+            loaded.set(true);
+            if let Some(sync) = &storage.sync {
+                match sync.settings().await {
+                    Ok(settings) => sync_settings.set(settings),
+                    Err(err) => tracing::error!("Error reading sync settings: {}", err),
+                }
+                refresh_sync.call(());
+            }
+        }
+    });
+
+    // Keep dated sections in order however the resume changed: loading, imports, merges and syncs
+    use_effect(move || {
+        let mut current = resume();
+        if sort_entries(&mut current) {
+            resume.set(current);
+        }
+    });
+
+    // Keep the working copy so edits survive a visit to another page
+    let storage_draft = storage.clone();
+    let mut last_opened = app.last_opened;
+    use_effect(move || {
+        let current = resume();
+        if loaded() {
+            storage_draft.draft.replace(Some(current.clone()));
+            last_opened.set(Some(current));
+        }
+    });
+
+    use_context_provider(|| ResumeSession {
+        id,
+        storage: storage.clone(),
+        resume,
+        preview_highlight,
+        selected_item,
+        import_review,
+        sync_settings,
+        sync_status,
+        start_merge,
+        refresh_sync,
+        save,
+        mark_edited,
+        select_item,
+    });
+
+    let title = app.entry(id).map(|entry| entry.title);
+    let tabs = [
+        ("Edit", Route::Editor { id }),
+        ("Preview", Route::Preview { id }),
+        ("Export", Route::Export { id }),
+        ("Settings", Route::Settings { id }),
+    ];
+    let current_route = use_route::<Route>();
+
+    let Some(title) = title else {
+        return rsx! {
+            div {
+                class: "p-6 bg-white rounded shadow text-gray-700",
+                p { "This resume is not in your library." },
+                Link {
+                    class: "text-blue-700 underline",
+                    to: Route::Library {},
+                    "Back to your resumes"
+                }
+            }
+        };
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-wrap justify-between items-center gap-4 mb-6 print:hidden",
+            div {
+                class: "flex items-center gap-4",
+                h2 {
+                    class: "text-2xl font-bold text-gray-800",
+                    "{title}"
+                },
+                nav {
+                    class: "flex bg-gray-200 rounded-full p-1",
+                    for (label, route) in tabs {
+                        Link {
+                            class: if current_route == route { "px-4 py-1 rounded-full bg-white text-blue-800 shadow" } else { "px-4 py-1 rounded-full text-gray-700 hover:text-blue-800" },
+                            to: route.clone(),
+                            "{label}"
+                        }
+                    }
+                }
+            },
+            div {
+                class: "flex items-center gap-4",
+                if sync_settings().is_some() {
+                    span {
+                        class: "text-sm text-gray-600",
+                        title: sync_status().description(),
+                        match sync_status() {
+                            SyncStatus::Disabled => "Sync off",
+                            SyncStatus::Synced { .. } => "Synced",
+                            SyncStatus::Offline => "Sync pending",
+                            SyncStatus::Conflict => "Sync conflict",
+                        }
+                    }
+                }
+                button {
+                    class: "px-4 py-2 bg-white text-blue-800 rounded hover:bg-blue-50 transition-colors duration-300",
+                    onclick: move |_| show_import_modal.set(true),
+                    "Import"
+                },
+                button {
+                    class: "px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors duration-300",
+                    onclick: move |_| save.call(()),
+                    "Save"
+                }
+            }
+        }
+
+        if loaded() {
+            Outlet::<Route> {}
+        }
+
+        // Import modal
+        ImportModal {
+            show: show_import_modal(),
+            on_close: move |_| show_import_modal.set(false),
+            on_merge: move |imported| start_merge.call((resume(), imported)),
+            on_replace: move |imported: Resume| {
+                // Keep the look of the current resume, only the content is replaced
+                let current = resume();
+                resume.set(Resume {
+                    theme: current.theme,
+                    section_order: current.section_order,
+                    paper_size: current.paper_size,
+                    education_order: current.education_order,
+                    experience_order: current.experience_order,
+                    ..imported
+                });
+            },
+            on_review: move |draft: ImportDraft| {
+                // The draft is edited in the normal forms; the previous resume is kept until review ends
+                let current = resume();
+                let fields = draft.fields_to_review();
+                resume.set(Resume {
+                    theme: current.theme,
+                    section_order: current.section_order.clone(),
+                    paper_size: current.paper_size,
+                    education_order: current.education_order,
+                    experience_order: current.experience_order,
+                    ..draft.resume
+                });
+                let previous = import_review().map_or(current, |(previous, _)| previous);
+                import_review.set(Some((previous, fields)));
+                navigator.push(Route::Editor { id });
+            }
+        }
+
+        // Merge conflict resolution
+        if let Some(plan) = pending_merge() {
+            MergeDialog {
+                plan: plan,
+                on_choice: move |(index, choice)| {
+                    if let Some(plan) = pending_merge.write().as_mut() {
+                        plan.set_choice(index, choice);
+                    }
+                },
+                on_cancel: move |_| {
+                    // An unresolved sync conflict is offered again on the next sync
+                    resolving_sync.set(false);
+                    pending_merge.set(None);
+                },
+                on_apply: {
+                    let use_case_merge = use_case.clone();
+                    move |_| {
+                        if let Some(plan) = pending_merge() {
+                            let merged = use_case_merge.apply_merge(&plan);
+                            resume.set(merged.clone());
+                            import_review.set(None);
+                            if resolving_sync() {
+                                resolve_sync.call(merged);
+                            }
+                        }
+                        pending_merge.set(None);
+                    }
+                }
+            }
+        }
+
+        // Unlock screen, shown over everything until the passphrase is entered
+        if is_locked() {
+            UnlockScreen {
+                error: unlock_error(),
+                busy: unlock_busy(),
+                on_cancel: move |_| {
+                    navigator.push(Route::Library {});
+                },
+                on_unlock: {
+                    let storage = storage.clone();
+                    move |passphrase: String| {
+                        let storage = storage.clone();
+                        let Some(encryption) = storage.encryption.clone() else {
+                            return;
+                        };
+                        unlock_busy.set(true);
+                        spawn(async move {
+                            match encryption.unlock(&passphrase).await {
+                                Ok(()) => {
+                                    unlock_error.set(None);
+                                    is_locked.set(false);
+                                    match storage.use_case.load_resume().await {
+                                        Ok(loaded_resume) => resume.set(loaded_resume),
+                                        Err(err) => tracing::error!("Error loading resume: {}", err),
+                                    }
+                                    loaded.set(true);
+                                    if let Some(sync) = &storage.sync {
+                                        if let Ok(settings) = sync.settings().await {
+                                            sync_settings.set(settings);
+                                        }
+                                    }
+                                    refresh_sync.call(());
+                                }
+                                Err(err) => unlock_error.set(Some(err.to_string())),
+                            }
+                            unlock_busy.set(false);
+                        });
+                    }
+                }
+            }
+        }
+    }
+}